@@ -1,18 +1,20 @@
 use crate::blueprint::conversions::{
-    cloning2cloning, lifecycle2lifecycle, method_guard2method_guard,
-    raw_callable2registered_callable,
+    constructor2constructor, lifecycle2lifecycle, method_guard2method_guard,
+    raw_callable2registered_callable, trailing_slash2trailing_slash,
 };
 use crate::blueprint::error_observer::RegisteredErrorObserver;
 use crate::blueprint::router::RegisteredFallback;
+use crate::http::StatusCode;
 use pavex_bp_schema::{
-    Blueprint as BlueprintSchema, Constructor, Fallback, NestedBlueprint, Route, WrappingMiddleware,
+    ApiVersion, AutoOptionsOverride, Blueprint as BlueprintSchema, Callable, Constructor, Fallback,
+    NestedBlueprint, RawCallableIdentifiers, Redirect, Route, WrappingMiddleware,
 };
 use pavex_reflection::Location;
 
 use super::constructor::{Lifecycle, RegisteredConstructor};
 use super::middleware::RegisteredWrappingMiddleware;
 use super::reflection::RawCallable;
-use super::router::{MethodGuard, RegisteredRoute};
+use super::router::{MethodGuard, RegisteredApiVersion, RegisteredRoute, TrailingSlash};
 
 /// The starting point for building an application with Pavex.
 ///
@@ -27,6 +29,7 @@ use super::router::{MethodGuard, RegisteredRoute};
 /// It keeps track of:
 ///
 /// - route handlers, registered via [`Blueprint::route`]
+/// - redirects, registered via [`Blueprint::redirect`]
 /// - constructors, registered via [`Blueprint::constructor`]
 /// - wrapping middlewares, registered via [`Blueprint::wrap`]
 /// - fallback handlers, registered via [`Blueprint::fallback`]
@@ -47,6 +50,7 @@ impl Default for Blueprint {
             schema: BlueprintSchema {
                 creation_location: Location::caller(),
                 components: Vec::new(),
+                router_settings: Default::default(),
             },
         }
     }
@@ -86,6 +90,22 @@ impl Blueprint {
     /// # }
     /// ```
     ///
+    /// # Parameter constraints
+    ///
+    /// Path parameters can be constrained to a primitive type or to a regular expression,
+    /// e.g. `/users/:id<u64>` (see [`pavex::request::path`](crate::request::path) for the details).
+    /// Constraints are checked **after** the route has been matched: if a value doesn't satisfy
+    /// its constraint, the request is handed over to the fallback that applies to the route—the
+    /// one registered via [`Blueprint::fallback`] on the blueprint that contains the route, or
+    /// on the closest ancestor that has one. This applies to routes that match any method,
+    /// including redirects.
+    ///
+    /// Requests that don't satisfy the constraints **don't** fall through to other routes:
+    /// all routes registered for the same path (e.g. `GET /users/:id<u64>` and
+    /// `DELETE /users/:id<u64>`) must share the same constraints, and you can't register
+    /// alternative routes that only differ by their constraints (e.g. `/users/:id<u64>` and
+    /// `/users/:name`).
+    ///
     /// [`router`]: crate::blueprint::router
     /// [`PathParams`]: struct@crate::request::path::PathParams
     pub fn route(
@@ -99,6 +119,9 @@ impl Blueprint {
             method_guard: method_guard2method_guard(method_guard),
             request_handler: raw_callable2registered_callable(callable),
             error_handler: None,
+            name: None,
+            constructors: Vec::new(),
+            redirect: None,
         };
         let component_id = self.push_component(registered_route);
         RegisteredRoute {
@@ -113,6 +136,9 @@ impl Blueprint {
             method_guard: method_guard2method_guard(r.method_guard),
             error_handler: r.error_handler,
             request_handler: r.callable,
+            name: r.name,
+            constructors: r.constructors,
+            redirect: None,
         };
         let component_id = self.push_component(r);
         RegisteredRoute {
@@ -121,6 +147,66 @@ impl Blueprint {
         }
     }
 
+    #[track_caller]
+    /// Redirect all incoming requests that match `from` to `to`, using the specified status code.
+    ///
+    /// Pavex provides the request handler for redirect routes: you don't have to write one.
+    /// Redirect routes are otherwise treated as any other route: they match all HTTP methods,
+    /// they are wrapped by the middlewares registered against the blueprint and they are
+    /// checked for conflicts with the other routes.
+    ///
+    /// # Path parameters
+    ///
+    /// `to` can reference the path parameters of `from`, using the same syntax—e.g.
+    /// `/users/:id` or `/files/*path`.
+    /// They are replaced, as they appear in the request path, with the values
+    /// matched by `from`.
+    /// `pavex` will reject, at build time, redirects that reference parameters
+    /// not defined in `from`, as well as status codes that don't belong to the
+    /// `3xx` range.
+    ///
+    /// `to` is used as it is: it isn't prefixed with the path prefix of the blueprint
+    /// and the query string of the incoming request isn't forwarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::blueprint::Blueprint;
+    /// use pavex::http::StatusCode;
+    ///
+    /// # fn main() {
+    /// let mut bp = Blueprint::new();
+    /// // `GET /profile/42` is redirected to `/users/42`, with a `301 Moved Permanently`.
+    /// bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY);
+    /// # }
+    /// ```
+    pub fn redirect(&mut self, from: &str, to: &str, status: StatusCode) -> RegisteredRoute {
+        let location = Location::caller();
+        let registered_route = Route {
+            path: from.to_owned(),
+            method_guard: pavex_bp_schema::MethodGuard::Any,
+            request_handler: Callable {
+                callable: RawCallableIdentifiers {
+                    registered_at: "pavex".to_owned(),
+                    import_path: "pavex::router::redirect".to_owned(),
+                },
+                location,
+            },
+            error_handler: None,
+            name: None,
+            constructors: Vec::new(),
+            redirect: Some(Redirect {
+                to: to.to_owned(),
+                status: status.as_u16(),
+            }),
+        };
+        let component_id = self.push_component(registered_route);
+        RegisteredRoute {
+            blueprint: &mut self.schema,
+            component_id,
+        }
+    }
+
     #[track_caller]
     /// Register a constructor.
     ///
@@ -173,14 +259,7 @@ impl Blueprint {
         &mut self,
         constructor: super::constructor::Constructor,
     ) -> RegisteredConstructor {
-        let constructor = Constructor {
-            constructor: constructor.callable,
-            lifecycle: lifecycle2lifecycle(constructor.lifecycle),
-            cloning_strategy: constructor.cloning_strategy.map(cloning2cloning),
-            error_handler: constructor.error_handler,
-            lints: constructor.lints,
-        };
-        let component_id = self.push_component(constructor);
+        let component_id = self.push_component(constructor2constructor(constructor));
         RegisteredConstructor {
             component_id,
             blueprint: &mut self.schema,
@@ -406,6 +485,8 @@ impl Blueprint {
         self.push_component(NestedBlueprint {
             blueprint: blueprint.schema,
             path_prefix: Some(prefix.into()),
+            domain: None,
+            version: None,
             nesting_location: Location::caller(),
         });
     }
@@ -418,10 +499,146 @@ impl Blueprint {
         self.push_component(NestedBlueprint {
             blueprint: blueprint.schema,
             path_prefix: None,
+            domain: None,
+            version: None,
+            nesting_location: Location::caller(),
+        });
+    }
+
+    #[track_caller]
+    /// Nest a [`Blueprint`] under the current [`Blueprint`] (the parent), restricting all
+    /// its routes to incoming requests that target the specified domain.
+    ///
+    /// The domain is matched against the `Host` header of the incoming request (or the
+    /// authority of the request target, for HTTP/2), ignoring the port.
+    ///
+    /// # Domain captures
+    ///
+    /// Labels that start with a `:` capture a single label of the incoming domain.
+    /// E.g. `:tenant.example.com` matches `acme.example.com` and `globex.example.com`,
+    /// but it doesn't match `example.com` or `eu.acme.example.com`.
+    /// Use [`HostParams`] to extract the captured values in your components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::f;
+    /// use pavex::blueprint::{Blueprint, router::GET};
+    ///
+    /// fn app() -> Blueprint {
+    ///     let mut bp = Blueprint::new();
+    ///     bp.nest_at_domain("admin.example.com", admin_bp());
+    ///     bp.nest_at_domain(":tenant.example.com", tenant_bp());
+    ///     bp
+    /// }
+    ///
+    /// fn admin_bp() -> Blueprint {
+    ///     let mut bp = Blueprint::new();
+    ///     bp.route(GET, "/users", f!(crate::admin::list_users));
+    ///     bp
+    /// }
+    ///
+    /// fn tenant_bp() -> Blueprint {
+    ///     let mut bp = Blueprint::new();
+    ///     bp.route(GET, "/users", f!(crate::tenant::list_users));
+    ///     bp
+    /// }
+    /// # mod admin { pub fn list_users() {} }
+    /// # mod tenant { pub fn list_users() {} }
+    /// ```
+    ///
+    /// # Routing
+    ///
+    /// Requests that target a registered domain are routed **exclusively** to the routes
+    /// nested under that domain: if none of them matches, the fallback of the
+    /// nested blueprint is invoked (or the closest fallback registered against one of its
+    /// parents, if it doesn't have one).
+    /// Requests for any other domain are routed to the routes that were not nested under a domain.
+    ///
+    /// If multiple domains match the incoming request (e.g. `admin.example.com` and
+    /// `:tenant.example.com`), literal labels take precedence over captures.
+    ///
+    /// # Restrictions
+    ///
+    /// A blueprint nested under a domain can nest other blueprints, with or without a path
+    /// prefix, but it can't be nested under a path prefix or another domain itself.
+    ///
+    /// [`HostParams`]: crate::request::host::HostParams
+    pub fn nest_at_domain(&mut self, domain: &str, blueprint: Blueprint) {
+        self.push_component(NestedBlueprint {
+            blueprint: blueprint.schema,
+            path_prefix: None,
+            domain: Some(domain.into()),
+            version: None,
             nesting_location: Location::caller(),
         });
     }
 
+    #[track_caller]
+    /// Nest a [`Blueprint`] under the current [`Blueprint`] (the parent), as a version
+    /// of your API.
+    ///
+    /// It behaves like [`Blueprint::nest_at`], using the name of the version as path prefix:
+    /// `bp.nest_version("v2", v2_bp())` exposes the routes of `v2_bp()` under `/v2`.
+    ///
+    /// Use the returned [`RegisteredApiVersion`] to inherit the routes of another version,
+    /// remove some of the inherited routes or to route requests to this version based on
+    /// their headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::f;
+    /// use pavex::blueprint::{Blueprint, router::{DELETE, GET}};
+    ///
+    /// fn api() -> Blueprint {
+    ///     let mut bp = Blueprint::new();
+    ///     bp.nest_version("v1", v1());
+    ///     bp.nest_version("v2", v2())
+    ///         // `/v2` exposes all the routes of `/v1`...
+    ///         .inherit_from("v1")
+    ///         // ...apart from this one...
+    ///         .remove_route(DELETE, "/users/:id")
+    ///         // ...and it's picked for requests that target `/users` with `Api-Version: 2`.
+    ///         .select_by_header("Api-Version", "2");
+    ///     bp
+    /// }
+    ///
+    /// fn v1() -> Blueprint {
+    ///     let mut bp = Blueprint::new();
+    ///     bp.route(GET, "/users", f!(crate::v1::list_users));
+    ///     bp.route(DELETE, "/users/:id", f!(crate::v1::delete_user));
+    ///     bp
+    /// }
+    ///
+    /// fn v2() -> Blueprint {
+    ///     let mut bp = Blueprint::new();
+    ///     // It takes precedence over the `GET /users` route inherited from `v1`.
+    ///     bp.route(GET, "/users", f!(crate::v2::list_users));
+    ///     bp
+    /// }
+    /// # mod v1 { pub fn list_users() {} pub fn delete_user() {} }
+    /// # mod v2 { pub fn list_users() {} }
+    /// ```
+    pub fn nest_version(&mut self, version: &str, blueprint: Blueprint) -> RegisteredApiVersion {
+        let component_id = self.push_component(NestedBlueprint {
+            blueprint: blueprint.schema,
+            path_prefix: Some(format!("/{version}")),
+            domain: None,
+            version: Some(ApiVersion {
+                name: version.into(),
+                inherits_from: None,
+                removed_routes: Vec::new(),
+                selector: None,
+            }),
+            nesting_location: Location::caller(),
+        });
+        RegisteredApiVersion {
+            component_id,
+            blueprint: &mut self.schema,
+        }
+    }
+
     #[track_caller]
     /// Register a fallback handler to be invoked when an incoming request does **not** match
     /// any of the routes you registered with [`Blueprint::route`].  
@@ -610,6 +827,132 @@ impl Blueprint {
         }
     }
 
+    /// Route `HEAD` requests to the `GET` handler of a path when there is no
+    /// `HEAD` handler registered for it.
+    ///
+    /// The body returned by the `GET` handler is discarded, but the `Content-Length`
+    /// header is preserved (see [`into_head_response`]).
+    /// `HEAD` is also listed in the [`AllowedMethods`] of those paths—e.g. in the `Allow`
+    /// header returned by the [default fallback](crate::router::default_fallback).
+    ///
+    /// It's disabled by default.
+    /// This setting can only be customized on the root blueprint: Pavex reports an error
+    /// if you customize it on a nested blueprint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::{f, blueprint::{router::GET, Blueprint}};
+    /// # pub fn get_article() {}
+    ///
+    /// let mut bp = Blueprint::new();
+    /// bp.auto_head(true);
+    /// // `HEAD /articles` is handled by `get_article` as well.
+    /// bp.route(GET, "/articles", f!(crate::get_article));
+    /// ```
+    ///
+    /// [`into_head_response`]: crate::router::into_head_response
+    /// [`AllowedMethods`]: crate::router::AllowedMethods
+    pub fn auto_head(&mut self, enabled: bool) {
+        self.schema.router_settings.auto_head = enabled;
+    }
+
+    /// Answer `OPTIONS` requests with a `204 No Content` response when there is
+    /// no `OPTIONS` handler registered for the requested path.
+    ///
+    /// The response carries an `Allow` header listing the methods registered for the path
+    /// (see [`auto_options`]).
+    /// The response goes through the middlewares that apply to the routes registered
+    /// for that path, therefore [`cors_middleware`] keeps answering CORS preflight requests.
+    ///
+    /// It's disabled by default.
+    /// Use [`Blueprint::auto_options_at`] to override this setting for a specific path.
+    /// This setting can only be customized on the root blueprint: Pavex reports an error
+    /// if you customize it on a nested blueprint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::{f, blueprint::{router::{GET, POST}, Blueprint}};
+    /// # pub fn list_articles() {}
+    /// # pub fn create_article() {}
+    ///
+    /// let mut bp = Blueprint::new();
+    /// bp.auto_options(true);
+    /// bp.route(GET, "/articles", f!(crate::list_articles));
+    /// bp.route(POST, "/articles", f!(crate::create_article));
+    /// // `OPTIONS /articles` gets a `204 No Content` response
+    /// // with `Allow: GET,POST,OPTIONS`.
+    /// ```
+    ///
+    /// [`auto_options`]: crate::router::auto_options
+    /// [`cors_middleware`]: crate::cors::cors_middleware
+    pub fn auto_options(&mut self, enabled: bool) {
+        self.schema.router_settings.auto_options = enabled;
+    }
+
+    /// Enable or disable automatic `OPTIONS` handling for a specific path,
+    /// overriding the setting specified via [`Blueprint::auto_options`].
+    ///
+    /// `path` must match, verbatim, the path of at least one registered route, including
+    /// the prefixes of the blueprints it's nested under.
+    /// For routes nested under a domain via [`Blueprint::nest_at_domain`], prepend the
+    /// domain to the path—e.g. `admin.example.com/users`.
+    /// The override is ignored if you register an `OPTIONS` handler for the same path.
+    ///
+    /// Overrides can only be registered on the root blueprint: Pavex reports an error
+    /// if you register them on a nested blueprint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::{f, blueprint::{router::GET, Blueprint}};
+    /// # pub fn get_article() {}
+    /// # pub fn legacy_feed() {}
+    ///
+    /// let mut bp = Blueprint::new();
+    /// bp.auto_options(true);
+    /// // `OPTIONS /legacy/feed` is not handled automatically.
+    /// bp.auto_options_at("/legacy/feed", false);
+    /// bp.route(GET, "/legacy/feed", f!(crate::legacy_feed));
+    /// bp.route(GET, "/articles/:id", f!(crate::get_article));
+    /// ```
+    #[track_caller]
+    pub fn auto_options_at(&mut self, path: &str, enabled: bool) {
+        self.schema
+            .router_settings
+            .auto_options_overrides
+            .push(AutoOptionsOverride {
+                path: path.to_owned(),
+                enabled,
+                location: Location::caller(),
+            });
+    }
+
+    /// Choose how the router handles requests whose path only differs from a registered
+    /// route by a trailing slash—e.g. `/users/` when `/users` is registered.
+    ///
+    /// It defaults to [`TrailingSlash::Strict`]: `/users` and `/users/` are distinct paths.
+    /// Check out [`TrailingSlash`] for the available policies.
+    ///
+    /// This setting can only be customized on the root blueprint: Pavex reports an error
+    /// if you customize it on a nested blueprint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::{f, blueprint::{router::{GET, TrailingSlash}, Blueprint}};
+    /// # pub fn list_users() {}
+    ///
+    /// let mut bp = Blueprint::new();
+    /// // `GET /users/` is redirected to `/users` with a `308 Permanent Redirect`.
+    /// bp.trailing_slash(TrailingSlash::Redirect);
+    /// bp.route(GET, "/users", f!(crate::list_users));
+    /// ```
+    pub fn trailing_slash(&mut self, policy: TrailingSlash) {
+        self.schema.router_settings.trailing_slash = trailing_slash2trailing_slash(policy);
+    }
+
     pub(super) fn register_error_observer(
         &mut self,
         eo: super::error_observer::ErrorObserver,
@@ -1,7 +1,8 @@
 //! Conversions between `pavex_bp_schema` and `pavex_bp` types.
-use crate::blueprint::constructor::{CloningStrategy, Lifecycle};
+use crate::blueprint::constructor::{CloningStrategy, Constructor, Lifecycle};
 use crate::blueprint::linter::Lint;
 use crate::blueprint::reflection::RawCallable;
+use crate::blueprint::router::TrailingSlash;
 use crate::router::AllowedMethods;
 use pavex_bp_schema::{Callable, Location};
 
@@ -16,6 +17,16 @@ pub(super) fn raw_callable2registered_callable(callable: RawCallable) -> Callabl
     }
 }
 
+pub(super) fn constructor2constructor(constructor: Constructor) -> pavex_bp_schema::Constructor {
+    pavex_bp_schema::Constructor {
+        constructor: constructor.callable,
+        lifecycle: lifecycle2lifecycle(constructor.lifecycle),
+        cloning_strategy: constructor.cloning_strategy.map(cloning2cloning),
+        error_handler: constructor.error_handler,
+        lints: constructor.lints,
+    }
+}
+
 pub(super) fn lifecycle2lifecycle(lifecycle: Lifecycle) -> pavex_bp_schema::Lifecycle {
     match lifecycle {
         Lifecycle::RequestScoped => pavex_bp_schema::Lifecycle::RequestScoped,
@@ -45,5 +56,16 @@ pub(super) fn method_guard2method_guard(
 pub(super) fn lint2lint(lint: Lint) -> pavex_bp_schema::Lint {
     match lint {
         Lint::Unused => pavex_bp_schema::Lint::Unused,
+        Lint::CustomDeserialize => pavex_bp_schema::Lint::CustomDeserialize,
+    }
+}
+
+pub(super) fn trailing_slash2trailing_slash(
+    policy: TrailingSlash,
+) -> pavex_bp_schema::TrailingSlash {
+    match policy {
+        TrailingSlash::Strict => pavex_bp_schema::TrailingSlash::Strict,
+        TrailingSlash::Redirect => pavex_bp_schema::TrailingSlash::Redirect,
+        TrailingSlash::MatchBoth => pavex_bp_schema::TrailingSlash::MatchBoth,
     }
 }
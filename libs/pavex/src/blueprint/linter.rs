@@ -11,4 +11,8 @@ pub enum Lint {
     /// You registered a component that's never used in the generated
     /// server SDK code.
     Unused,
+    /// You're extracting query parameters into a type that doesn't derive
+    /// `serde::Deserialize`: Pavex can't verify that its custom implementation
+    /// is compatible with the format of a query string.
+    CustomDeserialize,
 }
@@ -13,6 +13,8 @@ use crate::router::{AllowedMethods, MethodAllowList};
 /// [`MethodGuard::from_iter`].  
 /// If you want to match all well-known HTTP methods, use [`ANY`].  
 /// If you want to match **any** HTTP method, including custom ones, use [`ANY_WITH_EXTENSIONS`].  
+/// If you are implementing a WebDAV server, use the dedicated constants for WebDAV methods
+/// ([`PROPFIND`], [`MKCOL`], [`COPY`], etc.) or [`WEBDAV`] to match all of them.  
 ///
 /// [`Blueprint::route`]: crate::blueprint::Blueprint::route
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -40,6 +42,13 @@ impl MethodGuard {
     /// If you want to match **any** HTTP method, use [`ANY`].  
     /// If you want to match a single HTTP method, use the dedicated constants in this
     /// module ([`GET`], [`POST`], [`PATCH`], [`DELETE`], etc.).
+    ///
+    /// # Custom methods
+    ///
+    /// Custom methods are case-sensitive: `Method::from_bytes(b"purge")` doesn't match
+    /// `PURGE` requests.
+    /// Pavex rejects, when generating the server SDK, custom methods that only differ
+    /// by case from a well-known method (e.g. `get`), since they're almost certainly a mistake.
     pub fn from_iter(allowed_methods: impl IntoIterator<Item = Method>) -> Self {
         let mut bitset = 0;
         let mut extensions = BTreeSet::new();
@@ -113,6 +122,13 @@ impl MethodGuard {
                             inner::Method::TRACE,
                             inner::Method::HEAD,
                             inner::Method::CONNECT,
+                            inner::Method::PROPFIND,
+                            inner::Method::PROPPATCH,
+                            inner::Method::MKCOL,
+                            inner::Method::COPY,
+                            inner::Method::MOVE,
+                            inner::Method::LOCK,
+                            inner::Method::UNLOCK,
                         ]
                         .into_iter()
                         .filter(|method| self.allows_(method)),
@@ -196,6 +212,45 @@ pub const CONNECT: MethodGuard = MethodGuard {
     inner: inner::CONNECT,
 };
 
+/// A [`MethodGuard`] that matches incoming requests using one of the methods
+/// defined by WebDAV ([RFC 4918](https://www.rfc-editor.org/rfc/rfc4918#section-9)):
+/// `PROPFIND`, `PROPPATCH`, `MKCOL`, `COPY`, `MOVE`, `LOCK`, `UNLOCK`.
+///
+/// Combine it with other guards to match the methods that WebDAV borrows from HTTP—e.g.
+/// `WEBDAV.or(GET).or(PUT).or(DELETE)`.
+pub const WEBDAV: MethodGuard = MethodGuard {
+    inner: inner::WEBDAV,
+};
+
+/// A [`MethodGuard`] that matches incoming requests using the `PROPFIND` WebDAV method.
+pub const PROPFIND: MethodGuard = MethodGuard {
+    inner: inner::PROPFIND,
+};
+
+/// A [`MethodGuard`] that matches incoming requests using the `PROPPATCH` WebDAV method.
+pub const PROPPATCH: MethodGuard = MethodGuard {
+    inner: inner::PROPPATCH,
+};
+
+/// A [`MethodGuard`] that matches incoming requests using the `MKCOL` WebDAV method.
+pub const MKCOL: MethodGuard = MethodGuard {
+    inner: inner::MKCOL,
+};
+
+/// A [`MethodGuard`] that matches incoming requests using the `COPY` WebDAV method.
+pub const COPY: MethodGuard = MethodGuard { inner: inner::COPY };
+
+/// A [`MethodGuard`] that matches incoming requests using the `MOVE` WebDAV method.
+pub const MOVE: MethodGuard = MethodGuard { inner: inner::MOVE };
+
+/// A [`MethodGuard`] that matches incoming requests using the `LOCK` WebDAV method.
+pub const LOCK: MethodGuard = MethodGuard { inner: inner::LOCK };
+
+/// A [`MethodGuard`] that matches incoming requests using the `UNLOCK` WebDAV method.
+pub const UNLOCK: MethodGuard = MethodGuard {
+    inner: inner::UNLOCK,
+};
+
 mod inner {
     #![allow(clippy::upper_case_acronyms)]
 
@@ -231,6 +286,15 @@ mod inner {
         TRACE,
         HEAD,
         CONNECT,
+        // WebDAV methods get dedicated variants (and bits in `SomeMethodGuard::bitset`)
+        // to be usable in `const` guards.
+        PROPFIND,
+        PROPPATCH,
+        MKCOL,
+        COPY,
+        MOVE,
+        LOCK,
+        UNLOCK,
         Custom(Cow<'a, str>),
     }
 
@@ -246,6 +310,13 @@ mod inner {
                 Method::TRACE => Method::TRACE,
                 Method::HEAD => Method::HEAD,
                 Method::CONNECT => Method::CONNECT,
+                Method::PROPFIND => Method::PROPFIND,
+                Method::PROPPATCH => Method::PROPPATCH,
+                Method::MKCOL => Method::MKCOL,
+                Method::COPY => Method::COPY,
+                Method::MOVE => Method::MOVE,
+                Method::LOCK => Method::LOCK,
+                Method::UNLOCK => Method::UNLOCK,
                 Method::Custom(c) => Method::Custom(Cow::Owned(c.into_owned())),
             }
         }
@@ -263,7 +334,16 @@ mod inner {
                 &http::Method::TRACE => Method::TRACE,
                 &http::Method::HEAD => Method::HEAD,
                 &http::Method::CONNECT => Method::CONNECT,
-                m => Method::Custom(Cow::Borrowed(m.as_str())),
+                m => match m.as_str() {
+                    "PROPFIND" => Method::PROPFIND,
+                    "PROPPATCH" => Method::PROPPATCH,
+                    "MKCOL" => Method::MKCOL,
+                    "COPY" => Method::COPY,
+                    "MOVE" => Method::MOVE,
+                    "LOCK" => Method::LOCK,
+                    "UNLOCK" => Method::UNLOCK,
+                    m => Method::Custom(Cow::Borrowed(m)),
+                },
             }
         }
     }
@@ -286,6 +366,13 @@ mod inner {
                 Method::TRACE => http::Method::TRACE,
                 Method::HEAD => http::Method::HEAD,
                 Method::CONNECT => http::Method::CONNECT,
+                Method::PROPFIND => http::Method::from_str("PROPFIND").unwrap(),
+                Method::PROPPATCH => http::Method::from_str("PROPPATCH").unwrap(),
+                Method::MKCOL => http::Method::from_str("MKCOL").unwrap(),
+                Method::COPY => http::Method::from_str("COPY").unwrap(),
+                Method::MOVE => http::Method::from_str("MOVE").unwrap(),
+                Method::LOCK => http::Method::from_str("LOCK").unwrap(),
+                Method::UNLOCK => http::Method::from_str("UNLOCK").unwrap(),
                 Method::Custom(c) => http::Method::from_str(c.as_ref()).unwrap(),
             }
         }
@@ -311,7 +398,8 @@ mod inner {
 
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     pub(super) struct SomeMethodGuard<'a> {
-        /// A bitset to track which of the 9 well-known HTTP methods are allowed.
+        /// A bitset to track which of the 9 well-known HTTP methods and of the 7 WebDAV methods
+        /// are allowed.
         ///
         /// # Why so complicated?
         ///
@@ -345,7 +433,14 @@ mod inner {
             | &Method::DELETE
             | &Method::TRACE
             | &Method::HEAD
-            | &Method::CONNECT => Some(_method_to_bitset(method)),
+            | &Method::CONNECT
+            | &Method::PROPFIND
+            | &Method::PROPPATCH
+            | &Method::MKCOL
+            | &Method::COPY
+            | &Method::MOVE
+            | &Method::LOCK
+            | &Method::UNLOCK => Some(_method_to_bitset(method)),
             _ => None,
         }
     }
@@ -365,6 +460,13 @@ mod inner {
             Method::TRACE => 0b0000_0000_0000_0100,
             Method::HEAD => 0b0000_0000_0000_0010,
             Method::CONNECT => 0b0000_0000_0000_0001,
+            Method::PROPFIND => 0b0000_0010_0000_0000,
+            Method::PROPPATCH => 0b0000_0100_0000_0000,
+            Method::MKCOL => 0b0000_1000_0000_0000,
+            Method::COPY => 0b0001_0000_0000_0000,
+            Method::MOVE => 0b0010_0000_0000_0000,
+            Method::LOCK => 0b0100_0000_0000_0000,
+            Method::UNLOCK => 0b1000_0000_0000_0000,
             Method::Custom(_) => panic!(),
         }
     }
@@ -381,6 +483,44 @@ mod inner {
     pub(super) const HEAD: MethodGuard = MethodGuard::from_bits(_method_to_bitset(&Method::HEAD));
     pub(super) const CONNECT: MethodGuard =
         MethodGuard::from_bits(_method_to_bitset(&Method::CONNECT));
+    pub(super) const PROPFIND: MethodGuard =
+        MethodGuard::from_bits(_method_to_bitset(&Method::PROPFIND));
+    pub(super) const PROPPATCH: MethodGuard =
+        MethodGuard::from_bits(_method_to_bitset(&Method::PROPPATCH));
+    pub(super) const MKCOL: MethodGuard = MethodGuard::from_bits(_method_to_bitset(&Method::MKCOL));
+    pub(super) const COPY: MethodGuard = MethodGuard::from_bits(_method_to_bitset(&Method::COPY));
+    pub(super) const MOVE: MethodGuard = MethodGuard::from_bits(_method_to_bitset(&Method::MOVE));
+    pub(super) const LOCK: MethodGuard = MethodGuard::from_bits(_method_to_bitset(&Method::LOCK));
+    pub(super) const UNLOCK: MethodGuard =
+        MethodGuard::from_bits(_method_to_bitset(&Method::UNLOCK));
     pub(super) const ANY: MethodGuard = MethodGuard::from_bits(0b0000_0001_1111_1111);
+    pub(super) const WEBDAV: MethodGuard = MethodGuard::from_bits(0b1111_1110_0000_0000);
     pub(super) const ANY_WITH_EXTENSIONS: MethodGuard = MethodGuard::Any;
 }
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use super::{MethodGuard, ANY, GET, PROPFIND, WEBDAV};
+    use crate::router::AllowedMethods;
+
+    #[test]
+    fn webdav_methods_have_dedicated_guards() {
+        let propfind = Method::from_bytes(b"PROPFIND").unwrap();
+        assert!(PROPFIND.allows(&propfind));
+        assert!(WEBDAV.allows(&propfind));
+        assert!(!WEBDAV.allows(&Method::GET));
+        assert!(!ANY.allows(&propfind));
+
+        // A guard built at runtime is equivalent to the constant one.
+        let guard = MethodGuard::from_iter([propfind.clone()])
+            .or(PROPFIND)
+            .or(GET);
+        let AllowedMethods::Some(methods) = guard.allowed_methods() else {
+            panic!("Expected a finite set of methods");
+        };
+        let methods: Vec<_> = methods.into_iter().collect();
+        assert_eq!(methods, vec![Method::GET, propfind]);
+    }
+}
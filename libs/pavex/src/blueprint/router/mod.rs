@@ -6,11 +6,15 @@
 //! for a thorough introduction to routing in Pavex applications.
 pub use fallback::{Fallback, RegisteredFallback};
 pub use method_guard::{
-    MethodGuard, ANY, ANY_WITH_EXTENSIONS, CONNECT, DELETE, GET, HEAD, OPTIONS, PATCH, POST, PUT,
-    TRACE,
+    MethodGuard, ANY, ANY_WITH_EXTENSIONS, CONNECT, COPY, DELETE, GET, HEAD, LOCK, MKCOL, MOVE,
+    OPTIONS, PATCH, POST, PROPFIND, PROPPATCH, PUT, TRACE, UNLOCK, WEBDAV,
 };
 pub use route::{RegisteredRoute, Route};
+pub use trailing_slash::TrailingSlash;
+pub use version::RegisteredApiVersion;
 
 mod fallback;
 mod method_guard;
 mod route;
+mod trailing_slash;
+mod version;
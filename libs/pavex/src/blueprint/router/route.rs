@@ -1,4 +1,5 @@
-use crate::blueprint::conversions::raw_callable2registered_callable;
+use crate::blueprint::constructor::Constructor;
+use crate::blueprint::conversions::{constructor2constructor, raw_callable2registered_callable};
 use crate::blueprint::reflection::RawCallable;
 use crate::blueprint::router::MethodGuard;
 use crate::blueprint::Blueprint;
@@ -61,6 +62,104 @@ impl<'a> RegisteredRoute<'a> {
         self
     }
 
+    /// Give a name to this route.
+    ///
+    /// Pavex generates a function in the `urls` module of your server SDK for each named
+    /// route. It builds the (percent-encoded) path for the route, taking as input the
+    /// path parameters it expects.
+    /// Use it to build links (e.g. `Location` headers) that stay correct when the
+    /// route changes.
+    ///
+    /// Names are made of one or more dot-separated segments—e.g. `user.show`.
+    /// Each segment becomes a module in the generated code, apart from the last one, which
+    /// becomes the function name: `user.show` generates `urls::user::show`.
+    /// Segments must be valid Rust identifiers, in snake case.
+    /// Names must be unique across the whole application.
+    ///
+    /// # Path parameters
+    ///
+    /// If the request handler extracts path parameters via
+    /// [`PathParams<T>`](struct@crate::request::path::PathParams), the generated function
+    /// takes a `&T` as input.
+    /// Otherwise, it takes one argument per path parameter, in the order they appear in the
+    /// route path. Each argument can be of any type that implements [`Display`](std::fmt::Display).
+    ///
+    /// Routes nested under a domain via [`Blueprint::nest_at_domain`]
+    /// get a function that only builds the path: domain captures are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::f;
+    /// use pavex::blueprint::{Blueprint, router::GET};
+    /// use pavex::request::path::PathParams;
+    ///
+    /// #[PathParams]
+    /// pub struct UserParams {
+    ///     pub id: u64,
+    /// }
+    ///
+    /// pub fn get_user(params: &PathParams<UserParams>) {
+    ///     // [...]
+    /// }
+    ///
+    /// # fn main() {
+    /// let mut bp = Blueprint::new();
+    /// bp.route(GET, "/users/:id", f!(crate::get_user))
+    ///     .name("user.show");
+    /// // In the generated server SDK:
+    /// // `urls::user::show(&UserParams { id: 42 })` returns `"/users/42"`.
+    /// # }
+    /// ```
+    pub fn name(mut self, name: &str) -> Self {
+        self.route().name = Some(name.to_owned());
+        self
+    }
+
+    /// Override, for this route only, the constructor registered for a type.
+    ///
+    /// The constructor is used to build the inputs of the request handler for this route,
+    /// taking precedence over the constructors for the same type registered against
+    /// the [`Blueprint`] (or the blueprints it's nested under).
+    /// Middlewares are not affected: they keep using the constructors registered against
+    /// the blueprint they belong to.
+    ///
+    /// The constructor must be either request-scoped or transient: singletons are shared
+    /// by all routes, therefore they can't be overridden for a single one.
+    /// Pavex reports an error if no component in the request processing pipeline for this
+    /// route needs the type built by the constructor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::f;
+    /// use pavex::blueprint::{Blueprint, constructor::{Constructor, Lifecycle}, router::POST};
+    /// use pavex::request::body::BodySizeLimit;
+    /// use pavex::unit::ToByteUnit;
+    /// # pub fn upload() {}
+    ///
+    /// pub fn upload_size_limit() -> BodySizeLimit {
+    ///     BodySizeLimit::Enabled {
+    ///         max_size: 50.megabytes(),
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// let mut bp = Blueprint::new();
+    /// // The default limit, 2 MBs, applies to all routes...
+    /// BodySizeLimit::default_constructor().register(&mut bp);
+    /// // ...apart from this one.
+    /// bp.route(POST, "/uploads", f!(crate::upload))
+    ///     .constructor(Constructor::new(f!(crate::upload_size_limit), Lifecycle::RequestScoped));
+    /// # }
+    /// ```
+    pub fn constructor(mut self, constructor: Constructor) -> Self {
+        self.route()
+            .constructors
+            .push(constructor2constructor(constructor));
+        self
+    }
+
     fn route(&mut self) -> &mut pavex_bp_schema::Route {
         let component = &mut self.blueprint.components[self.component_id];
         let Component::Route(c) = component else {
@@ -89,6 +188,8 @@ pub struct Route {
     pub(in crate::blueprint) path: String,
     pub(in crate::blueprint) callable: Callable,
     pub(in crate::blueprint) error_handler: Option<Callable>,
+    pub(in crate::blueprint) name: Option<String>,
+    pub(in crate::blueprint) constructors: Vec<pavex_bp_schema::Constructor>,
 }
 
 impl Route {
@@ -101,6 +202,8 @@ impl Route {
         Self {
             callable: raw_callable2registered_callable(callable),
             error_handler: None,
+            name: None,
+            constructors: Vec::new(),
             method_guard,
             path: path.to_owned(),
         }
@@ -115,6 +218,22 @@ impl Route {
         self
     }
 
+    /// Give a name to this route.
+    ///
+    /// Check out the documentation of [`RegisteredRoute::name`] for more details.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Override, for this route only, the constructor registered for a type.
+    ///
+    /// Check out the documentation of [`RegisteredRoute::constructor`] for more details.
+    pub fn constructor(mut self, constructor: Constructor) -> Self {
+        self.constructors.push(constructor2constructor(constructor));
+        self
+    }
+
     /// Register this route with a [`Blueprint`].
     ///
     /// Check out the documentation of [`Blueprint::route`] for more details.
@@ -0,0 +1,27 @@
+/// How Pavex's router handles requests whose path only differs from a registered route
+/// by a trailing slash—e.g. `/users/` when only `/users` is registered, or vice versa.
+///
+/// Use [`Blueprint::trailing_slash`] to pick a policy for your application.
+///
+/// [`Blueprint::trailing_slash`]: crate::blueprint::Blueprint::trailing_slash
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrailingSlash {
+    /// `/users` and `/users/` are distinct paths: a request only matches a route if its
+    /// path is exactly the one that was registered.
+    ///
+    /// This is the default policy.
+    #[default]
+    Strict,
+    /// Requests for the other form of a registered path are redirected, with a
+    /// `308 Permanent Redirect`, to the registered (canonical) path.
+    /// The query string, if any, is preserved.
+    ///
+    /// Nothing happens if both forms have been registered: each one is handled by its own route.
+    Redirect,
+    /// Requests for either form of a registered path are handled by the registered route.
+    ///
+    /// You can't register routes for both `/users` and `/users/`: Pavex will report
+    /// a conflict.
+    MatchBoth,
+}
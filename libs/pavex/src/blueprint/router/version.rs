@@ -0,0 +1,128 @@
+use crate::blueprint::conversions::method_guard2method_guard;
+use crate::blueprint::router::MethodGuard;
+use pavex_bp_schema::{
+    ApiVersion, Blueprint as BlueprintSchema, Component, InheritedVersion, RemovedRoute,
+    VersionSelector,
+};
+use pavex_reflection::Location;
+
+/// The type returned by [`Blueprint::nest_version`].
+///
+/// It allows you to further configure the behaviour of the registered API version.
+///
+/// [`Blueprint::nest_version`]: crate::blueprint::Blueprint::nest_version
+pub struct RegisteredApiVersion<'a> {
+    pub(crate) blueprint: &'a mut BlueprintSchema,
+    /// The index of the nested blueprint in the parent blueprint's `components` vector.
+    pub(crate) component_id: usize,
+}
+
+impl<'a> RegisteredApiVersion<'a> {
+    #[track_caller]
+    /// Expose all the routes of another version of the API, on top of the ones registered
+    /// against the blueprint of this version.
+    ///
+    /// The inherited version must be nested, via [`Blueprint::nest_version`], against the same
+    /// parent blueprint.
+    ///
+    /// # Overrides
+    ///
+    /// If this version registers a route for the same path and method guard of an inherited
+    /// route, the inherited route is ignored.
+    /// Use [`RegisteredApiVersion::remove_route`] to stop exposing an inherited route without
+    /// replacing it.
+    ///
+    /// # Components
+    ///
+    /// Inherited routes are processed using the constructors, middlewares and error observers
+    /// they were registered with in the inherited version.
+    /// The middlewares registered against the blueprint of this version wrap around
+    /// inherited routes too, while the fallback of this version is used for inherited paths.
+    ///
+    /// [`Blueprint::nest_version`]: crate::blueprint::Blueprint::nest_version
+    pub fn inherit_from(mut self, version: &str) -> Self {
+        self.version().inherits_from = Some(InheritedVersion {
+            name: version.into(),
+            location: Location::caller(),
+        });
+        self
+    }
+
+    #[track_caller]
+    /// Don't expose one of the inherited routes.
+    ///
+    /// The path is relative to the version prefix, and both path and method guard must match
+    /// the ones the route was registered with in the inherited version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::blueprint::{Blueprint, router::DELETE};
+    /// # fn v1() -> Blueprint { Blueprint::new() }
+    /// # fn v2() -> Blueprint { Blueprint::new() }
+    ///
+    /// # fn main() {
+    /// let mut bp = Blueprint::new();
+    /// bp.nest_version("v1", v1());
+    /// bp.nest_version("v2", v2())
+    ///     .inherit_from("v1")
+    ///     // `DELETE /v1/users/:id` is not available as `DELETE /v2/users/:id`.
+    ///     .remove_route(DELETE, "/users/:id");
+    /// # }
+    /// ```
+    pub fn remove_route(mut self, method_guard: MethodGuard, path: &str) -> Self {
+        self.version().removed_routes.push(RemovedRoute {
+            path: path.into(),
+            method_guard: method_guard2method_guard(method_guard),
+            location: Location::caller(),
+        });
+        self
+    }
+
+    /// Route requests to this version when they carry a header with the specified value,
+    /// even if their path doesn't start with the version prefix.
+    ///
+    /// E.g. with `.select_by_header("Api-Version", "2")`, a `GET /users` request with
+    /// an `Api-Version: 2` header is routed as if it targeted `GET /v2/users`.
+    /// Header values are compared case-insensitively.
+    ///
+    /// Requests that don't match any route of this version are routed as usual,
+    /// using their path.
+    ///
+    /// If a selector has already been set for this version, it will be overwritten.
+    pub fn select_by_header(mut self, name: &str, value: &str) -> Self {
+        self.version().selector = Some(VersionSelector::Header {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Route requests to this version when their `Accept` header includes the specified
+    /// media type, even if their path doesn't start with the version prefix.
+    ///
+    /// E.g. with `.select_by_media_type("application/vnd.example.v2+json")`, a `GET /users`
+    /// request with an `Accept: application/vnd.example.v2+json` header is routed as if it
+    /// targeted `GET /v2/users`.
+    /// Media type parameters (e.g. `; q=0.9`) are ignored.
+    ///
+    /// Requests that don't match any route of this version are routed as usual,
+    /// using their path.
+    ///
+    /// If a selector has already been set for this version, it will be overwritten.
+    pub fn select_by_media_type(mut self, media_type: &str) -> Self {
+        self.version().selector = Some(VersionSelector::MediaType(media_type.into()));
+        self
+    }
+
+    fn version(&mut self) -> &mut ApiVersion {
+        let component = &mut self.blueprint.components[self.component_id];
+        let Component::NestedBlueprint(nested_bp) = component else {
+            unreachable!("The component should be a nested blueprint")
+        };
+        nested_bp
+            .version
+            .as_mut()
+            .expect("The nested blueprint should be a version of the API")
+    }
+}
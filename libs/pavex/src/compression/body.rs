@@ -0,0 +1,174 @@
+use std::io::Write;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use bytes::Bytes;
+use http::HeaderMap;
+use http_body::{Frame, SizeHint};
+
+use crate::response::body::raw::RawBody;
+use crate::response::ResponseBody;
+
+use super::{CompressionLevel, Encoding};
+
+/// A response body compressed on the fly, frame by frame.
+///
+/// Each data frame is flushed through the encoder as soon as it's received, to
+/// preserve the latency characteristics of streaming responses.
+pub(super) struct CompressedBody {
+    inner: ResponseBody,
+    encoder: Option<Encoder>,
+    trailers: Option<HeaderMap>,
+}
+
+impl CompressedBody {
+    pub(super) fn new(inner: ResponseBody, encoder: Encoder) -> Self {
+        Self {
+            inner,
+            encoder: Some(encoder),
+            trailers: None,
+        }
+    }
+}
+
+impl RawBody for CompressedBody {
+    type Data = Bytes;
+    type Error = crate::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = &mut *self;
+        loop {
+            let Some(encoder) = this.encoder.as_mut() else {
+                return Poll::Ready(this.trailers.take().map(|t| Ok(Frame::trailers(t))));
+            };
+            match ready!(Pin::new(&mut this.inner).poll_frame(cx)) {
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(data) => match encoder.encode(&data) {
+                        Ok(compressed) if compressed.is_empty() => continue,
+                        Ok(compressed) => return Poll::Ready(Some(Ok(Frame::data(compressed)))),
+                        Err(e) => return Poll::Ready(Some(Err(crate::Error::new(e)))),
+                    },
+                    Err(frame) => {
+                        // Trailers must come after the compressed data: we hold on to them
+                        // until the encoder has been finalized.
+                        if let Ok(trailers) = frame.into_trailers() {
+                            this.trailers = Some(trailers);
+                        }
+                    }
+                },
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => {
+                    let encoder = this.encoder.take().expect("The encoder is always set here");
+                    return match encoder.finish() {
+                        Ok(compressed) => Poll::Ready(Some(Ok(Frame::data(compressed)))),
+                        Err(e) => Poll::Ready(Some(Err(crate::Error::new(e)))),
+                    };
+                }
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.encoder.is_none() && self.trailers.is_none()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        // The size of the compressed body can't be known in advance.
+        SizeHint::default()
+    }
+}
+
+/// A streaming encoder, writing its output to an in-memory buffer.
+pub(super) enum Encoder {
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, Vec<u8>>),
+    #[cfg(feature = "br")]
+    Brotli(Box<brotli::CompressorWriter<Vec<u8>>>),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<Vec<u8>>),
+}
+
+impl Encoder {
+    pub(super) fn new(encoding: Encoding, level: CompressionLevel) -> std::io::Result<Self> {
+        let encoder = match encoding {
+            #[cfg(feature = "zstd")]
+            Encoding::Zstd => {
+                let level = match level {
+                    CompressionLevel::Fastest => 1,
+                    CompressionLevel::Default => 3,
+                    CompressionLevel::Best => 19,
+                };
+                Encoder::Zstd(zstd::stream::write::Encoder::new(Vec::new(), level)?)
+            }
+            #[cfg(feature = "br")]
+            Encoding::Brotli => {
+                let quality = match level {
+                    CompressionLevel::Fastest => 1,
+                    // Brotli's own default (11) is too slow for on-the-fly compression.
+                    CompressionLevel::Default => 4,
+                    CompressionLevel::Best => 11,
+                };
+                Encoder::Brotli(Box::new(brotli::CompressorWriter::new(
+                    Vec::new(),
+                    4096,
+                    quality,
+                    22,
+                )))
+            }
+            #[cfg(feature = "gzip")]
+            Encoding::Gzip => {
+                let level = match level {
+                    CompressionLevel::Fastest => flate2::Compression::fast(),
+                    CompressionLevel::Default => flate2::Compression::default(),
+                    CompressionLevel::Best => flate2::Compression::best(),
+                };
+                Encoder::Gzip(flate2::write::GzEncoder::new(Vec::new(), level))
+            }
+        };
+        Ok(encoder)
+    }
+
+    /// Compress `data`, returning all the output produced so far.
+    fn encode(&mut self, data: &[u8]) -> std::io::Result<Bytes> {
+        // Only one arm is left when a single encoding is enabled.
+        #[allow(clippy::infallible_destructuring_match)]
+        let writer: &mut dyn Write = match self {
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(e) => e,
+            #[cfg(feature = "br")]
+            Encoder::Brotli(e) => e.as_mut(),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(e) => e,
+        };
+        writer.write_all(data)?;
+        writer.flush()?;
+        Ok(Bytes::from(std::mem::take(self.buffer())))
+    }
+
+    /// Finalize the compressed stream, returning the remaining output.
+    fn finish(self) -> std::io::Result<Bytes> {
+        let buffer = match self {
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(e) => e.finish()?,
+            #[cfg(feature = "br")]
+            Encoder::Brotli(e) => e.into_inner(),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(e) => e.finish()?,
+        };
+        Ok(Bytes::from(buffer))
+    }
+
+    fn buffer(&mut self) -> &mut Vec<u8> {
+        match self {
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(e) => e.get_mut(),
+            #[cfg(feature = "br")]
+            Encoder::Brotli(e) => e.get_mut(),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(e) => e.get_mut(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use ubyte::ToByteUnit;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+use crate::unit::ByteUnit;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+/// Configuration for [`compression_middleware`](super::compression_middleware).
+///
+/// # Configuration
+///
+/// [`CompressionConfig`] implements [`serde::Deserialize`], so you can load it as part
+/// of your application configuration.
+pub struct CompressionConfig {
+    /// Responses whose body is known to be smaller than this are not compressed:
+    /// the savings wouldn't be worth the CPU time.
+    ///
+    /// Defaults to 1 KiB.
+    pub min_size: ByteUnit,
+    /// The trade-off between compression speed and compression ratio.
+    ///
+    /// Defaults to [`CompressionLevel::Default`].
+    pub level: CompressionLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// The trade-off between compression speed and compression ratio.
+///
+/// The exact meaning of each level depends on the chosen [`Encoding`](super::Encoding).
+pub enum CompressionLevel {
+    /// Compress as fast as possible.
+    Fastest,
+    /// A balance between speed and compression ratio that works well for
+    /// on-the-fly compression.
+    #[default]
+    Default,
+    /// Compress as much as possible, regardless of the cost.
+    Best,
+}
+
+impl CompressionConfig {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`CompressionConfig`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::default) for [`CompressionConfig`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(<pavex::compression::CompressionConfig as std::default::Default>::default),
+            Lifecycle::Singleton,
+        )
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            min_size: 1.kibibytes(),
+            level: CompressionLevel::Default,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompressionConfig, CompressionLevel};
+    use ubyte::ToByteUnit;
+
+    #[test]
+    fn deserialize_with_defaults() {
+        let config: CompressionConfig =
+            serde_json::from_str(r#"{ "min_size": "2 KiB", "level": "best" }"#).unwrap();
+        assert_eq!(config.min_size, 2.kibibytes());
+        assert_eq!(config.level, CompressionLevel::Best);
+
+        let config: CompressionConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, CompressionConfig::default());
+    }
+}
@@ -0,0 +1,134 @@
+use http::header::ACCEPT_ENCODING;
+use http::HeaderMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// A content encoding supported by [`compression_middleware`](super::compression_middleware).
+///
+/// Each variant is only available if the corresponding feature flag is enabled.
+pub enum Encoding {
+    /// Zstandard (`zstd`).
+    #[cfg(feature = "zstd")]
+    Zstd,
+    /// Brotli (`br`).
+    #[cfg(feature = "br")]
+    Brotli,
+    /// Gzip (`gzip`).
+    #[cfg(feature = "gzip")]
+    Gzip,
+}
+
+impl Encoding {
+    /// All the enabled encodings, in order of preference.
+    const ENABLED: &'static [Encoding] = &[
+        #[cfg(feature = "zstd")]
+        Encoding::Zstd,
+        #[cfg(feature = "br")]
+        Encoding::Brotli,
+        #[cfg(feature = "gzip")]
+        Encoding::Gzip,
+    ];
+
+    /// The token used to identify the encoding in `Accept-Encoding` and `Content-Encoding`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "zstd")]
+            Encoding::Zstd => "zstd",
+            #[cfg(feature = "br")]
+            Encoding::Brotli => "br",
+            #[cfg(feature = "gzip")]
+            Encoding::Gzip => "gzip",
+        }
+    }
+
+    /// Pick the encoding to use for a request with the given headers.
+    ///
+    /// It returns `None` if the client doesn't accept any of the enabled encodings.
+    pub fn negotiate(request_headers: &HeaderMap) -> Option<Self> {
+        let mut wildcard = None;
+        let mut explicit: Vec<(&str, f32)> = Vec::new();
+        for (token, q) in request_headers
+            .get_all(ACCEPT_ENCODING)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .filter_map(parse_item)
+        {
+            if token == "*" {
+                wildcard = Some(q);
+            } else {
+                explicit.push((token, q));
+            }
+        }
+
+        let mut best: Option<(Encoding, f32)> = None;
+        for encoding in Self::ENABLED {
+            let q = explicit
+                .iter()
+                .find(|(token, _)| {
+                    token.eq_ignore_ascii_case(encoding.as_str())
+                        // `x-gzip` is an alias for `gzip`.
+                        || (encoding.as_str() == "gzip" && token.eq_ignore_ascii_case("x-gzip"))
+                })
+                .map(|(_, q)| *q)
+                .or(wildcard)
+                .unwrap_or(0.0);
+            // Ties are broken by preference order, so we only move on strictly
+            // higher quality values.
+            let is_better = match best {
+                Some((_, best_q)) => q > best_q,
+                None => q > 0.0,
+            };
+            if is_better {
+                best = Some((*encoding, q));
+            }
+        }
+        best.map(|(encoding, _)| encoding)
+    }
+}
+
+/// Parse an item of the `Accept-Encoding` list (e.g. `gzip;q=0.8`).
+fn parse_item(item: &str) -> Option<(&str, f32)> {
+    let mut parts = item.split(';');
+    let token = parts.next()?.trim();
+    if token.is_empty() {
+        return None;
+    }
+    let mut q = 1.0;
+    for param in parts {
+        if let Some((name, value)) = param.split_once('=') {
+            if name.trim().eq_ignore_ascii_case("q") {
+                q = value.trim().parse::<f32>().ok()?.clamp(0.0, 1.0);
+            }
+        }
+    }
+    Some((token, q))
+}
+
+#[cfg(all(test, feature = "gzip", feature = "br", feature = "zstd"))]
+mod tests {
+    use super::Encoding;
+    use http::header::ACCEPT_ENCODING;
+    use http::{HeaderMap, HeaderValue};
+
+    fn negotiate(accept_encoding: &str) -> Option<Encoding> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_str(accept_encoding).unwrap(),
+        );
+        Encoding::negotiate(&headers)
+    }
+
+    #[test]
+    fn negotiation() {
+        assert_eq!(negotiate("gzip, deflate, br"), Some(Encoding::Brotli));
+        assert_eq!(negotiate("gzip, br, zstd"), Some(Encoding::Zstd));
+        assert_eq!(negotiate("gzip;q=1, br;q=0.5"), Some(Encoding::Gzip));
+        assert_eq!(negotiate("x-gzip"), Some(Encoding::Gzip));
+        assert_eq!(negotiate("*;q=0.1, zstd;q=0"), Some(Encoding::Brotli));
+        assert_eq!(negotiate("identity"), None);
+        assert_eq!(negotiate("gzip;q=0"), None);
+        assert_eq!(Encoding::negotiate(&HeaderMap::new()), None);
+    }
+}
@@ -0,0 +1,241 @@
+use std::future::IntoFuture;
+
+use http::header::{
+    ACCEPT_ENCODING, ACCEPT_RANGES, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE,
+    CONTENT_TYPE, ETAG,
+};
+use http::{HeaderValue, Method, StatusCode};
+
+use crate::middleware::Next;
+use crate::request::RequestHead;
+use crate::response::body::raw::RawBody;
+use crate::response::{Response, ResponseBody};
+
+use super::body::{CompressedBody, Encoder};
+use super::{CompressionConfig, Encoding};
+
+/// Compress the body of outgoing responses.
+///
+/// Check out the [module-level documentation](super) for more details.
+pub async fn compression_middleware<C>(
+    next: Next<C>,
+    request_head: &RequestHead,
+    config: &CompressionConfig,
+) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    let response = next.await;
+    compress(response, request_head, config)
+}
+
+fn compress(
+    response: Response,
+    request_head: &RequestHead,
+    config: &CompressionConfig,
+) -> Response {
+    if !is_compressible(&response, request_head) {
+        return response;
+    }
+    if let Some(size) = response.body().size_hint().exact() {
+        if size < config.min_size.as_u64() {
+            return response;
+        }
+    }
+    let mut response = response.append_vary(ACCEPT_ENCODING);
+    let Some(encoding) = Encoding::negotiate(&request_head.headers) else {
+        return response;
+    };
+    let encoder = match Encoder::new(encoding, config.level) {
+        Ok(encoder) => encoder,
+        Err(e) => {
+            tracing::warn!(
+                error.msg = %e,
+                error.details = ?e,
+                encoding = encoding.as_str(),
+                "Failed to initialize the encoder, the response will not be compressed"
+            );
+            return response;
+        }
+    };
+
+    let headers = response.headers_mut();
+    headers.remove(CONTENT_LENGTH);
+    headers.remove(ACCEPT_RANGES);
+    headers.insert(
+        CONTENT_ENCODING,
+        HeaderValue::from_static(encoding.as_str()),
+    );
+    if let Some(etag) = headers.get(ETAG) {
+        if etag.as_bytes().starts_with(b"\"") {
+            let mut weak = b"W/".to_vec();
+            weak.extend_from_slice(etag.as_bytes());
+            if let Ok(weak) = HeaderValue::from_bytes(&weak) {
+                headers.insert(ETAG, weak);
+            }
+        }
+    }
+
+    let (head, body) = response.into_parts();
+    Response::from_parts(head, ResponseBody::new(CompressedBody::new(body, encoder)))
+}
+
+/// Returns `false` if the response must be sent as it is, no matter what
+/// the client accepts.
+fn is_compressible(response: &Response, request_head: &RequestHead) -> bool {
+    if request_head.method == Method::HEAD {
+        return false;
+    }
+    let status = response.status();
+    if status.is_informational()
+        || status == StatusCode::NO_CONTENT
+        || status == StatusCode::NOT_MODIFIED
+        || status == StatusCode::PARTIAL_CONTENT
+    {
+        return false;
+    }
+    let headers = response.headers();
+    if headers.contains_key(CONTENT_ENCODING) || headers.contains_key(CONTENT_RANGE) {
+        return false;
+    }
+    let no_transform = headers
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-transform"));
+    if no_transform {
+        return false;
+    }
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<mime::Mime>().ok())
+        .is_some_and(|mime| is_compressible_mime(&mime))
+}
+
+/// Returns `false` for media types that are already compressed.
+fn is_compressible_mime(mime: &mime::Mime) -> bool {
+    match (mime.type_().as_str(), mime.subtype().as_str()) {
+        ("image", "svg") => true,
+        ("image" | "audio" | "video", _) => false,
+        ("font", subtype) => !subtype.starts_with("woff"),
+        (
+            "application",
+            "zip" | "gzip" | "x-gzip" | "zstd" | "x-bzip2" | "x-7z-compressed" | "x-rar-compressed"
+            | "x-xz" | "wasm" | "octet-stream" | "pdf" | "grpc",
+        ) => false,
+        _ => true,
+    }
+}
+
+#[cfg(all(test, feature = "gzip"))]
+mod tests {
+    use std::io::Read;
+
+    use http::header::{CONTENT_ENCODING, CONTENT_LENGTH, ETAG, VARY};
+    use http::{HeaderValue, Method, StatusCode};
+    use http_body_util::BodyExt;
+
+    use super::compression_middleware;
+    use crate::compression::CompressionConfig;
+    use crate::middleware::Next;
+    use crate::request::test_utils::request_head;
+    use crate::response::body::raw::{Bytes, Full};
+    use crate::response::Response;
+
+    fn text(len: usize) -> String {
+        "hello, world! ".repeat(len / 14 + 1)[..len].to_owned()
+    }
+
+    async fn run(method: Method, accept_encoding: &str, response: Response) -> Response {
+        let head = request_head(method, "/", &[("accept-encoding", accept_encoding)]);
+        let next = Next::new(async { response });
+        compression_middleware(next, &head, &CompressionConfig::default()).await
+    }
+
+    async fn gunzip(response: Response) -> String {
+        let (_, body) = response.into_parts();
+        let compressed = body.collect().await.unwrap().to_bytes();
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(&compressed[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        decoded
+    }
+
+    #[tokio::test]
+    async fn large_bodies_are_compressed() {
+        let body = text(4096);
+        let response = Response::ok()
+            .set_typed_body(body.clone())
+            .insert_header(ETAG, HeaderValue::from_static("\"v1\""));
+        let response = run(Method::GET, "gzip", response).await;
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+        assert_eq!(response.headers()[VARY], "accept-encoding");
+        assert_eq!(response.headers()[ETAG], "W/\"v1\"");
+        assert!(response.headers().get(CONTENT_LENGTH).is_none());
+        assert_eq!(gunzip(response).await, body);
+    }
+
+    #[tokio::test]
+    async fn streaming_bodies_are_compressed() {
+        let chunks = vec![text(10), text(20), text(30)];
+        let stream = futures_util::stream::iter(
+            chunks
+                .clone()
+                .into_iter()
+                .map(|c| Ok::<_, std::io::Error>(http_body::Frame::data(Bytes::from(c)))),
+        );
+        let response = Response::ok()
+            .set_raw_body(http_body_util::StreamBody::new(stream))
+            .insert_header(
+                http::header::CONTENT_TYPE,
+                HeaderValue::from_static("text/event-stream"),
+            );
+        let response = run(Method::GET, "gzip", response).await;
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+        assert_eq!(gunzip(response).await, chunks.concat());
+    }
+
+    #[tokio::test]
+    async fn small_bodies_are_left_alone() {
+        let response = Response::ok().set_typed_body(text(100));
+        let response = run(Method::GET, "gzip", response).await;
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+        // The decision doesn't depend on `Accept-Encoding`.
+        assert!(response.headers().get(VARY).is_none());
+    }
+
+    #[tokio::test]
+    async fn incompressible_responses_are_left_alone() {
+        let png = Response::ok().set_raw_body(Full::new(Bytes::from(vec![0; 4096])));
+        let png = png.insert_header(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("image/png"),
+        );
+        let response = run(Method::GET, "gzip", png).await;
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+
+        let no_content = Response::no_content();
+        let response = run(Method::GET, "gzip", no_content).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+
+        let response = run(
+            Method::HEAD,
+            "gzip",
+            Response::ok().set_typed_body(text(4096)),
+        )
+        .await;
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+    }
+
+    #[tokio::test]
+    async fn clients_that_dont_accept_compression_get_vary() {
+        let response = Response::ok().set_typed_body(text(4096));
+        let response = run(Method::GET, "identity", response).await;
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(response.headers()[VARY], "accept-encoding");
+    }
+}
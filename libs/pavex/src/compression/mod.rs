@@ -0,0 +1,64 @@
+//! Response compression.
+//!
+//! # Overview
+//!
+//! [`compression_middleware`] compresses the body of outgoing responses, using the
+//! best encoding supported by both the client (as advertised via `Accept-Encoding`)
+//! and your application.
+//!
+//! ```rust
+//! use pavex::blueprint::Blueprint;
+//! use pavex::compression::CompressionConfig;
+//! use pavex::f;
+//!
+//! let mut bp = Blueprint::new();
+//! CompressionConfig::register(&mut bp);
+//! bp.wrap(f!(pavex::compression::compression_middleware));
+//! // Register your routes here...
+//! ```
+//!
+//! # Encodings
+//!
+//! Each encoding is behind its own feature flag, none of which is enabled by default.
+//! This module is only available if at least one of them is enabled.
+//!
+//! | Feature | Encoding  |
+//! |---------|-----------|
+//! | `zstd`  | `zstd`    |
+//! | `br`    | `br`      |
+//! | `gzip`  | `gzip`    |
+//!
+//! When the client accepts more than one of them with the same preference, they are
+//! picked in the order listed in the table above.
+//!
+//! # Skipped responses
+//!
+//! The body is left untouched if:
+//!
+//! - the response is already encoded (i.e. it has a `Content-Encoding` header);
+//! - the response has no body (e.g. `204 No Content`, `304 Not Modified`, responses to
+//!   `HEAD` requests) or it's a partial response (`206 Partial Content`);
+//! - `Cache-Control` includes the `no-transform` directive;
+//! - the content type is missing or it's already compressed (e.g. `image/png`, `application/zip`);
+//! - the size of the body is known in advance and it's smaller than
+//!   [`CompressionConfig::min_size`].
+//!
+//! Bodies whose size is not known in advance (e.g. streaming responses) are compressed
+//! on the fly, chunk by chunk.
+//!
+//! # Headers
+//!
+//! When the body is compressed, `Content-Encoding` is set to the chosen encoding,
+//! `Content-Length` and `Accept-Ranges` are removed and strong `ETag`s are turned
+//! into weak ones—the compressed representation is not byte-for-byte identical to the
+//! original one.
+//! `Vary: accept-encoding` is added to all compressible responses, whether they were
+//! compressed or not.
+pub use config::{CompressionConfig, CompressionLevel};
+pub use encoding::Encoding;
+pub use middleware::compression_middleware;
+
+mod body;
+mod config;
+mod encoding;
+mod middleware;
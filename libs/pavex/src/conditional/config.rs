@@ -0,0 +1,51 @@
+use ubyte::ToByteUnit;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+use crate::unit::ByteUnit;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+/// Configuration for [`conditional_middleware`](super::conditional_middleware).
+///
+/// # Configuration
+///
+/// [`ConditionalConfig`] implements [`serde::Deserialize`], so you can load it as part
+/// of your application configuration.
+pub struct ConditionalConfig {
+    /// Compute a strong `ETag` for responses that don't set one, by hashing their body.
+    ///
+    /// Defaults to `true`.
+    pub compute_etag: bool,
+    /// Bodies larger than this (or whose size is not known in advance, e.g. streaming
+    /// responses) are never hashed.
+    ///
+    /// Defaults to 1 MiB.
+    pub max_hashed_body_size: ByteUnit,
+}
+
+impl ConditionalConfig {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`ConditionalConfig`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::default) for [`ConditionalConfig`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(<pavex::conditional::ConditionalConfig as std::default::Default>::default),
+            Lifecycle::Singleton,
+        )
+    }
+}
+
+impl Default for ConditionalConfig {
+    fn default() -> Self {
+        Self {
+            compute_etag: true,
+            max_hashed_body_size: 1.mebibytes(),
+        }
+    }
+}
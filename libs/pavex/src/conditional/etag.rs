@@ -0,0 +1,206 @@
+use std::fmt;
+
+use http::HeaderValue;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An entity tag, the value of the `ETag` header.
+///
+/// It identifies a specific version of a resource representation.
+///
+/// # Strong and weak tags
+///
+/// A strong tag changes whenever the representation changes, even by a single byte.
+/// A weak tag (`W/"..."`) only changes when the representation changes in a
+/// semantically significant way—e.g. a re-encoding of the same content may keep
+/// the same weak tag.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::conditional::ETag;
+///
+/// let etag = ETag::strong("v1");
+/// assert_eq!(etag.to_string(), r#""v1""#);
+///
+/// let parsed: ETag = r#"W/"v1""#.parse().unwrap();
+/// assert!(parsed.is_weak());
+/// assert!(parsed.weak_eq(&etag));
+/// assert!(!parsed.strong_eq(&etag));
+/// ```
+pub struct ETag {
+    weak: bool,
+    tag: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`{0}` is not a valid entity tag")]
+/// The error returned when parsing an invalid entity tag.
+pub struct InvalidETag(String);
+
+impl ETag {
+    /// Create a new strong entity tag.
+    ///
+    /// `tag` is the opaque part of the entity tag, without the surrounding quotes.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `tag` contains characters that are not allowed in an entity tag—e.g. `"`.
+    pub fn strong(tag: impl Into<String>) -> Self {
+        Self::new(false, tag.into())
+    }
+
+    /// Create a new weak entity tag.
+    ///
+    /// `tag` is the opaque part of the entity tag, without the surrounding quotes
+    /// and the `W/` prefix.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `tag` contains characters that are not allowed in an entity tag—e.g. `"`.
+    pub fn weak(tag: impl Into<String>) -> Self {
+        Self::new(true, tag.into())
+    }
+
+    /// Compute a strong entity tag by hashing `content`.
+    pub fn from_content(content: &[u8]) -> Self {
+        let digest = Sha256::digest(content);
+        // 128 bits are more than enough to tell versions apart.
+        let tag = digest[..16].iter().map(|b| format!("{b:02x}")).collect();
+        Self { weak: false, tag }
+    }
+
+    fn new(weak: bool, tag: String) -> Self {
+        if !tag.bytes().all(is_etag_char) {
+            panic!("`{tag}` contains characters that are not allowed in an entity tag");
+        }
+        Self { weak, tag }
+    }
+
+    /// Returns `true` if this is a weak entity tag.
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    /// The opaque part of the entity tag, without quotes and `W/` prefix.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// The strong comparison function: both tags must be strong and identical.
+    ///
+    /// It's used to evaluate `If-Match` and `If-Range`.
+    pub fn strong_eq(&self, other: &ETag) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    /// The weak comparison function: the opaque tags must be identical,
+    /// regardless of their strength.
+    ///
+    /// It's used to evaluate `If-None-Match`.
+    pub fn weak_eq(&self, other: &ETag) -> bool {
+        self.tag == other.tag
+    }
+
+    /// Convert the entity tag into a header value, ready to be used as `ETag`.
+    pub fn to_header_value(&self) -> HeaderValue {
+        HeaderValue::try_from(self.to_string())
+            .expect("An entity tag is always a valid header value")
+    }
+}
+
+impl fmt::Display for ETag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.weak {
+            write!(f, "W/")?;
+        }
+        write!(f, "\"{}\"", self.tag)
+    }
+}
+
+impl std::str::FromStr for ETag {
+    type Err = InvalidETag;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let (weak, quoted) = match trimmed.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, trimmed),
+        };
+        let tag = quoted
+            .strip_prefix('"')
+            .and_then(|q| q.strip_suffix('"'))
+            .filter(|tag| tag.bytes().all(is_etag_char))
+            .ok_or_else(|| InvalidETag(s.to_owned()))?;
+        Ok(Self {
+            weak,
+            tag: tag.to_owned(),
+        })
+    }
+}
+
+/// `etagc = %x21 / %x23-7E / obs-text`, as specified in RFC 9110 §8.8.3.
+fn is_etag_char(b: u8) -> bool {
+    b == 0x21 || (0x23..=0x7E).contains(&b) || b >= 0x80
+}
+
+/// The value of `If-Match` or `If-None-Match`.
+pub(super) enum ETagList {
+    /// `*`: any current representation.
+    Any,
+    Tags(Vec<ETag>),
+}
+
+impl ETagList {
+    /// Parse a comma-separated list of entity tags.
+    /// Malformed entries are ignored.
+    pub(super) fn parse(value: &str) -> Self {
+        if value.trim() == "*" {
+            return Self::Any;
+        }
+        Self::Tags(
+            value
+                .split(',')
+                .filter_map(|tag| tag.parse().ok())
+                .collect(),
+        )
+    }
+
+    pub(super) fn matches(&self, etag: Option<&ETag>, strong: bool) -> bool {
+        match (self, etag) {
+            (ETagList::Any, _) => true,
+            (ETagList::Tags(_), None) => false,
+            (ETagList::Tags(tags), Some(etag)) => tags.iter().any(|candidate| {
+                if strong {
+                    candidate.strong_eq(etag)
+                } else {
+                    candidate.weak_eq(etag)
+                }
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ETag;
+
+    #[test]
+    fn parsing_round_trips() {
+        for raw in [r#""abc""#, r#"W/"abc""#, r#""""#] {
+            let etag: ETag = raw.parse().unwrap();
+            assert_eq!(etag.to_string(), raw);
+        }
+        for raw in ["abc", r#""a"b""#, r#"w/"abc""#, r#""abc"#] {
+            assert!(raw.parse::<ETag>().is_err(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn content_hashes_are_stable() {
+        let etag = ETag::from_content(b"hello");
+        assert!(!etag.is_weak());
+        assert_eq!(etag.tag(), "2cf24dba5fb0a30e26e83b2ac5b9e29e");
+        assert_ne!(etag, ETag::from_content(b"hello!"));
+    }
+}
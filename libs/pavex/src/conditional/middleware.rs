@@ -0,0 +1,170 @@
+use std::future::IntoFuture;
+
+use bytes::Bytes;
+use http::header::{CACHE_CONTROL, CONTENT_LOCATION, DATE, ETAG, EXPIRES, LAST_MODIFIED, VARY};
+use http::{Method, StatusCode};
+use http_body_util::{BodyExt, Full};
+
+use crate::middleware::Next;
+use crate::request::RequestHead;
+use crate::response::body::raw::RawBody;
+use crate::response::Response;
+
+use super::{evaluate_preconditions, ConditionalConfig, ETag, Precondition};
+
+/// Turn successful responses to conditional `GET` and `HEAD` requests into
+/// `304 Not Modified` or `412 Precondition Failed`, when appropriate.
+///
+/// The validators are taken from the `ETag` and `Last-Modified` headers of the
+/// response returned by the rest of the request processing pipeline.
+/// If there's no `ETag`, a strong one is computed by hashing the body—unless it's
+/// disabled or the body is too large (see [`ConditionalConfig`]).
+///
+/// Check out the [module-level documentation](super) for more details.
+pub async fn conditional_middleware<C>(
+    next: Next<C>,
+    request_head: &RequestHead,
+    config: &ConditionalConfig,
+) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    let response = next.await;
+    let is_safe = request_head.method == Method::GET || request_head.method == Method::HEAD;
+    if !is_safe || response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (response, etag) = match response.headers().get(ETAG) {
+        Some(etag) => {
+            let etag = etag.to_str().ok().and_then(|e| e.parse::<ETag>().ok());
+            (response, etag)
+        }
+        None if config.compute_etag => match hash_body(response, config).await {
+            Ok(outcome) => outcome,
+            Err(response) => return response,
+        },
+        None => (response, None),
+    };
+    let last_modified = response
+        .headers()
+        .get(LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| httpdate::parse_http_date(v).ok());
+
+    match evaluate_preconditions(request_head, etag.as_ref(), last_modified) {
+        Precondition::Proceed => response,
+        Precondition::NotModified => not_modified(&response),
+        Precondition::Failed => Response::precondition_failed(),
+    }
+}
+
+/// Buffer the body (if it's small enough), hash it and set the `ETag` header.
+///
+/// It returns an error response if the body can't be buffered.
+async fn hash_body(
+    response: Response,
+    config: &ConditionalConfig,
+) -> Result<(Response, Option<ETag>), Response> {
+    match response.body().size_hint().exact() {
+        Some(size) if size <= config.max_hashed_body_size.as_u64() => {}
+        _ => return Ok((response, None)),
+    }
+    let (head, body) = response.into_parts();
+    let bytes: Bytes = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => {
+            tracing::error!(
+                error.msg = %e,
+                error.details = ?e,
+                "Failed to buffer the response body to compute its ETag"
+            );
+            return Err(Response::internal_server_error());
+        }
+    };
+    let etag = ETag::from_content(&bytes);
+    let response = Response::from_parts(head, Default::default())
+        .set_raw_body(Full::new(bytes))
+        .set_etag(&etag);
+    Ok((response, Some(etag)))
+}
+
+/// Build a `304 Not Modified` response, keeping the headers that a `200 OK`
+/// would have carried for caching purposes (RFC 9110 §15.4.5).
+fn not_modified(response: &Response) -> Response {
+    let mut not_modified = Response::not_modified();
+    for name in [
+        CACHE_CONTROL,
+        CONTENT_LOCATION,
+        DATE,
+        ETAG,
+        EXPIRES,
+        LAST_MODIFIED,
+        VARY,
+    ] {
+        for value in response.headers().get_all(&name) {
+            not_modified = not_modified.append_header(name.clone(), value.clone());
+        }
+    }
+    not_modified
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG};
+    use http::{HeaderValue, Method, StatusCode};
+
+    use super::conditional_middleware;
+    use crate::conditional::{ConditionalConfig, ETag};
+    use crate::middleware::Next;
+    use crate::request::test_utils::request_head;
+    use crate::response::Response;
+
+    async fn run(method: Method, headers: &[(&str, &str)], response: Response) -> Response {
+        let head = request_head(method, "/", headers);
+        let next = Next::new(async { response });
+        conditional_middleware(next, &head, &ConditionalConfig::default()).await
+    }
+
+    fn hello() -> Response {
+        Response::ok()
+            .set_typed_body("hello")
+            .insert_header(CACHE_CONTROL, HeaderValue::from_static("max-age=60"))
+    }
+
+    #[tokio::test]
+    async fn etags_are_computed_from_the_body() {
+        let response = run(Method::GET, &[], hello()).await;
+        let expected = ETag::from_content(b"hello").to_string();
+        assert_eq!(response.headers()[ETAG], expected.as_str());
+
+        let response = run(Method::GET, &[("if-none-match", &expected)], hello()).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], expected.as_str());
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=60");
+        assert!(response.headers().get(CONTENT_TYPE).is_none());
+    }
+
+    #[tokio::test]
+    async fn etags_set_by_the_handler_are_preserved() {
+        let etag = ETag::weak("v1");
+        let response = hello().set_etag(&etag);
+        let response = run(Method::GET, &[("if-match", r#""v1""#)], response).await;
+        // Weak tags never match `If-Match`.
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let response = hello().set_etag(&etag);
+        let response = run(Method::GET, &[("if-none-match", r#""v1""#)], response).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn unsafe_methods_and_errors_are_left_alone() {
+        let headers = [("if-match", r#""nope""#)];
+        let response = run(Method::POST, &headers, hello()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = run(Method::GET, &headers, Response::not_found()).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,65 @@
+//! Conditional requests, as specified in [RFC 9110 §13](https://www.rfc-editor.org/rfc/rfc9110#section-13).
+//!
+//! # Overview
+//!
+//! Clients can make a request conditional on the state of the target resource,
+//! using the validators (`ETag`, `Last-Modified`) returned by a previous response:
+//!
+//! - `If-None-Match` and `If-Modified-Since` let caches revalidate a stored response.
+//!   The server replies with a `304 Not Modified`, without a body, if the stored
+//!   response is still fresh.
+//! - `If-Match` and `If-Unmodified-Since` prevent lost updates.
+//!   The server replies with a `412 Precondition Failed` if the resource has been
+//!   modified in the meantime.
+//!
+//! # Safe methods
+//!
+//! [`conditional_middleware`] takes care of `GET` and `HEAD` requests for you.
+//! It looks at the validators set by your handlers (see [`Response::set_etag`] and
+//! [`Response::set_last_modified`]) and, if there's no `ETag`, it computes a strong
+//! one by hashing the response body.
+//!
+//! ```rust
+//! use pavex::blueprint::Blueprint;
+//! use pavex::conditional::ConditionalConfig;
+//! use pavex::f;
+//!
+//! let mut bp = Blueprint::new();
+//! ConditionalConfig::register(&mut bp);
+//! bp.wrap(f!(pavex::conditional::conditional_middleware));
+//! // Register your routes here...
+//! ```
+//!
+//! # Unsafe methods
+//!
+//! Preconditions on unsafe methods (e.g. `PUT` or `DELETE`) must be evaluated **before**
+//! changing the state of the resource: that's why [`conditional_middleware`] ignores them.
+//! Use [`evaluate_preconditions`] in your handler instead:
+//!
+//! ```rust
+//! use pavex::conditional::{evaluate_preconditions, ETag, Precondition};
+//! use pavex::request::RequestHead;
+//! use pavex::response::Response;
+//!
+//! pub async fn update_article(request_head: &RequestHead) -> Response {
+//!     # let current_version = 7;
+//!     let etag = ETag::strong(current_version.to_string());
+//!     if let Precondition::Failed = evaluate_preconditions(request_head, Some(&etag), None) {
+//!         return Response::precondition_failed();
+//!     }
+//!     // [...] Apply the update.
+//!     Response::no_content()
+//! }
+//! ```
+//!
+//! [`Response::set_etag`]: crate::response::Response::set_etag
+//! [`Response::set_last_modified`]: crate::response::Response::set_last_modified
+pub use config::ConditionalConfig;
+pub use etag::{ETag, InvalidETag};
+pub use middleware::conditional_middleware;
+pub use precondition::{evaluate_preconditions, Precondition};
+
+mod config;
+mod etag;
+mod middleware;
+pub(crate) mod precondition;
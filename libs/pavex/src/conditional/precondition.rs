@@ -0,0 +1,192 @@
+use std::time::SystemTime;
+
+use http::header::{IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_UNMODIFIED_SINCE};
+use http::{HeaderMap, HeaderName, Method};
+
+use crate::request::RequestHead;
+
+use super::etag::ETagList;
+use super::ETag;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The outcome of [`evaluate_preconditions`].
+pub enum Precondition {
+    /// All preconditions are satisfied (or there are none): process the request as usual.
+    Proceed,
+    /// The client already has the current representation: reply with a
+    /// `304 Not Modified`.
+    ///
+    /// It's only returned for `GET` and `HEAD` requests.
+    NotModified,
+    /// A precondition failed: reply with a `412 Precondition Failed`.
+    Failed,
+}
+
+/// Evaluate the conditional headers of a request against the current validators of
+/// the target resource.
+///
+/// `etag` and `last_modified` should be `None` if the resource doesn't have the
+/// corresponding validator.
+/// The headers are evaluated in the order specified by RFC 9110 §13.2.2:
+///
+/// 1. `If-Match`, using the strong comparison function;
+/// 2. `If-Unmodified-Since`, if `If-Match` is missing;
+/// 3. `If-None-Match`, using the weak comparison function;
+/// 4. `If-Modified-Since`, if `If-None-Match` is missing and the method is `GET` or `HEAD`.
+///
+/// Dates are compared with a resolution of one second.
+pub fn evaluate_preconditions(
+    request_head: &RequestHead,
+    etag: Option<&ETag>,
+    last_modified: Option<SystemTime>,
+) -> Precondition {
+    let headers = &request_head.headers;
+    let last_modified = last_modified.map(truncate_to_seconds);
+    let is_safe = request_head.method == Method::GET || request_head.method == Method::HEAD;
+
+    if let Some(if_match) = header_str(headers, IF_MATCH) {
+        if !ETagList::parse(if_match).matches(etag, true) {
+            return Precondition::Failed;
+        }
+    } else if let Some(since) = header_date(headers, IF_UNMODIFIED_SINCE) {
+        if last_modified.is_some_and(|m| m > since) {
+            return Precondition::Failed;
+        }
+    }
+
+    if let Some(if_none_match) = header_str(headers, IF_NONE_MATCH) {
+        if ETagList::parse(if_none_match).matches(etag, false) {
+            return if is_safe {
+                Precondition::NotModified
+            } else {
+                Precondition::Failed
+            };
+        }
+    } else if let Some(since) = header_date(headers, IF_MODIFIED_SINCE) {
+        if is_safe && last_modified.is_some_and(|m| m <= since) {
+            return Precondition::NotModified;
+        }
+    }
+    Precondition::Proceed
+}
+
+fn header_str(headers: &HeaderMap, name: HeaderName) -> Option<&str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Invalid dates are ignored, as mandated by RFC 9110.
+fn header_date(headers: &HeaderMap, name: HeaderName) -> Option<SystemTime> {
+    header_str(headers, name).and_then(|v| httpdate::parse_http_date(v).ok())
+}
+
+/// HTTP dates have a resolution of one second.
+pub(crate) fn truncate_to_seconds(time: SystemTime) -> SystemTime {
+    let since_epoch = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(since_epoch.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use http::Method;
+
+    use super::{evaluate_preconditions, Precondition};
+    use crate::conditional::ETag;
+    use crate::request::test_utils::request_head;
+
+    const LAST_MODIFIED: &str = "Wed, 21 Oct 2015 07:28:00 GMT";
+
+    fn last_modified() -> SystemTime {
+        httpdate::parse_http_date(LAST_MODIFIED).unwrap() + Duration::from_millis(300)
+    }
+
+    fn evaluate(method: Method, headers: &[(&str, &str)]) -> Precondition {
+        let etag = ETag::strong("v2");
+        evaluate_preconditions(
+            &request_head(method, "/", headers),
+            Some(&etag),
+            Some(last_modified()),
+        )
+    }
+
+    #[test]
+    fn if_none_match() {
+        use Precondition::*;
+        assert_eq!(
+            evaluate(Method::GET, &[("if-none-match", r#""v2""#)]),
+            NotModified
+        );
+        assert_eq!(
+            evaluate(Method::GET, &[("if-none-match", r#"W/"v2""#)]),
+            NotModified
+        );
+        assert_eq!(
+            evaluate(Method::GET, &[("if-none-match", r#""v1", "v2""#)]),
+            NotModified
+        );
+        assert_eq!(
+            evaluate(Method::GET, &[("if-none-match", r#""v1""#)]),
+            Proceed
+        );
+        assert_eq!(evaluate(Method::PUT, &[("if-none-match", "*")]), Failed);
+        // `If-Modified-Since` is ignored when `If-None-Match` is present.
+        assert_eq!(
+            evaluate(
+                Method::GET,
+                &[
+                    ("if-none-match", r#""v1""#),
+                    ("if-modified-since", LAST_MODIFIED)
+                ]
+            ),
+            Proceed
+        );
+    }
+
+    #[test]
+    fn if_modified_since() {
+        use Precondition::*;
+        assert_eq!(
+            evaluate(Method::GET, &[("if-modified-since", LAST_MODIFIED)]),
+            NotModified
+        );
+        assert_eq!(
+            evaluate(
+                Method::GET,
+                &[("if-modified-since", "Wed, 21 Oct 2015 07:27:59 GMT")]
+            ),
+            Proceed
+        );
+        assert_eq!(
+            evaluate(Method::GET, &[("if-modified-since", "garbage")]),
+            Proceed
+        );
+        assert_eq!(
+            evaluate(Method::POST, &[("if-modified-since", LAST_MODIFIED)]),
+            Proceed
+        );
+    }
+
+    #[test]
+    fn if_match_and_if_unmodified_since() {
+        use Precondition::*;
+        assert_eq!(evaluate(Method::PUT, &[("if-match", r#""v2""#)]), Proceed);
+        assert_eq!(evaluate(Method::PUT, &[("if-match", "*")]), Proceed);
+        // The strong comparison function never matches weak tags.
+        assert_eq!(evaluate(Method::PUT, &[("if-match", r#"W/"v2""#)]), Failed);
+        assert_eq!(evaluate(Method::PUT, &[("if-match", r#""v1""#)]), Failed);
+        assert_eq!(
+            evaluate(
+                Method::PUT,
+                &[("if-unmodified-since", "Wed, 21 Oct 2015 07:00:00 GMT")]
+            ),
+            Failed
+        );
+        assert_eq!(
+            evaluate(Method::PUT, &[("if-unmodified-since", LAST_MODIFIED)]),
+            Proceed
+        );
+    }
+}
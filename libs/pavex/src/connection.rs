@@ -0,0 +1,51 @@
+//! Information about the underlying network connection.
+use std::net::SocketAddr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Information about the connection the current request was received on.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::connection::ConnectionInfo;
+/// use pavex::response::Response;
+///
+/// pub fn handler(connection_info: &ConnectionInfo) -> Response {
+///     let peer = connection_info.peer_addr();
+///     Response::ok().set_typed_body(format!("Hello, {}!", peer.ip()))
+/// }
+/// ```
+///
+/// # Framework primitive
+///
+/// `ConnectionInfo` is a framework primitive—you don't need to register any constructor
+/// with [`Blueprint`] to use it in your application.
+/// It is populated by Pavex's [`Server`](crate::server::Server).
+///
+/// If the server fails to determine the peer address of a connection, its requests are
+/// still processed—but those that need a `ConnectionInfo` get a `500 Internal Server Error`
+/// response.
+///
+/// # Proxies
+///
+/// If your application sits behind a reverse proxy or a load balancer, the peer address
+/// is the address of the proxy, not the address of the client.
+/// Check the headers set by your proxy (e.g. `X-Forwarded-For`) to determine the
+/// address of the client.
+///
+/// [`Blueprint`]: crate::blueprint::Blueprint
+pub struct ConnectionInfo {
+    peer_addr: SocketAddr,
+}
+
+impl ConnectionInfo {
+    /// Create a new [`ConnectionInfo`] for a connection established by `peer_addr`.
+    pub fn new(peer_addr: SocketAddr) -> Self {
+        Self { peer_addr }
+    }
+
+    /// The address of the remote end of the connection.
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_addr
+    }
+}
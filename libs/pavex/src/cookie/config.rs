@@ -0,0 +1,167 @@
+use cookie::{Cookie, SameSite};
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+/// Default attributes for the cookies set by your application.
+///
+/// The defaults are applied by [`ResponseCookies::apply_delta_with_config`] to every
+/// outgoing cookie that doesn't set the corresponding attribute explicitly.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::cookie::{Cookie, CookieConfig, SameSite};
+///
+/// let config = CookieConfig {
+///     same_site: Some(SameSite::Strict),
+///     ..Default::default()
+/// };
+///
+/// // `Secure`, `HttpOnly` and `Path=/` come from the defaults...
+/// let cookie = config.apply_defaults(Cookie::new("session", "1234"));
+/// assert_eq!(cookie.to_string(), "session=1234; HttpOnly; SameSite=Strict; Secure; Path=/");
+///
+/// // ...unless the cookie sets them explicitly.
+/// let mut cookie = Cookie::new("theme", "dark");
+/// cookie.set_http_only(false);
+/// let cookie = config.apply_defaults(cookie);
+/// assert_eq!(cookie.to_string(), "theme=dark; SameSite=Strict; Secure; Path=/");
+/// ```
+///
+/// # Configuration
+///
+/// [`CookieConfig`] implements [`serde::Deserialize`], so you can load it as part
+/// of your application configuration.
+/// `same_site` accepts `"strict"`, `"lax"` or `"none"`.
+///
+/// [`ResponseCookies::apply_delta_with_config`]: super::ResponseCookies::apply_delta_with_config
+pub struct CookieConfig {
+    /// The default value for the `Secure` attribute.
+    ///
+    /// Defaults to `true`.
+    pub secure: bool,
+    /// The default value for the `HttpOnly` attribute.
+    ///
+    /// Defaults to `true`.
+    pub http_only: bool,
+    /// The default value for the `SameSite` attribute.
+    /// If `None`, the attribute is omitted.
+    ///
+    /// Defaults to `Some(SameSite::Lax)`.
+    #[serde(with = "same_site")]
+    pub same_site: Option<SameSite>,
+    /// The default value for the `Path` attribute.
+    /// If `None`, the attribute is omitted.
+    ///
+    /// Defaults to `Some("/")`.
+    pub path: Option<String>,
+    /// The default value for the `Domain` attribute.
+    /// If `None`, the attribute is omitted.
+    ///
+    /// Defaults to `None`.
+    pub domain: Option<String>,
+}
+
+impl CookieConfig {
+    /// Set the attributes that `cookie` doesn't specify to their default values.
+    pub fn apply_defaults(&self, mut cookie: Cookie<'static>) -> Cookie<'static> {
+        if cookie.secure().is_none() {
+            cookie.set_secure(self.secure);
+        }
+        if cookie.http_only().is_none() {
+            cookie.set_http_only(self.http_only);
+        }
+        if cookie.same_site().is_none() {
+            cookie.set_same_site(self.same_site);
+        }
+        if cookie.path().is_none() {
+            if let Some(path) = &self.path {
+                cookie.set_path(path.clone());
+            }
+        }
+        if cookie.domain().is_none() {
+            if let Some(domain) = &self.domain {
+                cookie.set_domain(domain.clone());
+            }
+        }
+        cookie
+    }
+
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`CookieConfig`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::default) for [`CookieConfig`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(<pavex::cookie::CookieConfig as std::default::Default>::default),
+            Lifecycle::Singleton,
+        )
+    }
+}
+
+impl Default for CookieConfig {
+    fn default() -> Self {
+        Self {
+            secure: true,
+            http_only: true,
+            same_site: Some(SameSite::Lax),
+            path: Some("/".into()),
+            domain: None,
+        }
+    }
+}
+
+mod same_site {
+    use cookie::SameSite;
+    use serde::{Deserialize, Deserializer};
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Option<SameSite>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(value) = Option::<String>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+        match value.to_ascii_lowercase().as_str() {
+            "strict" => Ok(Some(SameSite::Strict)),
+            "lax" => Ok(Some(SameSite::Lax)),
+            "none" => Ok(Some(SameSite::None)),
+            _ => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&value),
+                &"one of `strict`, `lax` or `none`",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CookieConfig;
+    use cookie::SameSite;
+
+    #[test]
+    fn deserialize_with_defaults() {
+        let config: CookieConfig =
+            serde_json::from_str(r#"{ "same_site": "Strict", "domain": "example.com" }"#).unwrap();
+        assert_eq!(
+            config,
+            CookieConfig {
+                same_site: Some(SameSite::Strict),
+                domain: Some("example.com".into()),
+                ..Default::default()
+            }
+        );
+
+        let config: CookieConfig = serde_json::from_str(r#"{ "same_site": null }"#).unwrap();
+        assert_eq!(config.same_site, None);
+
+        assert!(serde_json::from_str::<CookieConfig>(r#"{ "same_site": "loose" }"#).is_err());
+    }
+}
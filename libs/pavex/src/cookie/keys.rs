@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use cookie::Key;
+
+/// The set of keys used to sign and encrypt cookies.
+///
+/// It is consumed by [`SignedCookies`] and [`PrivateCookies`].
+///
+/// # Key rotation
+///
+/// New cookies are always signed (or encrypted) using the
+/// [current key](Self::current).
+/// Incoming cookies are verified against the current key first and then
+/// against each of the [retired keys](Self::with_retired_key), in the order
+/// they were added.
+///
+/// This allows you to rotate your key without invalidating all the cookies
+/// that are already out there: add the old key as a retired key, deploy,
+/// and remove it once all the cookies signed with it have expired.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::cookie::{CookieKeys, Key};
+///
+/// # let new_key = Key::generate();
+/// # let old_key = Key::generate();
+/// let keys = CookieKeys::new(new_key).with_retired_key(old_key);
+/// ```
+///
+/// # Singleton
+///
+/// There is no default constructor for [`CookieKeys`]: your keys are a
+/// secret and should be loaded from your application configuration.
+/// Register a singleton constructor for it with your [`Blueprint`].
+///
+/// [`Blueprint`]: crate::blueprint::Blueprint
+/// [`SignedCookies`]: super::SignedCookies
+/// [`PrivateCookies`]: super::PrivateCookies
+#[derive(Clone)]
+pub struct CookieKeys {
+    keys: Arc<Vec<Key>>,
+}
+
+impl CookieKeys {
+    /// Create a new set of keys, using `current` to sign and encrypt new cookies.
+    pub fn new(current: Key) -> Self {
+        Self {
+            keys: Arc::new(vec![current]),
+        }
+    }
+
+    /// Add a retired key.
+    ///
+    /// Retired keys are never used to sign or encrypt new cookies, but cookies
+    /// signed or encrypted with them are still accepted.
+    pub fn with_retired_key(mut self, key: Key) -> Self {
+        Arc::make_mut(&mut self.keys).push(key);
+        self
+    }
+
+    /// The key used to sign and encrypt new cookies.
+    pub fn current(&self) -> &Key {
+        &self.keys[0]
+    }
+
+    /// The keys that are still accepted when verifying or decrypting incoming cookies,
+    /// but are no longer used to sign or encrypt new ones.
+    pub fn retired(&self) -> &[Key] {
+        &self.keys[1..]
+    }
+
+    /// Iterate over all keys, starting from the current one.
+    pub(super) fn iter(&self) -> impl Iterator<Item = &Key> {
+        self.keys.iter()
+    }
+}
+
+impl std::fmt::Debug for CookieKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CookieKeys")
+            .field("n_retired_keys", &self.retired().len())
+            .finish_non_exhaustive()
+    }
+}
@@ -13,7 +13,16 @@ use std::cell::RefCell;
 use std::future::IntoFuture;
 use std::rc::Rc;
 
-pub use cookie::Cookie;
+pub use config::CookieConfig;
+pub use cookie::{Cookie, Key, SameSite};
+pub use keys::CookieKeys;
+pub use private::PrivateCookies;
+pub use signed::SignedCookies;
+
+mod config;
+mod keys;
+mod private;
+mod signed;
 
 #[derive(Debug)]
 /// Structure that facilitates reading Cookies from HTTP Request Headers
@@ -91,21 +100,47 @@ impl ResponseCookies {
 
     /// Attaches the changes in the [`CookieJar`] to the [`Response`]
     /// It percent-encodes reserved characters.
-    /// This should only be done once per Response and is generally handled by [cookie_middleware](cookie_middleware)
+    /// This should only be done once per Response and is generally handled by [`cookie_middleware`]
     pub fn apply_delta(&self, mut response: Response) -> Response {
         for delta in self.jar.borrow().delta() {
-            match HeaderValue::from_str(&delta.encoded().to_string()) {
-                Ok(headervalue) => response = response.append_header(SET_COOKIE, headervalue),
-                Err(e) => {
-                    tracing::warn!(warning="Ignoring bad cookie.", context=%e);
-                }
-            }
+            response = append_cookie(response, delta);
         }
         response
     }
+
+    /// Like [`apply_delta`](Self::apply_delta), but the defaults in [`CookieConfig`] are applied
+    /// to every cookie that doesn't set the corresponding attribute explicitly.
+    /// This should only be done once per Response and is generally handled by
+    /// [`cookie_middleware_with_config`]
+    pub fn apply_delta_with_config(
+        &self,
+        mut response: Response,
+        config: &CookieConfig,
+    ) -> Response {
+        for delta in self.jar.borrow().delta() {
+            response = append_cookie(response, &config.apply_defaults(delta.clone()));
+        }
+        response
+    }
+}
+
+/// Append a `Set-Cookie` header for `cookie` to the response.
+/// Cookies that can't be represented as a header value are skipped.
+fn append_cookie(response: Response, cookie: &Cookie<'static>) -> Response {
+    match HeaderValue::from_str(&cookie.encoded().to_string()) {
+        Ok(headervalue) => response.append_header(SET_COOKIE, headervalue),
+        Err(e) => {
+            tracing::warn!(warning="Ignoring bad cookie.", context=%e);
+            response
+        }
+    }
 }
 
 /// Middleware to handle updating cookies
+///
+/// Cookies are sent as they were set, no default attributes are applied.
+/// Use [`cookie_middleware_with_config`] if you want to apply the defaults
+/// in [`CookieConfig`].
 pub async fn cookie_middleware<C>(next: Next<C>, cookies: &ResponseCookies) -> Response
 where
     C: IntoFuture<Output = Response>,
@@ -114,6 +149,20 @@ where
     cookies.apply_delta(response)
 }
 
+/// Middleware to handle updating cookies, using the default attributes
+/// specified in the [`CookieConfig`] registered with your application.
+pub async fn cookie_middleware_with_config<C>(
+    next: Next<C>,
+    cookies: &ResponseCookies,
+    config: &CookieConfig,
+) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    let response = next.await;
+    cookies.apply_delta_with_config(response, config)
+}
+
 impl RequestCookies {
     /// Register the [default constructor](Self::default_constructor)
     /// for [`RequestCookies`] with a [`Blueprint`].
@@ -149,7 +198,7 @@ impl ResponseCookies {
 
 #[cfg(test)]
 mod tests {
-    use super::{RequestCookies, ResponseCookies};
+    use super::{Cookie, CookieConfig, RequestCookies, ResponseCookies, SameSite};
     use crate::{
         http::header::{COOKIE, SET_COOKIE},
         response::Response,
@@ -233,4 +282,35 @@ mod tests {
             responsecookies[0].starts_with("test2=%20%22%23%25&%28%29%2C%2F%3A%3B%3D%3F%40%5B%5D")
         );
     }
+
+    #[test]
+    fn apply_delta_with_config_sets_missing_attributes() {
+        let (parts, _) = http::Request::builder().body(()).unwrap().into_parts();
+        let cookies =
+            ResponseCookies::from_request_cookies(&RequestCookies::extract(&parts.into()));
+        let mut explicit = Cookie::new("theme", "dark");
+        explicit.set_http_only(false);
+        cookies.add(explicit);
+        cookies.add(Cookie::new("session", "1234"));
+        let config = CookieConfig {
+            same_site: Some(SameSite::Strict),
+            ..Default::default()
+        };
+
+        let res = cookies.apply_delta_with_config(Response::ok(), &config);
+        let mut responsecookies = res
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .map(|hv| hv.to_str().unwrap())
+            .collect::<Vec<_>>();
+        responsecookies.sort();
+        assert_eq!(
+            responsecookies,
+            [
+                "session=1234; HttpOnly; SameSite=Strict; Secure; Path=/",
+                "theme=dark; SameSite=Strict; Secure; Path=/",
+            ]
+        );
+    }
 }
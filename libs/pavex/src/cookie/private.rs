@@ -0,0 +1,174 @@
+use cookie::Cookie;
+
+use crate::blueprint::constructor::{
+    CloningStrategy, Constructor, Lifecycle, RegisteredConstructor,
+};
+use crate::blueprint::Blueprint;
+use crate::f;
+
+use super::{CookieKeys, ResponseCookies};
+
+/// A view over [`ResponseCookies`] that encrypts outgoing cookies and decrypts
+/// incoming ones.
+///
+/// Private cookies can't be read nor tampered with by the client: their value
+/// is encrypted and authenticated.
+/// Cookies that fail decryption are silently ignored—it's as if they
+/// weren't there in the first place.
+///
+/// Cookies are encrypted using the [current key](CookieKeys::current) and
+/// decrypted using any of the keys in [`CookieKeys`], thus supporting key rotation.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::cookie::{Cookie, PrivateCookies};
+/// use pavex::response::Response;
+///
+/// pub fn handler(cookies: &PrivateCookies) -> Response {
+///     match cookies.get("user_id") {
+///         Some(user_id) => Response::ok().set_typed_body(user_id.value().to_owned()),
+///         None => Response::unauthorized(),
+///     }
+/// }
+/// ```
+///
+/// # Cloning
+///
+/// [`PrivateCookies`] is cheap to clone: all clones share the same underlying
+/// [`ResponseCookies`].
+#[derive(Debug, Clone)]
+pub struct PrivateCookies {
+    cookies: ResponseCookies,
+    keys: CookieKeys,
+}
+
+impl PrivateCookies {
+    /// Create a new private view over the cookies attached to the current request.
+    pub fn new(cookies: &ResponseCookies, keys: &CookieKeys) -> Self {
+        Self {
+            cookies: cookies.clone(),
+            keys: keys.clone(),
+        }
+    }
+
+    /// Get the decrypted [`Cookie`] with the given name, if it exists.
+    ///
+    /// It returns `None` if the cookie is missing or if it can't be decrypted
+    /// with any of the configured keys.
+    pub fn get(&self, name: &str) -> Option<Cookie<'static>> {
+        let cookie = self.cookies.jar.borrow().get(name)?.clone();
+        self.decrypt(cookie)
+    }
+
+    /// Get all the cookies that can be decrypted.
+    /// Plaintext or tampered cookies are skipped.
+    pub fn get_all(&self) -> Vec<Cookie<'static>> {
+        let cookies = self.cookies.get_all_cloned();
+        cookies
+            .into_iter()
+            .filter_map(|c| self.decrypt(c.into_owned()))
+            .collect()
+    }
+
+    /// Encrypt a [`Cookie`] with the current key and add it to the response.
+    /// If a cookie by the same name is already present, it will be overwritten.
+    pub fn add(&self, cookie: Cookie<'static>) {
+        self.cookies
+            .jar
+            .borrow_mut()
+            .private_mut(self.keys.current())
+            .add(cookie)
+    }
+
+    /// Removes a [`Cookie`], instructing the client to delete it.
+    pub fn remove(&self, cookie: Cookie<'static>) {
+        self.cookies.remove(cookie)
+    }
+
+    fn decrypt(&self, cookie: Cookie<'static>) -> Option<Cookie<'static>> {
+        let jar = self.cookies.jar.borrow();
+        self.keys
+            .iter()
+            .find_map(|key| jar.private(key).decrypt(cookie.clone()))
+    }
+}
+
+impl PrivateCookies {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`PrivateCookies`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](PrivateCookies::new) for [`PrivateCookies`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(crate::cookie::PrivateCookies::new),
+            Lifecycle::RequestScoped,
+        )
+        .cloning(CloningStrategy::CloneIfNecessary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrivateCookies;
+    use crate::cookie::{Cookie, CookieKeys, Key, RequestCookies, ResponseCookies};
+    use crate::http::header::COOKIE;
+    use crate::http::Method;
+    use crate::request::test_utils::request_head;
+
+    fn response_cookies(cookie_header: &str) -> ResponseCookies {
+        let head = request_head(Method::GET, "/", &[(COOKIE.as_str(), cookie_header)]);
+        ResponseCookies::from_request_cookies(&RequestCookies::extract(&head))
+    }
+
+    /// Encrypt a cookie with `key` and return its encoded `name=value` pair.
+    fn encrypted_pair(key: &Key, name: &str, value: &str) -> String {
+        let keys = CookieKeys::new(key.clone());
+        let private = PrivateCookies::new(&response_cookies(""), &keys);
+        private.add(Cookie::new(name.to_owned(), value.to_owned()));
+        let cookie = private.cookies.get_cloned(name).unwrap();
+        format!("{}={}", cookie.name(), cookie.value())
+    }
+
+    #[test]
+    fn private_cookies_roundtrip() {
+        let key = Key::generate();
+        let header = encrypted_pair(&key, "user_id", "42");
+        assert!(!header.contains("42"));
+        let private = PrivateCookies::new(&response_cookies(&header), &CookieKeys::new(key));
+        assert_eq!(private.get("user_id").unwrap().value(), "42");
+    }
+
+    #[test]
+    fn tampered_and_plaintext_cookies_are_dropped() {
+        let key = Key::generate();
+        let header = encrypted_pair(&key, "user_id", "42");
+        let tampered = format!("{}A; plain=value", header);
+        let private = PrivateCookies::new(&response_cookies(&tampered), &CookieKeys::new(key));
+        assert!(private.get("user_id").is_none());
+        assert!(private.get("plain").is_none());
+        assert!(private.get_all().is_empty());
+    }
+
+    #[test]
+    fn retired_keys_are_accepted_for_decryption() {
+        let old_key = Key::generate();
+        let new_key = Key::generate();
+        let header = encrypted_pair(&old_key, "user_id", "42");
+
+        let keys = CookieKeys::new(new_key.clone()).with_retired_key(old_key.clone());
+        let private = PrivateCookies::new(&response_cookies(&header), &keys);
+        assert_eq!(private.get("user_id").unwrap().value(), "42");
+
+        // New cookies are encrypted with the current key.
+        private.add(Cookie::new("user_id", "43"));
+        let only_new = PrivateCookies::new(&private.cookies, &CookieKeys::new(new_key));
+        assert_eq!(private.get("user_id").unwrap().value(), "43");
+        assert_eq!(only_new.get("user_id").unwrap().value(), "43");
+        let only_old = PrivateCookies::new(&private.cookies, &CookieKeys::new(old_key));
+        assert!(only_old.get("user_id").is_none());
+    }
+}
@@ -0,0 +1,175 @@
+use cookie::Cookie;
+
+use crate::blueprint::constructor::{
+    CloningStrategy, Constructor, Lifecycle, RegisteredConstructor,
+};
+use crate::blueprint::Blueprint;
+use crate::f;
+
+use super::{CookieKeys, ResponseCookies};
+
+/// A view over [`ResponseCookies`] that signs outgoing cookies and verifies
+/// incoming ones.
+///
+/// Signed cookies can be read by the client, but they can't be tampered with:
+/// any modification invalidates the signature.
+/// Cookies that fail verification are silently ignored—it's as if they
+/// weren't there in the first place.
+///
+/// Cookies are signed using the [current key](CookieKeys::current) and verified
+/// against all the keys in [`CookieKeys`], thus supporting key rotation.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::cookie::{Cookie, SignedCookies};
+/// use pavex::response::Response;
+///
+/// pub fn handler(cookies: &SignedCookies) -> Response {
+///     let visits = cookies
+///         .get("visits")
+///         .and_then(|c| c.value().parse::<u64>().ok())
+///         .unwrap_or(0);
+///     cookies.add(Cookie::new("visits", (visits + 1).to_string()));
+///     Response::ok()
+/// }
+/// ```
+///
+/// # Cloning
+///
+/// [`SignedCookies`] is cheap to clone: all clones share the same underlying
+/// [`ResponseCookies`].
+#[derive(Debug, Clone)]
+pub struct SignedCookies {
+    cookies: ResponseCookies,
+    keys: CookieKeys,
+}
+
+impl SignedCookies {
+    /// Create a new signed view over the cookies attached to the current request.
+    pub fn new(cookies: &ResponseCookies, keys: &CookieKeys) -> Self {
+        Self {
+            cookies: cookies.clone(),
+            keys: keys.clone(),
+        }
+    }
+
+    /// Get the verified [`Cookie`] with the given name, if it exists.
+    ///
+    /// It returns `None` if the cookie is missing or if its signature
+    /// can't be verified with any of the configured keys.
+    pub fn get(&self, name: &str) -> Option<Cookie<'static>> {
+        let cookie = self.cookies.jar.borrow().get(name)?.clone();
+        self.verify(cookie)
+    }
+
+    /// Get all the cookies whose signature can be verified.
+    /// Unsigned or tampered cookies are skipped.
+    pub fn get_all(&self) -> Vec<Cookie<'static>> {
+        let cookies = self.cookies.get_all_cloned();
+        cookies
+            .into_iter()
+            .filter_map(|c| self.verify(c.into_owned()))
+            .collect()
+    }
+
+    /// Sign a [`Cookie`] with the current key and add it to the response.
+    /// If a cookie by the same name is already present, it will be overwritten.
+    pub fn add(&self, cookie: Cookie<'static>) {
+        self.cookies
+            .jar
+            .borrow_mut()
+            .signed_mut(self.keys.current())
+            .add(cookie)
+    }
+
+    /// Removes a [`Cookie`], instructing the client to delete it.
+    pub fn remove(&self, cookie: Cookie<'static>) {
+        self.cookies.remove(cookie)
+    }
+
+    fn verify(&self, cookie: Cookie<'static>) -> Option<Cookie<'static>> {
+        let jar = self.cookies.jar.borrow();
+        self.keys
+            .iter()
+            .find_map(|key| jar.signed(key).verify(cookie.clone()))
+    }
+}
+
+impl SignedCookies {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`SignedCookies`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](SignedCookies::new) for [`SignedCookies`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(crate::cookie::SignedCookies::new),
+            Lifecycle::RequestScoped,
+        )
+        .cloning(CloningStrategy::CloneIfNecessary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignedCookies;
+    use crate::cookie::{Cookie, CookieKeys, Key, RequestCookies, ResponseCookies};
+    use crate::http::header::COOKIE;
+    use crate::http::Method;
+    use crate::request::test_utils::request_head;
+
+    fn response_cookies(cookie_header: &str) -> ResponseCookies {
+        let head = request_head(Method::GET, "/", &[(COOKIE.as_str(), cookie_header)]);
+        ResponseCookies::from_request_cookies(&RequestCookies::extract(&head))
+    }
+
+    /// Sign a cookie with `key` and return its encoded `name=value` pair.
+    fn signed_pair(key: &Key, name: &str, value: &str) -> String {
+        let keys = CookieKeys::new(key.clone());
+        let signed = SignedCookies::new(&response_cookies(""), &keys);
+        signed.add(Cookie::new(name.to_owned(), value.to_owned()));
+        let cookie = signed.cookies.get_cloned(name).unwrap();
+        format!("{}={}", cookie.name(), cookie.value())
+    }
+
+    #[test]
+    fn signed_cookies_roundtrip() {
+        let key = Key::generate();
+        let header = signed_pair(&key, "session", "42");
+        assert_ne!(header, "session=42");
+        let signed = SignedCookies::new(&response_cookies(&header), &CookieKeys::new(key));
+        assert_eq!(signed.get("session").unwrap().value(), "42");
+    }
+
+    #[test]
+    fn tampered_and_unsigned_cookies_are_dropped() {
+        let key = Key::generate();
+        let header = signed_pair(&key, "session", "42");
+        let tampered = format!("{}3; plain=value", header);
+        let signed = SignedCookies::new(&response_cookies(&tampered), &CookieKeys::new(key));
+        assert!(signed.get("session").is_none());
+        assert!(signed.get("plain").is_none());
+        assert!(signed.get_all().is_empty());
+    }
+
+    #[test]
+    fn retired_keys_are_accepted_for_verification() {
+        let old_key = Key::generate();
+        let new_key = Key::generate();
+        let header = signed_pair(&old_key, "session", "42");
+
+        let keys = CookieKeys::new(new_key.clone()).with_retired_key(old_key.clone());
+        let signed = SignedCookies::new(&response_cookies(&header), &keys);
+        assert_eq!(signed.get("session").unwrap().value(), "42");
+
+        // New cookies are signed with the current key.
+        signed.add(Cookie::new("session", "43"));
+        let only_new = SignedCookies::new(&signed.cookies, &CookieKeys::new(new_key));
+        assert_eq!(only_new.get("session").unwrap().value(), "43");
+        let only_old = SignedCookies::new(&signed.cookies, &CookieKeys::new(old_key));
+        assert!(only_old.get("session").is_none());
+    }
+}
@@ -0,0 +1,299 @@
+use std::future::IntoFuture;
+
+use http::header::{
+    ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE,
+    ACCESS_CONTROL_REQUEST_HEADERS, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN,
+};
+use http::{HeaderValue, Method};
+
+use crate::middleware::Next;
+use crate::request::RequestHead;
+use crate::response::Response;
+use crate::router::AllowedMethods;
+
+use super::policy::AllowedHeaders;
+use super::CorsPolicy;
+
+/// Enforce a [`CorsPolicy`].
+///
+/// - Preflight requests are answered directly, without invoking the rest of the
+///   request processing pipeline.
+/// - For all other requests coming from an allowed origin, the relevant CORS headers
+///   are added to the response returned by the rest of the pipeline.
+///
+/// The `Vary` header is set on all responses whose CORS headers depend on the
+/// incoming request.
+///
+/// Check out the [module-level documentation](super) for more details.
+pub async fn cors_middleware<C>(
+    next: Next<C>,
+    policy: &CorsPolicy,
+    request_head: &RequestHead,
+    allowed_methods: &AllowedMethods,
+) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    if is_preflight(request_head) {
+        return preflight(policy, request_head, allowed_methods);
+    }
+    let response = next.await;
+    decorate(policy, request_head, response)
+}
+
+fn is_preflight(request_head: &RequestHead) -> bool {
+    request_head.method == Method::OPTIONS
+        && request_head.headers.contains_key(ORIGIN)
+        && request_head
+            .headers
+            .contains_key(ACCESS_CONTROL_REQUEST_METHOD)
+}
+
+/// The origin of the request, if it's allowed by the policy.
+fn allowed_origin<'a>(
+    policy: &CorsPolicy,
+    request_head: &'a RequestHead,
+) -> Option<&'a HeaderValue> {
+    let origin = request_head.headers.get(ORIGIN)?;
+    let is_allowed = origin
+        .to_str()
+        .map(|o| policy.is_origin_allowed(o))
+        .unwrap_or(false);
+    is_allowed.then_some(origin)
+}
+
+fn preflight(
+    policy: &CorsPolicy,
+    request_head: &RequestHead,
+    allowed_methods: &AllowedMethods,
+) -> Response {
+    let mut response = Response::no_content()
+        .append_vary(ORIGIN)
+        .append_vary(ACCESS_CONTROL_REQUEST_METHOD)
+        .append_vary(ACCESS_CONTROL_REQUEST_HEADERS);
+    let Some(origin) = allowed_origin(policy, request_head) else {
+        return response;
+    };
+    response = response.insert_header(
+        ACCESS_CONTROL_ALLOW_ORIGIN,
+        policy.allow_origin_header(origin),
+    );
+    if policy.allow_credentials {
+        response = response.insert_header(
+            ACCESS_CONTROL_ALLOW_CREDENTIALS,
+            HeaderValue::from_static("true"),
+        );
+    }
+
+    let methods = match allowed_methods {
+        AllowedMethods::Some(registered) => registered
+            .iter()
+            .filter(|m| match &policy.methods {
+                Some(methods) => methods.contains(m),
+                None => true,
+            })
+            .map(|m| m.as_str().to_owned())
+            .collect::<Vec<_>>(),
+        AllowedMethods::All => match &policy.methods {
+            Some(methods) => methods.iter().map(|m| m.as_str().to_owned()).collect(),
+            // All methods are allowed: echo back the requested one.
+            None => request_head
+                .headers
+                .get(ACCESS_CONTROL_REQUEST_METHOD)
+                .and_then(|m| m.to_str().ok())
+                .map(|m| vec![m.to_owned()])
+                .unwrap_or_default(),
+        },
+    };
+    if let Some(value) = join(methods) {
+        response = response.insert_header(ACCESS_CONTROL_ALLOW_METHODS, value);
+    }
+
+    let headers = match &policy.headers {
+        AllowedHeaders::Any => request_head
+            .headers
+            .get(ACCESS_CONTROL_REQUEST_HEADERS)
+            .cloned(),
+        AllowedHeaders::List(headers) => join(headers.iter().map(|h| h.as_str().to_owned())),
+    };
+    if let Some(value) = headers {
+        response = response.insert_header(ACCESS_CONTROL_ALLOW_HEADERS, value);
+    }
+
+    if let Some(max_age) = policy.max_age {
+        response = response.insert_header(ACCESS_CONTROL_MAX_AGE, max_age.as_secs().into());
+    }
+    response
+}
+
+fn decorate(policy: &CorsPolicy, request_head: &RequestHead, mut response: Response) -> Response {
+    if policy.varies_on_origin() {
+        response = response.append_vary(ORIGIN);
+    }
+    let Some(origin) = allowed_origin(policy, request_head) else {
+        return response;
+    };
+    response = response.insert_header(
+        ACCESS_CONTROL_ALLOW_ORIGIN,
+        policy.allow_origin_header(origin),
+    );
+    if policy.allow_credentials {
+        response = response.insert_header(
+            ACCESS_CONTROL_ALLOW_CREDENTIALS,
+            HeaderValue::from_static("true"),
+        );
+    }
+    if let Some(value) = join(policy.exposed_headers.iter().map(|h| h.as_str().to_owned())) {
+        response = response.insert_header(ACCESS_CONTROL_EXPOSE_HEADERS, value);
+    }
+    response
+}
+
+/// Join a list of tokens into a comma-separated header value.
+/// It returns `None` if the list is empty.
+fn join(items: impl IntoIterator<Item = String>) -> Option<HeaderValue> {
+    let joined = items.into_iter().collect::<Vec<_>>().join(",");
+    if joined.is_empty() {
+        return None;
+    }
+    HeaderValue::from_str(&joined).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{
+        ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS,
+        ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS,
+        ACCESS_CONTROL_MAX_AGE, VARY,
+    };
+    use http::{Method, StatusCode};
+    use std::time::Duration;
+
+    use super::cors_middleware;
+    use crate::cors::CorsPolicy;
+    use crate::middleware::Next;
+    use crate::request::test_utils::request_head;
+    use crate::request::RequestHead;
+    use crate::response::Response;
+    use crate::router::{AllowedMethods, MethodAllowList};
+
+    fn header(response: &Response, name: http::HeaderName) -> Option<&str> {
+        response.headers().get(name).map(|v| v.to_str().unwrap())
+    }
+
+    fn get_post_and_delete() -> AllowedMethods {
+        MethodAllowList::from_iter([Method::GET, Method::POST, Method::DELETE]).into()
+    }
+
+    async fn run(policy: &CorsPolicy, head: &RequestHead) -> Response {
+        let next = Next::new(async { Response::ok() });
+        cors_middleware(next, policy, head, &get_post_and_delete()).await
+    }
+
+    #[tokio::test]
+    async fn preflight_lists_registered_methods() {
+        let policy = CorsPolicy::new()
+            .allow_origin("https://app.example.com")
+            .allow_methods([Method::GET, Method::POST, Method::PUT])
+            .allow_headers(["content-type"])
+            .max_age(Duration::from_secs(600));
+        let head = request_head(
+            Method::OPTIONS,
+            "/",
+            &[
+                ("origin", "https://app.example.com"),
+                ("access-control-request-method", "POST"),
+            ],
+        );
+        let response = run(&policy, &head).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.example.com")
+        );
+        // `PUT` is not registered for this path, `DELETE` is not allowed by the policy.
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_METHODS),
+            Some("GET,POST")
+        );
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_HEADERS),
+            Some("content-type")
+        );
+        assert_eq!(header(&response, ACCESS_CONTROL_MAX_AGE), Some("600"));
+        assert_eq!(
+            header(&response, VARY),
+            Some("origin, access-control-request-method, access-control-request-headers")
+        );
+    }
+
+    #[tokio::test]
+    async fn preflight_from_disallowed_origin() {
+        let policy = CorsPolicy::new().allow_origin("https://app.example.com");
+        let head = request_head(
+            Method::OPTIONS,
+            "/",
+            &[
+                ("origin", "https://evil.com"),
+                ("access-control-request-method", "POST"),
+            ],
+        );
+        let response = run(&policy, &head).await;
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_ORIGIN), None);
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_METHODS), None);
+    }
+
+    #[tokio::test]
+    async fn any_header_mirrors_the_request() {
+        let policy = CorsPolicy::new().allow_any_origin().allow_any_header();
+        let head = request_head(
+            Method::OPTIONS,
+            "/",
+            &[
+                ("origin", "https://app.example.com"),
+                ("access-control-request-method", "GET"),
+                ("access-control-request-headers", "x-custom,authorization"),
+            ],
+        );
+        let response = run(&policy, &head).await;
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_ORIGIN), Some("*"));
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_HEADERS),
+            Some("x-custom,authorization")
+        );
+    }
+
+    #[tokio::test]
+    async fn actual_requests_are_decorated() {
+        let policy = CorsPolicy::new()
+            .allow_origin("https://app.example.com")
+            .allow_credentials(true)
+            .expose_headers(["x-request-id"]);
+        let head = request_head(Method::GET, "/", &[("origin", "https://app.example.com")]);
+        let response = run(&policy, &head).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.example.com")
+        );
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true")
+        );
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_EXPOSE_HEADERS),
+            Some("x-request-id")
+        );
+        assert_eq!(header(&response, VARY), Some("origin"));
+    }
+
+    #[tokio::test]
+    async fn same_origin_requests_are_left_alone() {
+        let policy = CorsPolicy::new().allow_any_origin();
+        let head = request_head(Method::GET, "/", &[]);
+        let response = run(&policy, &head).await;
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_ORIGIN), None);
+        assert_eq!(header(&response, VARY), None);
+    }
+}
@@ -0,0 +1,44 @@
+//! Cross-Origin Resource Sharing (CORS).
+//!
+//! # Overview
+//!
+//! Browsers block cross-origin requests issued by scripts unless the server
+//! explicitly allows them via the CORS protocol.
+//! Describe which origins, methods and headers should be allowed with a [`CorsPolicy`],
+//! then wrap your routes with [`cors_middleware`].
+//!
+//! ```rust
+//! use pavex::blueprint::{constructor::Lifecycle, Blueprint};
+//! use pavex::cors::CorsPolicy;
+//! use pavex::f;
+//! use std::time::Duration;
+//!
+//! pub fn cors_policy() -> CorsPolicy {
+//!     CorsPolicy::new()
+//!         .allow_origin("https://app.example.com")
+//!         .allow_origin_pattern("https://*.preview.example.com")
+//!         .allow_headers(["content-type", "authorization"])
+//!         .allow_credentials(true)
+//!         .max_age(Duration::from_secs(3600))
+//! }
+//!
+//! let mut bp = Blueprint::new();
+//! bp.constructor(f!(crate::cors_policy), Lifecycle::Singleton);
+//! bp.wrap(f!(pavex::cors::cors_middleware));
+//! // Register your routes here...
+//! ```
+//!
+//! # Preflight requests
+//!
+//! [`cors_middleware`] answers preflight requests (`OPTIONS` requests with an
+//! `Access-Control-Request-Method` header) directly, without invoking your request handlers.
+//! The `Access-Control-Allow-Methods` header lists the methods that are actually
+//! registered for the requested path, as reported by [`AllowedMethods`],
+//! further restricted by [`CorsPolicy::allow_methods`] if set.
+//!
+//! [`AllowedMethods`]: crate::router::AllowedMethods
+pub use middleware::cors_middleware;
+pub use policy::CorsPolicy;
+
+mod middleware;
+mod policy;
@@ -0,0 +1,304 @@
+use std::time::Duration;
+
+use http::{HeaderName, HeaderValue, Method};
+
+#[derive(Debug, Clone)]
+/// The set of rules that determine which cross-origin requests are allowed.
+///
+/// A new policy doesn't allow any cross-origin request: use the builder methods
+/// to relax it.
+///
+/// Check out the [module-level documentation](super) for an example.
+pub struct CorsPolicy {
+    pub(super) origins: AllowedOrigins,
+    pub(super) methods: Option<Vec<Method>>,
+    pub(super) headers: AllowedHeaders,
+    pub(super) exposed_headers: Vec<HeaderName>,
+    pub(super) allow_credentials: bool,
+    pub(super) max_age: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum AllowedOrigins {
+    Any,
+    List(Vec<OriginMatcher>),
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum AllowedHeaders {
+    /// Mirror the headers listed in `Access-Control-Request-Headers`.
+    Any,
+    List(Vec<HeaderName>),
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum OriginMatcher {
+    Exact(String),
+    /// The pattern, split on `*`.
+    Pattern(Vec<String>),
+}
+
+impl CorsPolicy {
+    /// Create a new policy that doesn't allow any cross-origin request.
+    pub fn new() -> Self {
+        Self {
+            origins: AllowedOrigins::List(Vec::new()),
+            methods: None,
+            headers: AllowedHeaders::List(Vec::new()),
+            exposed_headers: Vec::new(),
+            allow_credentials: false,
+            max_age: None,
+        }
+    }
+
+    /// Allow requests coming from `origin` (e.g. `https://app.example.com`).
+    ///
+    /// The comparison is case-insensitive.
+    pub fn allow_origin(mut self, origin: &str) -> Self {
+        let matcher = OriginMatcher::Exact(origin.to_ascii_lowercase());
+        self.push_origin(matcher);
+        self
+    }
+
+    /// Allow requests coming from any origin matching `pattern`.
+    ///
+    /// Each `*` in the pattern matches a non-empty sequence of characters, excluding `/`.
+    /// E.g. `https://*.example.com` matches `https://app.example.com` and
+    /// `https://staging.app.example.com`, but not `https://example.com`.
+    /// `http://localhost:*` matches `http://localhost` on any explicit port.
+    ///
+    /// The comparison is case-insensitive.
+    pub fn allow_origin_pattern(mut self, pattern: &str) -> Self {
+        let segments = pattern
+            .to_ascii_lowercase()
+            .split('*')
+            .map(ToOwned::to_owned)
+            .collect();
+        self.push_origin(OriginMatcher::Pattern(segments));
+        self
+    }
+
+    /// Allow requests coming from any origin.
+    ///
+    /// # Panics
+    ///
+    /// It panics if [credentials are allowed](Self::allow_credentials): any website would
+    /// be able to issue credentialed requests on behalf of your users.
+    /// List the allowed origins explicitly, using [`allow_origin`](Self::allow_origin) or
+    /// [`allow_origin_pattern`](Self::allow_origin_pattern).
+    pub fn allow_any_origin(mut self) -> Self {
+        assert_credentials_are_not_combined_with_any_origin(true, self.allow_credentials);
+        self.origins = AllowedOrigins::Any;
+        self
+    }
+
+    /// Only allow cross-origin requests using one of the specified methods.
+    ///
+    /// By default, all the methods registered for the requested path are allowed.
+    /// If this method is called, the allowed methods are the intersection of `methods`
+    /// and the methods registered for the requested path.
+    pub fn allow_methods(mut self, methods: impl IntoIterator<Item = Method>) -> Self {
+        self.methods = Some(methods.into_iter().collect());
+        self
+    }
+
+    /// Allow cross-origin requests to include the specified headers.
+    ///
+    /// CORS-safelisted request headers (e.g. `Accept`) are always allowed.
+    ///
+    /// # Panics
+    ///
+    /// It panics if one of the header names is invalid.
+    pub fn allow_headers<I, H>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = H>,
+        H: AsRef<str>,
+    {
+        let headers = headers.into_iter().map(|h| parse_header_name(h.as_ref()));
+        match &mut self.headers {
+            AllowedHeaders::List(list) => list.extend(headers),
+            AllowedHeaders::Any => {}
+        }
+        self
+    }
+
+    /// Allow cross-origin requests to include any header.
+    pub fn allow_any_header(mut self) -> Self {
+        self.headers = AllowedHeaders::Any;
+        self
+    }
+
+    /// Let browsers expose the specified response headers to cross-origin scripts.
+    ///
+    /// CORS-safelisted response headers (e.g. `Content-Type`) are always exposed.
+    ///
+    /// # Panics
+    ///
+    /// It panics if one of the header names is invalid.
+    pub fn expose_headers<I, H>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = H>,
+        H: AsRef<str>,
+    {
+        self.exposed_headers
+            .extend(headers.into_iter().map(|h| parse_header_name(h.as_ref())));
+        self
+    }
+
+    /// Allow cross-origin requests to include credentials (cookies, TLS client
+    /// certificates, `Authorization` headers).
+    ///
+    /// # Panics
+    ///
+    /// It panics if `allow` is `true` and [any origin is allowed](Self::allow_any_origin):
+    /// credentials can only be allowed for an explicit list of origins.
+    pub fn allow_credentials(mut self, allow: bool) -> Self {
+        assert_credentials_are_not_combined_with_any_origin(
+            matches!(self.origins, AllowedOrigins::Any),
+            allow,
+        );
+        self.allow_credentials = allow;
+        self
+    }
+
+    /// For how long browsers can cache the outcome of a preflight request.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    fn push_origin(&mut self, matcher: OriginMatcher) {
+        match &mut self.origins {
+            AllowedOrigins::List(list) => list.push(matcher),
+            AllowedOrigins::Any => {}
+        }
+    }
+
+    /// Returns `true` if requests from `origin` are allowed.
+    pub fn is_origin_allowed(&self, origin: &str) -> bool {
+        match &self.origins {
+            AllowedOrigins::Any => true,
+            AllowedOrigins::List(matchers) => {
+                let origin = origin.to_ascii_lowercase();
+                matchers.iter().any(|m| m.matches(&origin))
+            }
+        }
+    }
+
+    /// The value of the `Access-Control-Allow-Origin` header for a request coming
+    /// from an allowed `origin`.
+    pub(super) fn allow_origin_header(&self, origin: &HeaderValue) -> HeaderValue {
+        match self.origins {
+            AllowedOrigins::Any => HeaderValue::from_static("*"),
+            AllowedOrigins::List(_) => origin.clone(),
+        }
+    }
+
+    /// Returns `true` if the value of `Access-Control-Allow-Origin` depends on the
+    /// origin of the request—i.e. caches must key responses on the `Origin` header.
+    pub(super) fn varies_on_origin(&self) -> bool {
+        !matches!(self.origins, AllowedOrigins::Any)
+    }
+}
+
+impl Default for CorsPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OriginMatcher {
+    fn matches(&self, origin: &str) -> bool {
+        match self {
+            OriginMatcher::Exact(o) => o == origin,
+            OriginMatcher::Pattern(segments) => matches_pattern(segments, origin),
+        }
+    }
+}
+
+/// Match `s` against a pattern split on `*`.
+/// Each wildcard must match at least one character, and never a `/`.
+fn matches_pattern(segments: &[String], s: &str) -> bool {
+    let Some((first, rest)) = segments.split_first() else {
+        return s.is_empty();
+    };
+    let Some(mut remainder) = s.strip_prefix(first.as_str()) else {
+        return false;
+    };
+    if rest.is_empty() {
+        return remainder.is_empty();
+    }
+    // Try every possible (non-empty) expansion for the next wildcard.
+    loop {
+        let Some(c) = remainder.chars().next() else {
+            return false;
+        };
+        if c == '/' {
+            return false;
+        }
+        remainder = &remainder[c.len_utf8()..];
+        if matches_pattern(rest, remainder) {
+            return true;
+        }
+    }
+}
+
+fn assert_credentials_are_not_combined_with_any_origin(any_origin: bool, credentials: bool) {
+    assert!(
+        !(any_origin && credentials),
+        "Credentials can't be allowed for requests coming from any origin: \
+        list the allowed origins explicitly via `CorsPolicy::allow_origin` or \
+        `CorsPolicy::allow_origin_pattern`"
+    );
+}
+
+fn parse_header_name(name: &str) -> HeaderName {
+    HeaderName::try_from(name).unwrap_or_else(|_| panic!("`{name}` is not a valid header name"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CorsPolicy;
+
+    #[test]
+    fn exact_origins() {
+        let policy = CorsPolicy::new().allow_origin("https://app.example.com");
+        assert!(policy.is_origin_allowed("https://app.example.com"));
+        assert!(policy.is_origin_allowed("HTTPS://APP.EXAMPLE.COM"));
+        assert!(!policy.is_origin_allowed("http://app.example.com"));
+        assert!(!policy.is_origin_allowed("https://app.example.com.evil.com"));
+    }
+
+    #[test]
+    fn origin_patterns() {
+        let policy = CorsPolicy::new()
+            .allow_origin_pattern("https://*.example.com")
+            .allow_origin_pattern("http://localhost:*");
+        assert!(policy.is_origin_allowed("https://app.example.com"));
+        assert!(policy.is_origin_allowed("https://staging.app.example.com"));
+        assert!(policy.is_origin_allowed("http://localhost:8080"));
+        assert!(!policy.is_origin_allowed("https://example.com"));
+        assert!(!policy.is_origin_allowed("https://evil.com/.example.com"));
+        assert!(!policy.is_origin_allowed("http://localhost"));
+    }
+
+    #[test]
+    fn nothing_is_allowed_by_default() {
+        assert!(!CorsPolicy::new().is_origin_allowed("https://example.com"));
+        assert!(CorsPolicy::new()
+            .allow_any_origin()
+            .is_origin_allowed("https://example.com"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Credentials can't be allowed for requests coming from any origin")]
+    fn credentials_cannot_be_allowed_for_any_origin() {
+        let _ = CorsPolicy::new().allow_any_origin().allow_credentials(true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Credentials can't be allowed for requests coming from any origin")]
+    fn any_origin_cannot_be_allowed_with_credentials() {
+        let _ = CorsPolicy::new().allow_credentials(true).allow_any_origin();
+    }
+}
@@ -0,0 +1,61 @@
+use http::HeaderName;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::router::{MethodGuard, GET, HEAD, OPTIONS, TRACE};
+use crate::blueprint::Blueprint;
+use crate::f;
+
+#[derive(Debug, Clone)]
+/// Configuration for CSRF protection.
+///
+/// Check out the [module-level documentation](super) for an overview.
+pub struct CsrfConfig {
+    /// The name of the (signed) cookie that holds the CSRF token.
+    ///
+    /// Defaults to `csrf_token`.
+    pub cookie_name: String,
+    /// The request header that [`csrf_middleware`](super::csrf_middleware) and
+    /// [`VerifiedCsrfForm`](super::VerifiedCsrfForm) inspect to find the submitted token.
+    ///
+    /// Defaults to `x-csrf-token`.
+    pub header_name: HeaderName,
+    /// The form field that [`VerifiedCsrfForm`](super::VerifiedCsrfForm) inspects
+    /// to find the submitted token, if the header is missing and the request body
+    /// is `application/x-www-form-urlencoded`.
+    ///
+    /// Defaults to `csrf_token`.
+    pub form_field: String,
+    /// Requests using these methods are never checked.
+    ///
+    /// Safe methods are not supposed to have side effects, therefore they can't be
+    /// abused in a CSRF attack.
+    /// Defaults to `GET`, `HEAD`, `OPTIONS` and `TRACE`.
+    pub safe_methods: MethodGuard,
+}
+
+impl CsrfConfig {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`CsrfConfig`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::default) for [`CsrfConfig`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(<pavex::csrf::CsrfConfig as std::default::Default>::default),
+            Lifecycle::Singleton,
+        )
+    }
+}
+
+impl Default for CsrfConfig {
+    fn default() -> Self {
+        Self {
+            cookie_name: "csrf_token".into(),
+            header_name: HeaderName::from_static("x-csrf-token"),
+            form_field: "csrf_token".into(),
+            safe_methods: GET.or(HEAD).or(OPTIONS).or(TRACE),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::response::Response;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+/// The error returned by [`csrf_middleware`](super::csrf_middleware) and
+/// [`VerifiedCsrfForm`](super::VerifiedCsrfForm) when an incoming request fails the CSRF check.
+pub enum CsrfError {
+    #[error("The CSRF cookie is missing or its signature is invalid.")]
+    /// The request doesn't carry a valid (signed) CSRF cookie.
+    MissingCookie,
+    #[error("The request doesn't include a CSRF token, neither as a header nor as a form field.")]
+    /// The request doesn't submit a CSRF token.
+    MissingToken,
+    #[error("The submitted CSRF token doesn't match the one stored in the CSRF cookie.")]
+    /// The submitted token doesn't match the one in the cookie.
+    TokenMismatch,
+}
+
+impl CsrfError {
+    /// Convert a [`CsrfError`] into an HTTP response.
+    ///
+    /// It always returns a `403 Forbidden`.
+    pub fn into_response(&self) -> Response {
+        Response::forbidden().set_typed_body(format!("{}", self))
+    }
+}
@@ -0,0 +1,69 @@
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::cookie::SignedCookies;
+use crate::f;
+use crate::request::body::BufferedBody;
+use crate::request::RequestHead;
+
+use super::middleware::{is_form, verify_token};
+use super::{CsrfConfig, CsrfError};
+
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+/// Proof that the incoming request passed the CSRF check, including the
+/// [form field](CsrfConfig::form_field) of `application/x-www-form-urlencoded` submissions.
+///
+/// [`csrf_middleware`](super::csrf_middleware) doesn't buffer the request body: it lets
+/// form submissions without a token header through.
+/// Inject [`VerifiedCsrfForm`] into the request handlers that process form submissions
+/// to look for the token in the (buffered) body.
+///
+/// Check out the [module-level documentation](super) for more details.
+pub struct VerifiedCsrfForm;
+
+impl VerifiedCsrfForm {
+    /// Verify the CSRF token submitted alongside the incoming request.
+    ///
+    /// Requests using a [safe method](CsrfConfig::safe_methods) are never checked.
+    /// The token is looked up in the [configured header](CsrfConfig::header_name) first and
+    /// then, for `application/x-www-form-urlencoded` bodies, in the
+    /// [configured form field](CsrfConfig::form_field).
+    pub fn extract(
+        request_head: &RequestHead,
+        body: &BufferedBody,
+        cookies: &SignedCookies,
+        config: &CsrfConfig,
+    ) -> Result<Self, CsrfError> {
+        if config.safe_methods.allows(&request_head.method) {
+            return Ok(Self);
+        }
+        let submitted = match request_head.headers.get(&config.header_name) {
+            Some(value) => value.to_str().ok().map(ToOwned::to_owned),
+            None if is_form(request_head) => form_urlencoded::parse(&body.bytes)
+                .find(|(key, _)| key == config.form_field.as_str())
+                .map(|(_, value)| value.into_owned()),
+            None => None,
+        };
+        verify_token(submitted.as_deref(), cookies, config)?;
+        Ok(Self)
+    }
+}
+
+impl VerifiedCsrfForm {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`VerifiedCsrfForm`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](VerifiedCsrfForm::extract)
+    /// and [error handler](CsrfError::into_response)
+    /// for [`VerifiedCsrfForm`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(pavex::csrf::VerifiedCsrfForm::extract),
+            Lifecycle::RequestScoped,
+        )
+        .error_handler(f!(pavex::csrf::CsrfError::into_response))
+    }
+}
@@ -0,0 +1,237 @@
+use std::future::IntoFuture;
+
+use http::header::CONTENT_TYPE;
+
+use crate::cookie::SignedCookies;
+use crate::middleware::Next;
+use crate::request::RequestHead;
+use crate::response::Response;
+
+use super::token::tokens_match;
+use super::{CsrfConfig, CsrfError};
+
+/// Reject requests using unsafe methods unless they carry a valid CSRF token.
+///
+/// The submitted token is looked up in the [configured header](CsrfConfig::header_name)
+/// and it must match the token stored in the signed CSRF cookie.
+///
+/// Requests using a [safe method](CsrfConfig::safe_methods) are never checked.
+/// The request body is never buffered: `application/x-www-form-urlencoded` submissions
+/// without the header are let through, and their [form field](CsrfConfig::form_field) must
+/// be checked by injecting [`VerifiedCsrfForm`](super::VerifiedCsrfForm) into the
+/// request handlers that process them.
+///
+/// Check out the [module-level documentation](super) for more details.
+pub async fn csrf_middleware<C>(
+    next: Next<C>,
+    request_head: &RequestHead,
+    cookies: &SignedCookies,
+    config: &CsrfConfig,
+) -> Result<Response, CsrfError>
+where
+    C: IntoFuture<Output = Response>,
+{
+    verify(request_head, cookies, config)?;
+    Ok(next.await)
+}
+
+fn verify(
+    request_head: &RequestHead,
+    cookies: &SignedCookies,
+    config: &CsrfConfig,
+) -> Result<(), CsrfError> {
+    if config.safe_methods.allows(&request_head.method) {
+        return Ok(());
+    }
+    match request_head.headers.get(&config.header_name) {
+        Some(value) => verify_token(value.to_str().ok(), cookies, config),
+        // The form field is checked by `VerifiedCsrfForm`.
+        None if is_form(request_head) => Ok(()),
+        None => verify_token(None, cookies, config),
+    }
+}
+
+/// Check that the `submitted` token matches the one stored in the signed CSRF cookie.
+pub(super) fn verify_token(
+    submitted: Option<&str>,
+    cookies: &SignedCookies,
+    config: &CsrfConfig,
+) -> Result<(), CsrfError> {
+    let expected = cookies
+        .get(&config.cookie_name)
+        .ok_or(CsrfError::MissingCookie)?;
+    let submitted = submitted.ok_or(CsrfError::MissingToken)?;
+    if tokens_match(expected.value(), submitted) {
+        Ok(())
+    } else {
+        Err(CsrfError::TokenMismatch)
+    }
+}
+
+pub(super) fn is_form(request_head: &RequestHead) -> bool {
+    request_head
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<mime::Mime>().ok())
+        .map(|m| m.essence_str() == "application/x-www-form-urlencoded")
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use http::Method;
+
+    use super::verify;
+    use crate::cookie::{CookieKeys, Key, RequestCookies, ResponseCookies, SignedCookies};
+    use crate::csrf::{CsrfConfig, CsrfError, CsrfToken, VerifiedCsrfForm};
+    use crate::request::body::BufferedBody;
+    use crate::request::test_utils::request_head;
+    use crate::request::RequestHead;
+
+    struct Client {
+        keys: CookieKeys,
+        cookie_header: String,
+        token: String,
+    }
+
+    /// Simulate a first request that renders a form and returns the CSRF cookie.
+    fn client(config: &CsrfConfig) -> Client {
+        let keys = CookieKeys::new(Key::generate());
+        let (head, _) = request(Method::GET, "", &[]);
+        let response_cookies = response_cookies(&head);
+        let signed = SignedCookies::new(&response_cookies, &keys);
+        let token = CsrfToken::new(&signed, config).value().to_owned();
+        let cookie = response_cookies.get_cloned(&config.cookie_name).unwrap();
+        Client {
+            keys,
+            cookie_header: format!("{}={}", cookie.name(), cookie.value()),
+            token,
+        }
+    }
+
+    fn request(
+        method: Method,
+        body: &str,
+        headers: &[(&str, &str)],
+    ) -> (RequestHead, BufferedBody) {
+        let body = BufferedBody {
+            bytes: Bytes::copy_from_slice(body.as_bytes()),
+        };
+        (request_head(method, "/", headers), body)
+    }
+
+    fn response_cookies(head: &RequestHead) -> ResponseCookies {
+        ResponseCookies::from_request_cookies(&RequestCookies::extract(head))
+    }
+
+    /// Run the checks performed by the middleware and by [`VerifiedCsrfForm`].
+    fn check(
+        client: &Client,
+        config: &CsrfConfig,
+        method: Method,
+        body: &str,
+        headers: &[(&str, &str)],
+    ) -> (Result<(), CsrfError>, Result<VerifiedCsrfForm, CsrfError>) {
+        let mut headers = headers.to_vec();
+        headers.push(("cookie", &client.cookie_header));
+        let (head, body) = request(method, body, &headers);
+        let signed = SignedCookies::new(&response_cookies(&head), &client.keys);
+        (
+            verify(&head, &signed, config),
+            VerifiedCsrfForm::extract(&head, &body, &signed, config),
+        )
+    }
+
+    #[test]
+    fn tokens_are_reused_across_requests() {
+        let config = CsrfConfig::default();
+        let client = client(&config);
+        let (head, _) = request(Method::GET, "", &[("cookie", &client.cookie_header)]);
+        let signed = SignedCookies::new(&response_cookies(&head), &client.keys);
+        assert_eq!(CsrfToken::new(&signed, &config).value(), client.token);
+    }
+
+    #[test]
+    fn safe_methods_are_not_checked() {
+        let config = CsrfConfig::default();
+        let (head, body) = request(Method::GET, "", &[]);
+        let signed = SignedCookies::new(&response_cookies(&head), &client(&config).keys);
+        assert!(verify(&head, &signed, &config).is_ok());
+        assert!(VerifiedCsrfForm::extract(&head, &body, &signed, &config).is_ok());
+    }
+
+    #[test]
+    fn token_via_header() {
+        let config = CsrfConfig::default();
+        let client = client(&config);
+        let token = client.token.clone();
+        let (middleware, form) = check(
+            &client,
+            &config,
+            Method::POST,
+            "",
+            &[("x-csrf-token", &token)],
+        );
+        assert!(middleware.is_ok());
+        assert!(form.is_ok());
+    }
+
+    #[test]
+    fn token_via_form_field() {
+        let config = CsrfConfig::default();
+        let client = client(&config);
+        let form_headers = [("content-type", "application/x-www-form-urlencoded")];
+
+        let form = format!("content=hello&csrf_token={}", client.token);
+        let (middleware, extractor) = check(&client, &config, Method::POST, &form, &form_headers);
+        assert!(middleware.is_ok());
+        assert!(extractor.is_ok());
+
+        // The middleware doesn't look at the body: the form field is checked by the extractor.
+        let form = "content=hello&csrf_token=nope";
+        let (middleware, extractor) = check(&client, &config, Method::POST, form, &form_headers);
+        assert!(middleware.is_ok());
+        assert!(matches!(extractor, Err(CsrfError::TokenMismatch)));
+
+        // The form field is ignored if the body is not a form.
+        let form = format!("content=hello&csrf_token={}", client.token);
+        let (middleware, extractor) = check(&client, &config, Method::POST, &form, &[]);
+        assert!(matches!(middleware, Err(CsrfError::MissingToken)));
+        assert!(matches!(extractor, Err(CsrfError::MissingToken)));
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        let config = CsrfConfig::default();
+        let client = client(&config);
+        let (middleware, form) = check(
+            &client,
+            &config,
+            Method::DELETE,
+            "",
+            &[("x-csrf-token", "nope")],
+        );
+        assert!(matches!(middleware, Err(CsrfError::TokenMismatch)));
+        assert!(matches!(form, Err(CsrfError::TokenMismatch)));
+        let (middleware, form) = check(&client, &config, Method::PUT, "", &[]);
+        assert!(matches!(middleware, Err(CsrfError::MissingToken)));
+        assert!(matches!(form, Err(CsrfError::MissingToken)));
+
+        // A cookie signed with another key is ignored.
+        let other = Client {
+            keys: CookieKeys::new(Key::generate()),
+            ..client
+        };
+        let (middleware, form) = check(
+            &other,
+            &config,
+            Method::POST,
+            "",
+            &[("x-csrf-token", &other.token)],
+        );
+        assert!(matches!(middleware, Err(CsrfError::MissingCookie)));
+        assert!(matches!(form, Err(CsrfError::MissingCookie)));
+    }
+}
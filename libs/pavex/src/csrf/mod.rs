@@ -0,0 +1,67 @@
+//! Cross-Site Request Forgery (CSRF) protection.
+//!
+//! # Overview
+//!
+//! Pavex implements the signed double-submit cookie pattern:
+//!
+//! 1. [`CsrfToken`] generates a random token and stores it in a
+//!    [signed cookie](crate::cookie::SignedCookies).
+//!    Inject it into the handlers that render your forms and embed its
+//!    [value](CsrfToken::value) in the page.
+//! 2. [`csrf_middleware`] rejects requests using an unsafe method
+//!    (i.e. not allowed by [`CsrfConfig::safe_methods`]) unless they submit the
+//!    same token via a header, with a `403 Forbidden`.
+//! 3. Form submissions carry the token in a form field instead.
+//!    [`csrf_middleware`] lets them through, since it never buffers the request body:
+//!    inject [`VerifiedCsrfForm`] into the handlers that process them to check the field.
+//!
+//! An attacker can trigger requests from another origin, but it can neither read
+//! the token nor forge a valid signed cookie.
+//!
+//! ```rust
+//! use pavex::csrf::{CsrfToken, VerifiedCsrfForm};
+//! use pavex::request::body::BufferedBody;
+//! use pavex::response::{body::Html, Response};
+//!
+//! pub fn new_post_form(token: &CsrfToken) -> Response {
+//!     let body = format!(
+//!         r#"<form method="post" action="/posts">
+//!           <input type="hidden" name="csrf_token" value="{}">
+//!           <textarea name="content"></textarea>
+//!         </form>"#,
+//!         token.value()
+//!     );
+//!     Response::ok().set_typed_body(Html::from(body))
+//! }
+//!
+//! // The form was submitted with a valid token, otherwise `VerifiedCsrfForm`'s
+//! // constructor would have rejected the request.
+//! pub fn create_post(_csrf: &VerifiedCsrfForm, body: &BufferedBody) -> Response {
+//!     // [...]
+//!     # todo!()
+//! }
+//! ```
+//!
+//! # Setup
+//!
+//! [`csrf_middleware`] requires [`SignedCookies`](crate::cookie::SignedCookies).
+//! Wrap your routes with it, and register [`CsrfToken`] and [`CsrfConfig`]
+//! via their default constructors.
+//!
+//! [`VerifiedCsrfForm`] also requires [`BufferedBody`](crate::request::body::BufferedBody):
+//! register it via its default constructor and inject it into every handler that
+//! processes form submissions—**form fields are not checked otherwise**.
+//!
+//! This module is only available if the `csrf` feature is enabled.
+//! It's not enabled by default.
+pub use config::CsrfConfig;
+pub use errors::CsrfError;
+pub use form::VerifiedCsrfForm;
+pub use middleware::csrf_middleware;
+pub use token::CsrfToken;
+
+mod config;
+mod errors;
+mod form;
+mod middleware;
+mod token;
@@ -0,0 +1,87 @@
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+use crate::blueprint::constructor::{
+    CloningStrategy, Constructor, Lifecycle, RegisteredConstructor,
+};
+use crate::blueprint::Blueprint;
+use crate::cookie::{Cookie, SignedCookies};
+use crate::f;
+
+use super::CsrfConfig;
+
+/// The number of characters in a CSRF token (~256 bits of entropy).
+const TOKEN_LENGTH: usize = 43;
+
+#[derive(Debug, Clone)]
+/// The CSRF token for the current client.
+///
+/// If the client already holds a valid CSRF cookie, its token is reused.
+/// Otherwise a new random token is generated and stored in a signed cookie,
+/// which is attached to the outgoing response.
+///
+/// Check out the [module-level documentation](super) for an example.
+pub struct CsrfToken {
+    value: String,
+}
+
+impl CsrfToken {
+    /// Retrieve the CSRF token for the current client, generating a new one if needed.
+    pub fn new(cookies: &SignedCookies, config: &CsrfConfig) -> Self {
+        if let Some(cookie) = cookies.get(&config.cookie_name) {
+            if is_well_formed(cookie.value()) {
+                return Self {
+                    value: cookie.value().to_owned(),
+                };
+            }
+        }
+        let value: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+        let cookie = Cookie::build((config.cookie_name.clone(), value.clone()))
+            // The token is meant to be embedded in the page by the server:
+            // scripts don't need to read the cookie.
+            .http_only(true);
+        cookies.add(cookie.into());
+        Self { value }
+    }
+
+    /// The token value, to be submitted alongside unsafe requests via the
+    /// configured header or form field.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl CsrfToken {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`CsrfToken`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](CsrfToken::new) for [`CsrfToken`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(f!(crate::csrf::CsrfToken::new), Lifecycle::RequestScoped)
+            .cloning(CloningStrategy::CloneIfNecessary)
+    }
+}
+
+fn is_well_formed(token: &str) -> bool {
+    token.len() == TOKEN_LENGTH && token.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// Compare two tokens in constant time, to avoid leaking information via timing.
+pub(super) fn tokens_match(expected: &str, submitted: &str) -> bool {
+    let (expected, submitted) = (expected.as_bytes(), submitted.as_bytes());
+    if expected.len() != submitted.len() {
+        return false;
+    }
+    expected
+        .iter()
+        .zip(submitted)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
@@ -0,0 +1,63 @@
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use bytes::{Bytes, BytesMut};
+use http_body::{Frame, SizeHint};
+use tokio::io::{AsyncRead, ReadBuf};
+
+use crate::response::body::raw::RawBody;
+
+/// The size of the chunks read from disk.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// A response body streaming (a portion of) a file from disk.
+pub(super) struct FileBody {
+    file: tokio::fs::File,
+    remaining: u64,
+}
+
+impl FileBody {
+    /// Stream `len` bytes from `file`, starting at its current position.
+    pub(super) fn new(file: tokio::fs::File, len: u64) -> Self {
+        Self {
+            file,
+            remaining: len,
+        }
+    }
+}
+
+impl RawBody for FileBody {
+    type Data = Bytes;
+    type Error = std::io::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+        let chunk_size = self.remaining.min(CHUNK_SIZE as u64) as usize;
+        let mut buffer = BytesMut::zeroed(chunk_size);
+        let mut read_buf = ReadBuf::new(&mut buffer);
+        if let Err(e) = ready!(Pin::new(&mut self.file).poll_read(cx, &mut read_buf)) {
+            return Poll::Ready(Some(Err(e)));
+        }
+        let n_read = read_buf.filled().len();
+        if n_read == 0 {
+            // The file was truncated while we were reading it.
+            return Poll::Ready(Some(Err(std::io::ErrorKind::UnexpectedEof.into())));
+        }
+        buffer.truncate(n_read);
+        self.remaining -= n_read as u64;
+        Poll::Ready(Some(Ok(Frame::data(buffer.freeze()))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.remaining == 0
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.remaining)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::response::Response;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+/// The error returned by [`serve_dir`](fn@super::serve_dir).
+pub enum ServeDirError {
+    #[error("Failed to read `{path}`")]
+    /// The requested file exists, but it couldn't be read.
+    Io {
+        /// The path of the file on disk.
+        path: std::path::PathBuf,
+        #[source]
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+}
+
+impl ServeDirError {
+    /// Convert a [`ServeDirError`] into an HTTP response.
+    ///
+    /// It always returns a `500 Internal Server Error`: missing files are
+    /// reported as `404 Not Found` by [`serve_dir`](fn@super::serve_dir) directly.
+    pub fn into_response(&self) -> Response {
+        Response::internal_server_error()
+    }
+}
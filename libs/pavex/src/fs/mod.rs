@@ -0,0 +1,52 @@
+//! Serve static files from a directory.
+//!
+//! # Overview
+//!
+//! [`serve_dir`](fn@serve_dir) is a request handler that maps the catch-all path parameter of a route
+//! to a file inside a [`ServeDir`]'s root directory.
+//!
+//! ```rust
+//! use pavex::blueprint::{constructor::Lifecycle, router::GET, Blueprint};
+//! use pavex::f;
+//! use pavex::fs::ServeDir;
+//!
+//! pub fn assets() -> ServeDir {
+//!     ServeDir::new("assets").precompressed_gzip().precompressed_br()
+//! }
+//!
+//! let mut bp = Blueprint::new();
+//! bp.constructor(f!(crate::assets), Lifecycle::Singleton);
+//! bp.route(GET, "/assets/*path", f!(pavex::fs::serve_dir))
+//!     .error_handler(f!(pavex::fs::ServeDirError::into_response));
+//! ```
+//!
+//! The handler takes care of:
+//!
+//! - rejecting paths that try to escape the root directory (e.g. `../../etc/passwd`);
+//! - setting `Content-Type` based on the file extension;
+//! - setting `ETag` and `Last-Modified`, and honoring conditional requests
+//!   (`If-None-Match`, `If-Modified-Since`, `If-Match`, `If-Unmodified-Since`);
+//! - serving partial content for `Range` requests;
+//! - serving precompressed `.br`/`.gz` siblings, when the client accepts them;
+//! - serving an index file for directories and, optionally, a fallback file for
+//!   paths that don't match any file—e.g. the entrypoint of a single-page application.
+//!
+//! # Single-page applications
+//!
+//! ```rust
+//! use pavex::fs::ServeDir;
+//!
+//! pub fn spa() -> ServeDir {
+//!     // Unknown paths are routed client-side: serve `index.html` for all of them.
+//!     ServeDir::new("dist").fallback("index.html")
+//! }
+//! ```
+pub use errors::ServeDirError;
+pub use serve_dir::{serve_dir, ServeDir};
+
+mod body;
+mod content_type;
+mod errors;
+mod path;
+mod range;
+mod serve_dir;
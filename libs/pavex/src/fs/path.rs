@@ -0,0 +1,72 @@
+use std::path::{Component, Path, PathBuf};
+
+use percent_encoding::percent_decode_str;
+
+/// Turn the raw (percent-encoded) value of the catch-all path parameter into
+/// a relative path that's guaranteed to stay inside the root directory.
+///
+/// It returns `None` if the path is malformed or if it tries to escape the root
+/// directory.
+pub(super) fn sanitize(raw: &str, allow_hidden: bool) -> Option<PathBuf> {
+    let decoded = percent_decode_str(raw).decode_utf8().ok()?;
+    let mut relative = PathBuf::new();
+    for segment in decoded.split('/') {
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+        if segment == ".."
+            || segment.contains(['\\', '\0', ':'])
+            || (!allow_hidden && segment.starts_with('.'))
+        {
+            return None;
+        }
+        // Defense in depth: each segment must be a plain file name on the current platform.
+        let mut components = Path::new(segment).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(_)), None) => relative.push(segment),
+            _ => return None,
+        }
+    }
+    Some(relative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sanitize;
+    use std::path::PathBuf;
+
+    #[test]
+    fn valid_paths() {
+        assert_eq!(
+            sanitize("css/main.css", false),
+            Some(PathBuf::from("css/main.css"))
+        );
+        assert_eq!(
+            sanitize("a//b/./c%20d.txt", false),
+            Some(PathBuf::from("a/b/c d.txt"))
+        );
+        assert_eq!(sanitize("", false), Some(PathBuf::new()));
+        assert_eq!(
+            sanitize(".well-known/x", true),
+            Some(PathBuf::from(".well-known/x"))
+        );
+    }
+
+    #[test]
+    fn traversal_attempts_are_rejected() {
+        for raw in [
+            "../secret",
+            "a/../../secret",
+            "%2e%2e/secret",
+            "a/%2E%2E/%2E%2E/secret",
+            "..%2fsecret",
+            "a\\..\\secret",
+            "C:/Windows",
+            "file%00.txt",
+            ".env",
+            "%ff",
+        ] {
+            assert_eq!(sanitize(raw, false), None, "{raw}");
+        }
+    }
+}
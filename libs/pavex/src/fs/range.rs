@@ -0,0 +1,92 @@
+/// The outcome of evaluating a `Range` header against a representation of `len` bytes.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum ByteRange {
+    /// Serve the whole representation.
+    /// The header is missing, malformed or asks for multiple ranges—which we don't support.
+    Full,
+    /// Serve the bytes in `start..=end`.
+    Partial { start: u64, end: u64 },
+    /// None of the requested bytes exist.
+    Unsatisfiable,
+}
+
+/// Parse the value of a `Range` header, as specified in RFC 9110 §14.1.2.
+pub(super) fn parse(header: &str, len: u64) -> ByteRange {
+    let Some(spec) = header.trim().strip_prefix("bytes=") else {
+        return ByteRange::Full;
+    };
+    if spec.contains(',') {
+        return ByteRange::Full;
+    }
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return ByteRange::Full;
+    };
+    let (start, end) = (start.trim(), end.trim());
+    if start.is_empty() {
+        // Suffix range: the last `n` bytes.
+        let Ok(n) = end.parse::<u64>() else {
+            return ByteRange::Full;
+        };
+        if n == 0 || len == 0 {
+            return ByteRange::Unsatisfiable;
+        }
+        return ByteRange::Partial {
+            start: len.saturating_sub(n),
+            end: len - 1,
+        };
+    }
+    let Ok(start) = start.parse::<u64>() else {
+        return ByteRange::Full;
+    };
+    let end = if end.is_empty() {
+        u64::MAX
+    } else {
+        match end.parse::<u64>() {
+            Ok(end) if end >= start => end,
+            _ => return ByteRange::Full,
+        }
+    };
+    if start >= len {
+        return ByteRange::Unsatisfiable;
+    }
+    ByteRange::Partial {
+        start,
+        end: end.min(len - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, ByteRange};
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            parse("bytes=0-9", 100),
+            ByteRange::Partial { start: 0, end: 9 }
+        );
+        assert_eq!(
+            parse("bytes=90-", 100),
+            ByteRange::Partial { start: 90, end: 99 }
+        );
+        assert_eq!(
+            parse("bytes=90-1000", 100),
+            ByteRange::Partial { start: 90, end: 99 }
+        );
+        assert_eq!(
+            parse("bytes=-10", 100),
+            ByteRange::Partial { start: 90, end: 99 }
+        );
+        assert_eq!(
+            parse("bytes=-1000", 100),
+            ByteRange::Partial { start: 0, end: 99 }
+        );
+        assert_eq!(parse("bytes=100-", 100), ByteRange::Unsatisfiable);
+        assert_eq!(parse("bytes=-0", 100), ByteRange::Unsatisfiable);
+        assert_eq!(parse("bytes=0-", 0), ByteRange::Unsatisfiable);
+        assert_eq!(parse("bytes=9-0", 100), ByteRange::Full);
+        assert_eq!(parse("bytes=0-1,5-6", 100), ByteRange::Full);
+        assert_eq!(parse("items=0-1", 100), ByteRange::Full);
+        assert_eq!(parse("bytes=a-b", 100), ByteRange::Full);
+    }
+}
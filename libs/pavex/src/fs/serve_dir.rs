@@ -0,0 +1,589 @@
+use std::ffi::OsString;
+use std::fs::Metadata;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use http::header::{
+    ACCEPT_ENCODING, ACCEPT_RANGES, ALLOW, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_LENGTH,
+    CONTENT_RANGE, CONTENT_TYPE, IF_RANGE, LOCATION, RANGE,
+};
+use http::{HeaderMap, HeaderValue, Method};
+use tokio::io::AsyncSeekExt;
+
+use crate::conditional::precondition::truncate_to_seconds;
+use crate::conditional::{evaluate_preconditions, ETag, Precondition};
+use crate::request::path::RawPathParams;
+use crate::request::RequestHead;
+use crate::response::Response;
+
+use super::body::FileBody;
+use super::content_type::content_type;
+use super::path::sanitize;
+use super::range::{self, ByteRange};
+use super::ServeDirError;
+
+#[derive(Debug, Clone)]
+/// A directory whose files are served by [`serve_dir`].
+///
+/// # Singleton
+///
+/// There is no default constructor for [`ServeDir`]: Pavex can't know which
+/// directory you want to serve.
+/// Register a singleton constructor for it with your [`Blueprint`].
+///
+/// Check out the [module-level documentation](super) for an example.
+///
+/// [`Blueprint`]: crate::blueprint::Blueprint
+pub struct ServeDir {
+    root: PathBuf,
+    path_parameter: String,
+    index_file: Option<String>,
+    fallback: Option<PathBuf>,
+    precompressed_br: bool,
+    precompressed_gzip: bool,
+    cache_control: Option<HeaderValue>,
+    serve_hidden_files: bool,
+}
+
+impl ServeDir {
+    /// Serve the files inside `root`.
+    ///
+    /// Relative paths are resolved against the working directory of your application.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            path_parameter: "path".into(),
+            index_file: Some("index.html".into()),
+            fallback: None,
+            precompressed_br: false,
+            precompressed_gzip: false,
+            cache_control: None,
+            serve_hidden_files: false,
+        }
+    }
+
+    /// The name of the catch-all path parameter that holds the path of the requested file.
+    ///
+    /// Defaults to `path`—i.e. routes should look like `/assets/*path`.
+    pub fn path_parameter(mut self, name: &str) -> Self {
+        self.path_parameter = name.into();
+        self
+    }
+
+    /// The file served when a directory is requested.
+    ///
+    /// Defaults to `index.html`.
+    pub fn index_file(mut self, name: &str) -> Self {
+        self.index_file = Some(name.into());
+        self
+    }
+
+    /// Return `404 Not Found` when a directory is requested.
+    pub fn without_index_file(mut self) -> Self {
+        self.index_file = None;
+        self
+    }
+
+    /// Serve `path` (relative to the root directory) when the requested file doesn't exist,
+    /// instead of returning a `404 Not Found`.
+    ///
+    /// This is what single-page applications expect: the fallback is usually their
+    /// `index.html` entrypoint.
+    pub fn fallback(mut self, path: impl Into<PathBuf>) -> Self {
+        self.fallback = Some(path.into());
+        self
+    }
+
+    /// Serve `<file>.br`, if it exists, to clients that accept Brotli-encoded responses.
+    pub fn precompressed_br(mut self) -> Self {
+        self.precompressed_br = true;
+        self
+    }
+
+    /// Serve `<file>.gz`, if it exists, to clients that accept gzip-encoded responses.
+    pub fn precompressed_gzip(mut self) -> Self {
+        self.precompressed_gzip = true;
+        self
+    }
+
+    /// Set the `Cache-Control` header on all successful responses.
+    pub fn cache_control(mut self, value: HeaderValue) -> Self {
+        self.cache_control = Some(value);
+        self
+    }
+
+    /// Serve files and directories whose name starts with a `.` (e.g. `.well-known`).
+    ///
+    /// Hidden files are not served by default, since they often contain secrets
+    /// (e.g. `.env`) or version control metadata (e.g. `.git`).
+    pub fn serve_hidden_files(mut self, serve: bool) -> Self {
+        self.serve_hidden_files = serve;
+        self
+    }
+
+    fn is_precompression_enabled(&self) -> bool {
+        self.precompressed_br || self.precompressed_gzip
+    }
+}
+
+/// Serve a file from a [`ServeDir`].
+///
+/// The path of the file is taken from the catch-all path parameter
+/// specified via [`ServeDir::path_parameter`].
+/// Requests using a method other than `GET` or `HEAD` are rejected
+/// with a `405 Method Not Allowed`.
+///
+/// Check out the [module-level documentation](super) for more details.
+pub async fn serve_dir(
+    dir: &ServeDir,
+    request_head: &RequestHead,
+    path_params: &RawPathParams<'_, '_>,
+) -> Result<Response, ServeDirError> {
+    if request_head.method != Method::GET && request_head.method != Method::HEAD {
+        return Ok(Response::method_not_allowed()
+            .insert_header(ALLOW, HeaderValue::from_static("GET, HEAD")));
+    }
+    let raw_path = path_params.get(&dir.path_parameter).unwrap_or_default();
+    let Some(relative) = sanitize(raw_path, dir.serve_hidden_files) else {
+        return Ok(Response::not_found());
+    };
+    let path = dir.root.join(relative);
+    match metadata(&path).await? {
+        Some(m) if m.is_file() => serve_file(dir, request_head, path, m).await,
+        Some(m) if m.is_dir() => {
+            let Some(index_file) = &dir.index_file else {
+                return serve_fallback(dir, request_head).await;
+            };
+            let request_path = request_head.target.path();
+            if !request_path.ends_with('/') {
+                return Ok(redirect_to_directory(request_head));
+            }
+            let index = path.join(index_file);
+            match metadata(&index).await? {
+                Some(m) if m.is_file() => serve_file(dir, request_head, index, m).await,
+                _ => serve_fallback(dir, request_head).await,
+            }
+        }
+        _ => serve_fallback(dir, request_head).await,
+    }
+}
+
+/// Retrieve the metadata of the file at `path`, following symlinks.
+/// It returns `None` if there's nothing at `path`.
+async fn metadata(path: &Path) -> Result<Option<Metadata>, ServeDirError> {
+    match tokio::fs::metadata(path).await {
+        Ok(m) => Ok(Some(m)),
+        Err(e) if is_not_found(&e) => Ok(None),
+        Err(e) => Err(ServeDirError::Io {
+            path: path.to_owned(),
+            source: e,
+        }),
+    }
+}
+
+/// Returns `true` if the error means that there's nothing at the requested path.
+///
+/// A path that goes "through" a file (e.g. `index.html/foo`) fails with `ENOTDIR` on Unix.
+/// We match on the raw OS error since `ErrorKind::NotADirectory` requires a newer
+/// toolchain than the nightly used to compute the JSON docs for `pavex`.
+fn is_not_found(e: &std::io::Error) -> bool {
+    /// `ENOTDIR` has the same value on Linux, macOS and the BSDs.
+    #[cfg(unix)]
+    const ENOTDIR: i32 = 20;
+
+    if e.kind() == std::io::ErrorKind::NotFound {
+        return true;
+    }
+    #[cfg(unix)]
+    if e.raw_os_error() == Some(ENOTDIR) {
+        return true;
+    }
+    false
+}
+
+async fn serve_fallback(
+    dir: &ServeDir,
+    request_head: &RequestHead,
+) -> Result<Response, ServeDirError> {
+    let Some(fallback) = &dir.fallback else {
+        return Ok(Response::not_found());
+    };
+    let path = dir.root.join(fallback);
+    match metadata(&path).await? {
+        Some(m) if m.is_file() => serve_file(dir, request_head, path, m).await,
+        _ => Ok(Response::not_found()),
+    }
+}
+
+/// Redirect `/assets/docs` to `/assets/docs/`, so that relative links in
+/// the index file are resolved correctly by browsers.
+fn redirect_to_directory(request_head: &RequestHead) -> Response {
+    let last_segment = request_head
+        .target
+        .path()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    // A relative reference, so that we never redirect to a different origin.
+    let mut location = format!("./{last_segment}/");
+    if let Some(query) = request_head.target.query() {
+        location.push('?');
+        location.push_str(query);
+    }
+    match HeaderValue::try_from(location) {
+        Ok(location) => Response::permanent_redirect().insert_header(LOCATION, location),
+        Err(_) => Response::not_found(),
+    }
+}
+
+async fn serve_file(
+    dir: &ServeDir,
+    request_head: &RequestHead,
+    path: PathBuf,
+    metadata: Metadata,
+) -> Result<Response, ServeDirError> {
+    let content_type = content_type(&path);
+    let (path, metadata, encoding) = match precompressed(dir, &request_head.headers, &path).await? {
+        Some((path, metadata, encoding)) => (path, metadata, Some(encoding)),
+        None => (path, metadata, None),
+    };
+    let len = metadata.len();
+    let last_modified = metadata.modified().ok();
+    let etag = etag(len, last_modified, encoding);
+
+    let mut response = Response::ok().set_etag(&etag);
+    if let Some(last_modified) = last_modified {
+        response = response.set_last_modified(last_modified);
+    }
+    if let Some(cache_control) = &dir.cache_control {
+        response = response.insert_header(CACHE_CONTROL, cache_control.clone());
+    }
+    if dir.is_precompression_enabled() {
+        response = response.append_vary(ACCEPT_ENCODING);
+    }
+
+    match evaluate_preconditions(request_head, Some(&etag), last_modified) {
+        Precondition::Proceed => {}
+        Precondition::NotModified => {
+            return Ok(response.set_status(http::StatusCode::NOT_MODIFIED));
+        }
+        Precondition::Failed => return Ok(Response::precondition_failed()),
+    }
+
+    response = response
+        .insert_header(CONTENT_TYPE, content_type)
+        .insert_header(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    if let Some(encoding) = encoding {
+        response = response.insert_header(CONTENT_ENCODING, HeaderValue::from_static(encoding));
+    }
+
+    let byte_range = match request_head.headers.get(RANGE) {
+        Some(range) if if_range_matches(&request_head.headers, &etag, last_modified) => range
+            .to_str()
+            .map(|range| range::parse(range, len))
+            .unwrap_or(ByteRange::Full),
+        _ => ByteRange::Full,
+    };
+    let (start, body_len) = match byte_range {
+        ByteRange::Full => (0, len),
+        ByteRange::Partial { start, end } => {
+            let content_range = HeaderValue::try_from(format!("bytes {start}-{end}/{len}"))
+                .expect("A byte range is always a valid header value");
+            response = response
+                .set_status(http::StatusCode::PARTIAL_CONTENT)
+                .insert_header(CONTENT_RANGE, content_range);
+            (start, end - start + 1)
+        }
+        ByteRange::Unsatisfiable => {
+            let content_range = HeaderValue::try_from(format!("bytes */{len}"))
+                .expect("A byte range is always a valid header value");
+            return Ok(
+                Response::range_not_satisfiable().insert_header(CONTENT_RANGE, content_range)
+            );
+        }
+    };
+    response = response.insert_header(CONTENT_LENGTH, body_len.into());
+
+    if request_head.method == Method::HEAD {
+        return Ok(response);
+    }
+    let io_error = |source| ServeDirError::Io {
+        path: path.clone(),
+        source,
+    };
+    let mut file = tokio::fs::File::open(&path).await.map_err(io_error)?;
+    if start > 0 {
+        file.seek(SeekFrom::Start(start)).await.map_err(io_error)?;
+    }
+    Ok(response.set_raw_body(FileBody::new(file, body_len)))
+}
+
+/// Look for a precompressed sibling of the file at `path` that the client accepts.
+async fn precompressed(
+    dir: &ServeDir,
+    request_headers: &HeaderMap,
+    path: &Path,
+) -> Result<Option<(PathBuf, Metadata, &'static str)>, ServeDirError> {
+    let candidates = [
+        (dir.precompressed_br, "br", ".br"),
+        (dir.precompressed_gzip, "gzip", ".gz"),
+    ];
+    for (enabled, encoding, extension) in candidates {
+        if !enabled || !accepts_encoding(request_headers, encoding) {
+            continue;
+        }
+        let mut candidate = OsString::from(path.as_os_str());
+        candidate.push(extension);
+        let candidate = PathBuf::from(candidate);
+        if let Some(m) = metadata(&candidate).await? {
+            if m.is_file() {
+                return Ok(Some((candidate, m, encoding)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Returns `true` if `Accept-Encoding` lists `encoding` (or `*`) with a non-zero quality.
+fn accepts_encoding(request_headers: &HeaderMap, encoding: &str) -> bool {
+    let mut accepted = None;
+    for item in request_headers
+        .get_all(ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+    {
+        let mut parts = item.split(';');
+        let token = parts.next().unwrap_or_default().trim();
+        let is_explicit = token.eq_ignore_ascii_case(encoding);
+        if !is_explicit && token != "*" {
+            continue;
+        }
+        let q = parts
+            .filter_map(|p| p.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+            .and_then(|(_, q)| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        // An explicit mention takes precedence over the wildcard.
+        if is_explicit {
+            return q > 0.0;
+        }
+        accepted = Some(q > 0.0);
+    }
+    accepted.unwrap_or(false)
+}
+
+/// A strong validator derived from the size and modification time of the file.
+fn etag(len: u64, modified: Option<SystemTime>, encoding: Option<&str>) -> ETag {
+    let modified = modified
+        .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+        .unwrap_or_default();
+    let suffix = encoding.map(|e| format!("-{e}")).unwrap_or_default();
+    ETag::strong(format!(
+        "{len:x}-{:x}.{:x}{suffix}",
+        modified.as_secs(),
+        modified.subsec_nanos()
+    ))
+}
+
+/// Returns `true` if the `Range` header should be honored, according to `If-Range`.
+fn if_range_matches(
+    request_headers: &HeaderMap,
+    etag: &ETag,
+    last_modified: Option<SystemTime>,
+) -> bool {
+    let Some(if_range) = request_headers.get(IF_RANGE).and_then(|v| v.to_str().ok()) else {
+        return true;
+    };
+    if let Ok(candidate) = if_range.parse::<ETag>() {
+        return candidate.strong_eq(etag);
+    }
+    match (httpdate::parse_http_date(if_range), last_modified) {
+        (Ok(date), Some(last_modified)) => date == truncate_to_seconds(last_modified),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{
+        CONTENT_ENCODING, CONTENT_RANGE, CONTENT_TYPE, ETAG, LAST_MODIFIED, LOCATION,
+    };
+    use http::{Method, StatusCode};
+    use http_body_util::BodyExt;
+
+    use super::{serve_dir, ServeDir};
+    use crate::request::path::RawPathParams;
+    use crate::request::test_utils::request_head;
+    use crate::response::Response;
+
+    async fn get(dir: &ServeDir, path: &str, headers: &[(&str, &str)]) -> Response {
+        request(dir, Method::GET, path, headers).await
+    }
+
+    async fn request(
+        dir: &ServeDir,
+        method: Method,
+        path: &str,
+        headers: &[(&str, &str)],
+    ) -> Response {
+        let mut router = matchit::Router::new();
+        router.insert("/assets/*path", ()).unwrap();
+        router.insert("/assets/", ()).unwrap();
+        let matched = router.at(path).unwrap();
+        let params: RawPathParams = matched.params.into();
+        let head = request_head(method, path, headers);
+        serve_dir(dir, &head, &params).await.unwrap()
+    }
+
+    async fn body(response: Response) -> String {
+        let (_, body) = response.into_parts();
+        let bytes = body.collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    fn fixture() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("index.html"), "<h1>Home</h1>").unwrap();
+        std::fs::create_dir(root.path().join("css")).unwrap();
+        std::fs::write(root.path().join("css/main.css"), "body { color: red; }").unwrap();
+        std::fs::write(root.path().join("css/main.css.gz"), "gzipped").unwrap();
+        std::fs::create_dir(root.path().join("docs")).unwrap();
+        std::fs::write(root.path().join("docs/index.html"), "<h1>Docs</h1>").unwrap();
+        std::fs::write(root.path().join(".env"), "SECRET=1").unwrap();
+        root
+    }
+
+    #[tokio::test]
+    async fn files_are_served_with_metadata() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path());
+        let response = get(&dir, "/assets/css/main.css", &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/css; charset=utf-8");
+        assert!(response.headers().contains_key(ETAG));
+        assert!(response.headers().contains_key(LAST_MODIFIED));
+        assert_eq!(body(response).await, "body { color: red; }");
+
+        let response = request(&dir, Method::HEAD, "/assets/css/main.css", &[]).await;
+        assert_eq!(response.headers()["content-length"], "20");
+        assert_eq!(body(response).await, "");
+    }
+
+    #[tokio::test]
+    async fn traversal_and_hidden_files_are_rejected() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path().join("css"));
+        for path in ["/assets/../index.html", "/assets/%2e%2e/index.html"] {
+            let response = get(&dir, path, &[]).await;
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{path}");
+        }
+        let dir = ServeDir::new(root.path());
+        let response = get(&dir, "/assets/.env", &[]).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn conditional_requests() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path());
+        let response = get(&dir, "/assets/css/main.css", &[]).await;
+        let etag = response.headers()[ETAG].to_str().unwrap().to_owned();
+        let last_modified = response.headers()[LAST_MODIFIED]
+            .to_str()
+            .unwrap()
+            .to_owned();
+
+        let response = get(&dir, "/assets/css/main.css", &[("if-none-match", &etag)]).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(body(response).await, "");
+
+        let response = get(
+            &dir,
+            "/assets/css/main.css",
+            &[("if-modified-since", &last_modified)],
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+        let response = get(&dir, "/assets/css/main.css", &[("if-match", "\"other\"")]).await;
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[tokio::test]
+    async fn range_requests() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path());
+        let response = get(&dir, "/assets/css/main.css", &[("range", "bytes=0-3")]).await;
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.headers()[CONTENT_RANGE], "bytes 0-3/20");
+        assert_eq!(body(response).await, "body");
+
+        let response = get(&dir, "/assets/css/main.css", &[("range", "bytes=-3")]).await;
+        assert_eq!(body(response).await, "; }");
+
+        let response = get(&dir, "/assets/css/main.css", &[("range", "bytes=50-")]).await;
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(response.headers()[CONTENT_RANGE], "bytes */20");
+
+        // A stale `If-Range` validator: the whole file is sent.
+        let response = get(
+            &dir,
+            "/assets/css/main.css",
+            &[("range", "bytes=0-3"), ("if-range", "\"stale\"")],
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn precompressed_siblings() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path()).precompressed_gzip();
+        let response = get(
+            &dir,
+            "/assets/css/main.css",
+            &[("accept-encoding", "br, gzip")],
+        )
+        .await;
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/css; charset=utf-8");
+        assert_eq!(response.headers()["vary"], "accept-encoding");
+        assert_eq!(body(response).await, "gzipped");
+
+        let response = get(&dir, "/assets/css/main.css", &[("accept-encoding", "br")]).await;
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(body(response).await, "body { color: red; }");
+    }
+
+    #[tokio::test]
+    async fn directories_and_fallbacks() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path());
+        let response = get(&dir, "/assets/", &[]).await;
+        assert_eq!(body(response).await, "<h1>Home</h1>");
+
+        let response = get(&dir, "/assets/docs", &[]).await;
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(response.headers()[LOCATION], "./docs/");
+        let response = get(&dir, "/assets/docs/", &[]).await;
+        assert_eq!(body(response).await, "<h1>Docs</h1>");
+
+        let response = get(&dir, "/assets/app/settings", &[]).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let spa = ServeDir::new(root.path()).fallback("index.html");
+        let response = get(&spa, "/assets/app/settings", &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body(response).await, "<h1>Home</h1>");
+    }
+
+    #[tokio::test]
+    async fn unsupported_methods_are_rejected() {
+        let root = fixture();
+        let dir = ServeDir::new(root.path());
+        let response = request(&dir, Method::POST, "/assets/index.html", &[]).await;
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()["allow"], "GET, HEAD");
+    }
+}
@@ -2,6 +2,8 @@ use crate::blueprint::constructor::Constructor;
 use crate::blueprint::linter::Lint;
 use crate::blueprint::Blueprint;
 use crate::request::body::{BodySizeLimit, BufferedBody, JsonBody};
+use crate::request::host::HostParams;
+use crate::request::negotiation::Accept;
 use crate::request::path::PathParams;
 use crate::request::query::QueryParams;
 
@@ -15,6 +17,13 @@ use crate::request::query::QueryParams;
 /// section of Pavex's guide for a thorough introduction to kits and how to
 /// customize them.
 ///
+/// # Stability
+///
+/// [`ApiKit`] is marked as `#[non_exhaustive]`: new constructors may be added to the kit
+/// in future releases without it being a breaking change.
+/// You can only get an instance via [`ApiKit::new`]; set a field to `None` if you
+/// want to opt out of one of the bundled constructors.
+///
 /// # Example
 ///
 /// ```rust
@@ -37,6 +46,10 @@ pub struct ApiKit {
     pub buffered_body: Option<Constructor>,
     /// The [default constructor](BodySizeLimit::default_constructor) for [`BodySizeLimit`].
     pub body_size_limit: Option<Constructor>,
+    /// The [default constructor](Accept::default_constructor) for [`Accept`].
+    pub accept: Option<Constructor>,
+    /// The [default constructor](HostParams::default_constructor) for [`HostParams`].
+    pub host_params: Option<Constructor>,
 }
 
 impl ApiKit {
@@ -48,6 +61,8 @@ impl ApiKit {
             json_body: Some(JsonBody::default_constructor().ignore(Lint::Unused)),
             buffered_body: Some(BufferedBody::default_constructor().ignore(Lint::Unused)),
             body_size_limit: Some(BodySizeLimit::default_constructor().ignore(Lint::Unused)),
+            accept: Some(Accept::default_constructor().ignore(Lint::Unused)),
+            host_params: Some(HostParams::default_constructor().ignore(Lint::Unused)),
         }
     }
 
@@ -70,6 +85,12 @@ impl ApiKit {
         if let Some(body_size_limit) = self.body_size_limit {
             body_size_limit.register(bp);
         }
+        if let Some(accept) = self.accept {
+            accept.register(bp);
+        }
+        if let Some(host_params) = self.host_params {
+            host_params.register(bp);
+        }
         RegisteredApiKit {}
     }
 }
@@ -0,0 +1,102 @@
+use crate::blueprint::constructor::Constructor;
+use crate::blueprint::linter::Lint;
+use crate::blueprint::middleware::WrappingMiddleware;
+use crate::blueprint::Blueprint;
+use crate::cookie::{CookieConfig, RequestCookies, ResponseCookies};
+use crate::f;
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+/// A collection of first-party components to work with cookies.
+///
+/// # Guide
+///
+/// Check out the ["Kits"](https://pavex.dev/docs/guide/dependency_injection/core_concepts/kits)
+/// section of Pavex's guide for a thorough introduction to kits and how to
+/// customize them.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::blueprint::Blueprint;
+/// use pavex::kit::CookieKit;
+///  
+/// let mut bp = Blueprint::new();
+/// let kit = CookieKit::new().register(&mut bp);
+/// ```
+///
+/// # Middleware ordering
+///
+/// The [`cookie_middleware_with_config`](crate::cookie::cookie_middleware_with_config) only wraps the routes
+/// that are registered **after** the kit.
+/// Register the kit before your routes to make sure that all cookie changes
+/// are attached to the outgoing response.
+///
+/// # Signed and private cookies
+///
+/// [`SignedCookies`](crate::cookie::SignedCookies) and
+/// [`PrivateCookies`](crate::cookie::PrivateCookies) are not included:
+/// they require a [`CookieKeys`](crate::cookie::CookieKeys) singleton that
+/// must be provided by your application.
+pub struct CookieKit {
+    /// The [default constructor](RequestCookies::default_constructor)
+    /// for [`RequestCookies`].
+    pub request_cookies: Option<Constructor>,
+    /// The [default constructor](ResponseCookies::default_constructor)
+    /// for [`ResponseCookies`].
+    pub response_cookies: Option<Constructor>,
+    /// The [default constructor](CookieConfig::default_constructor)
+    /// for [`CookieConfig`].
+    ///
+    /// Set it to `None` if you want to load [`CookieConfig`] from your
+    /// application configuration.
+    pub cookie_config: Option<Constructor>,
+    /// A wrapping middleware that attaches the cookies in [`ResponseCookies`]
+    /// to the outgoing response, using the defaults in [`CookieConfig`].
+    /// See [`cookie_middleware_with_config`](crate::cookie::cookie_middleware_with_config).
+    pub cookie_middleware: Option<WrappingMiddleware>,
+}
+
+impl CookieKit {
+    /// Create a new [`CookieKit`] with all the bundled constructors and middlewares.
+    pub fn new() -> Self {
+        Self {
+            request_cookies: Some(RequestCookies::default_constructor().ignore(Lint::Unused)),
+            response_cookies: Some(ResponseCookies::default_constructor().ignore(Lint::Unused)),
+            cookie_config: Some(CookieConfig::default_constructor().ignore(Lint::Unused)),
+            cookie_middleware: Some(WrappingMiddleware::new(f!(
+                crate::cookie::cookie_middleware_with_config
+            ))),
+        }
+    }
+
+    /// Register all the bundled constructors and middlewares with a [`Blueprint`].
+    ///
+    /// Components that are set to `None` will not be registered.
+    pub fn register(self, bp: &mut Blueprint) -> RegisteredCookieKit {
+        if let Some(request_cookies) = self.request_cookies {
+            request_cookies.register(bp);
+        }
+        if let Some(response_cookies) = self.response_cookies {
+            response_cookies.register(bp);
+        }
+        if let Some(cookie_config) = self.cookie_config {
+            cookie_config.register(bp);
+        }
+        if let Some(cookie_middleware) = self.cookie_middleware {
+            cookie_middleware.register(bp);
+        }
+        RegisteredCookieKit {}
+    }
+}
+
+impl Default for CookieKit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+/// The type returned by [`CookieKit::register`].
+pub struct RegisteredCookieKit {}
@@ -2,5 +2,13 @@
 //!
 //! Check out the [`ApiKit`] documentation for more details.
 mod api;
+#[cfg(feature = "cookie")]
+mod cookie;
+#[cfg(feature = "session")]
+mod session;
 
 pub use api::ApiKit;
+#[cfg(feature = "cookie")]
+pub use cookie::CookieKit;
+#[cfg(feature = "session")]
+pub use session::SessionKit;
@@ -0,0 +1,98 @@
+use crate::blueprint::constructor::Constructor;
+use crate::blueprint::linter::Lint;
+use crate::blueprint::middleware::WrappingMiddleware;
+use crate::blueprint::Blueprint;
+use crate::f;
+use crate::session::{Session, SessionConfig, SharedSessionStore};
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+/// A collection of first-party components to work with server-side [`Session`]s.
+///
+/// # Guide
+///
+/// Check out the ["Kits"](https://pavex.dev/docs/guide/dependency_injection/core_concepts/kits)
+/// section of Pavex's guide for a thorough introduction to kits and how to
+/// customize them.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::blueprint::Blueprint;
+/// use pavex::kit::{CookieKit, SessionKit};
+///  
+/// let mut bp = Blueprint::new();
+/// CookieKit::new().register(&mut bp);
+/// SessionKit::new().register(&mut bp);
+/// ```
+///
+/// # Requirements
+///
+/// [`Session`] relies on the components registered by [`CookieKit`](super::CookieKit).
+/// Register [`SessionKit`] **after** [`CookieKit`](super::CookieKit), so that
+/// the session cookie set by [`session_middleware`](crate::session::session_middleware)
+/// is attached to the response by the cookie middleware.
+pub struct SessionKit {
+    /// The [default constructor](Session::default_constructor) for [`Session`].
+    pub session: Option<Constructor>,
+    /// The [default constructor](SessionConfig::default_constructor)
+    /// for [`SessionConfig`].
+    ///
+    /// Set it to `None` if you want to load [`SessionConfig`] from your
+    /// application configuration.
+    pub session_config: Option<Constructor>,
+    /// The [default constructor](SharedSessionStore::default_constructor)
+    /// for [`SharedSessionStore`].
+    ///
+    /// It's backed by an [`InMemorySessionStore`](crate::session::InMemorySessionStore):
+    /// replace it to keep sessions somewhere else.
+    pub session_store: Option<Constructor>,
+    /// A wrapping middleware that persists the session after the request handler
+    /// has returned, see [`session_middleware`](crate::session::session_middleware).
+    pub session_middleware: Option<WrappingMiddleware>,
+}
+
+impl SessionKit {
+    /// Create a new [`SessionKit`] with all the bundled constructors and middlewares.
+    pub fn new() -> Self {
+        Self {
+            session: Some(Session::default_constructor().ignore(Lint::Unused)),
+            session_config: Some(SessionConfig::default_constructor().ignore(Lint::Unused)),
+            session_store: Some(SharedSessionStore::default_constructor().ignore(Lint::Unused)),
+            session_middleware: Some(
+                WrappingMiddleware::new(f!(crate::session::session_middleware))
+                    .error_handler(f!(crate::session::SessionError::into_response)),
+            ),
+        }
+    }
+
+    /// Register all the bundled constructors and middlewares with a [`Blueprint`].
+    ///
+    /// Components that are set to `None` will not be registered.
+    pub fn register(self, bp: &mut Blueprint) -> RegisteredSessionKit {
+        if let Some(session) = self.session {
+            session.register(bp);
+        }
+        if let Some(session_config) = self.session_config {
+            session_config.register(bp);
+        }
+        if let Some(session_store) = self.session_store {
+            session_store.register(bp);
+        }
+        if let Some(session_middleware) = self.session_middleware {
+            session_middleware.register(bp);
+        }
+        RegisteredSessionKit {}
+    }
+}
+
+impl Default for SessionKit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+/// The type returned by [`SessionKit::register`].
+pub struct RegisteredSessionKit {}
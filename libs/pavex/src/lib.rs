@@ -13,16 +13,27 @@
 pub use error::Error;
 
 pub mod blueprint;
+#[cfg(any(feature = "gzip", feature = "br", feature = "zstd"))]
+pub mod compression;
+pub mod conditional;
+pub mod connection;
 #[cfg(feature = "cookie")]
 pub mod cookie;
+pub mod cors;
+#[cfg(feature = "csrf")]
+pub mod csrf;
 mod error;
+pub mod fs;
 pub mod http;
 pub mod kit;
 pub mod middleware;
+pub mod rate_limit;
 pub mod request;
 pub mod response;
 pub mod router;
 pub mod serialization;
 #[cfg(feature = "server")]
 pub mod server;
+#[cfg(feature = "session")]
+pub mod session;
 pub mod unit;
@@ -0,0 +1,105 @@
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::connection::ConnectionInfo;
+use crate::f;
+use crate::request::RequestHead;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The key used to group requests together for rate limiting purposes.
+///
+/// All requests with the same key share the same [`Quota`](super::Quota).
+///
+/// # Constructors
+///
+/// The [default constructor](Self::default_constructor) keys requests by the IP address
+/// of the client, as reported by [`ConnectionInfo`].
+/// If your application sits behind a reverse proxy, register
+/// [`RateLimitKey::forwarded_client_ip`] instead—or write your own constructor
+/// (e.g. to key requests by the authenticated user).
+pub struct RateLimitKey(String);
+
+impl RateLimitKey {
+    /// Create a new key from an arbitrary string.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    /// Key requests by the IP address of the peer that opened the connection.
+    pub fn client_ip(connection_info: &ConnectionInfo) -> Self {
+        Self(connection_info.peer_addr().ip().to_string())
+    }
+
+    /// Key requests by the IP address appended to the `X-Forwarded-For` header
+    /// by the closest reverse proxy—i.e. the rightmost entry.
+    ///
+    /// It falls back to the IP address of the peer if the header is missing or malformed.
+    ///
+    /// Only use this constructor if your application can't be reached without going
+    /// through your proxy: clients can set `X-Forwarded-For` to whatever they want.
+    pub fn forwarded_client_ip(
+        request_head: &RequestHead,
+        connection_info: &ConnectionInfo,
+    ) -> Self {
+        let forwarded = request_head
+            .headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .next_back()
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse::<std::net::IpAddr>().ok());
+        match forwarded {
+            Some(ip) => Self(ip.to_string()),
+            None => Self::client_ip(connection_info),
+        }
+    }
+
+    /// The key, as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`RateLimitKey`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::client_ip) for [`RateLimitKey`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(pavex::rate_limit::RateLimitKey::client_ip),
+            Lifecycle::RequestScoped,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use super::RateLimitKey;
+    use crate::connection::ConnectionInfo;
+    use crate::request::test_utils::request_head;
+
+    fn forwarded_client_ip(forwarded_for: &[&str]) -> RateLimitKey {
+        let info = ConnectionInfo::new("10.0.0.1:4000".parse().unwrap());
+        let headers: Vec<_> = forwarded_for
+            .iter()
+            .map(|value| ("x-forwarded-for", *value))
+            .collect();
+        RateLimitKey::forwarded_client_ip(&request_head(Method::GET, "/", &headers), &info)
+    }
+
+    #[test]
+    fn forwarded_client_ip_uses_the_rightmost_entry() {
+        let key = forwarded_client_ip(&["1.1.1.1, 2.2.2.2"]);
+        assert_eq!(key.as_str(), "2.2.2.2");
+        let key = forwarded_client_ip(&["1.1.1.1", "3.3.3.3"]);
+        assert_eq!(key.as_str(), "3.3.3.3");
+        let key = forwarded_client_ip(&["garbage"]);
+        assert_eq!(key.as_str(), "10.0.0.1");
+        let key = forwarded_client_ip(&[]);
+        assert_eq!(key.as_str(), "10.0.0.1");
+    }
+}
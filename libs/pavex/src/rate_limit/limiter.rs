@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use super::{
+    InMemoryRateLimitStore, Quota, RateLimitDecision, RateLimitStore, RateLimitStoreError,
+};
+
+#[derive(Clone)]
+/// Applies a [`Quota`] to incoming requests, storing counters in a [`RateLimitStore`].
+///
+/// It is consumed by [`rate_limit_middleware`](super::rate_limit_middleware).
+///
+/// # Singleton
+///
+/// There is no default constructor for [`RateLimiter`]: there is no sensible
+/// default [`Quota`].
+/// Register a singleton constructor for it with your [`Blueprint`].
+/// Cloning a [`RateLimiter`] is cheap: all clones share the same store.
+///
+/// [`Blueprint`]: crate::blueprint::Blueprint
+pub struct RateLimiter {
+    quota: Quota,
+    store: Arc<dyn RateLimitStore>,
+    namespace: Option<Arc<str>>,
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter enforcing `quota`, backed by an [`InMemoryRateLimitStore`].
+    pub fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            store: Arc::new(InMemoryRateLimitStore::new()),
+            namespace: None,
+        }
+    }
+
+    /// Store counters in `store` rather than in memory.
+    pub fn with_store<S: RateLimitStore>(self, store: S) -> Self {
+        self.with_shared_store(Arc::new(store))
+    }
+
+    /// Store counters in a store that's shared with other rate limiters.
+    ///
+    /// Use [`with_namespace`](Self::with_namespace) to keep their counters apart.
+    pub fn with_shared_store(mut self, store: Arc<dyn RateLimitStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Prefix all keys with `namespace`.
+    ///
+    /// Requests that are counted by a rate limiter don't affect the counters of rate
+    /// limiters with a different namespace, even if they share the same store.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// The quota enforced by this rate limiter.
+    pub fn quota(&self) -> &Quota {
+        &self.quota
+    }
+
+    /// Check whether a new request for `key` is allowed.
+    /// If it is, the request is counted against the quota.
+    pub async fn acquire(&self, key: &str) -> Result<RateLimitDecision, RateLimitStoreError> {
+        match &self.namespace {
+            Some(namespace) => {
+                let key = format!("{namespace}:{key}");
+                self.store.acquire(&key, &self.quota).await
+            }
+            None => self.store.acquire(key, &self.quota).await,
+        }
+    }
+}
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("quota", &self.quota)
+            .field("namespace", &self.namespace)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,154 @@
+use std::future::IntoFuture;
+use std::time::Duration;
+
+use http::header::RETRY_AFTER;
+use http::{HeaderName, HeaderValue, StatusCode};
+
+use crate::middleware::Next;
+use crate::response::Response;
+
+use super::{RateLimitDecision, RateLimitKey, RateLimiter};
+
+/// Enforce the quota of a [`RateLimiter`].
+///
+/// Requests that exceed the quota are rejected with a `429 Too Many Requests`,
+/// without invoking the rest of the request processing pipeline.
+/// The `Retry-After` header tells the client how many seconds it should wait
+/// before retrying.
+///
+/// All responses include the `RateLimit-Limit`, `RateLimit-Remaining` and
+/// `RateLimit-Reset` headers.
+///
+/// # Store failures
+///
+/// If the [`RateLimitStore`](super::RateLimitStore) fails, the error is logged and the
+/// request is let through: an unavailable store shouldn't take down your application.
+///
+/// Check out the [module-level documentation](super) for more details.
+pub async fn rate_limit_middleware<C>(
+    next: Next<C>,
+    limiter: &RateLimiter,
+    key: &RateLimitKey,
+) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    let decision = match limiter.acquire(key.as_str()).await {
+        Ok(decision) => decision,
+        Err(e) => {
+            tracing::warn!(
+                error.msg = %e,
+                error.details = ?e,
+                "Failed to check the rate limit for an incoming request, letting it through"
+            );
+            return next.await;
+        }
+    };
+    let response = match decision.retry_after {
+        Some(retry_after) => Response::new(StatusCode::TOO_MANY_REQUESTS)
+            .insert_header(RETRY_AFTER, ceil_secs(retry_after).into()),
+        None => next.await,
+    };
+    with_rate_limit_headers(response, &decision)
+}
+
+fn with_rate_limit_headers(response: Response, decision: &RateLimitDecision) -> Response {
+    response
+        .insert_header(
+            HeaderName::from_static("ratelimit-limit"),
+            HeaderValue::from(decision.limit),
+        )
+        .insert_header(
+            HeaderName::from_static("ratelimit-remaining"),
+            HeaderValue::from(decision.remaining),
+        )
+        .insert_header(
+            HeaderName::from_static("ratelimit-reset"),
+            ceil_secs(decision.reset_after).into(),
+        )
+}
+
+/// Round a duration up to the closest number of whole seconds.
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use futures_util::future::BoxFuture;
+    use http::StatusCode;
+
+    use super::rate_limit_middleware;
+    use crate::middleware::Next;
+    use crate::rate_limit::{
+        Quota, RateLimitDecision, RateLimitKey, RateLimitStore, RateLimitStoreError, RateLimiter,
+    };
+    use crate::response::Response;
+
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response.headers().get(name).map(|v| v.to_str().unwrap())
+    }
+
+    async fn run(limiter: &RateLimiter, key: &str) -> Response {
+        let next = Next::new(async { Response::ok() });
+        rate_limit_middleware(next, limiter, &RateLimitKey::new(key)).await
+    }
+
+    #[tokio::test]
+    async fn requests_over_quota_are_rejected() {
+        let limiter = RateLimiter::new(Quota::per_minute(NonZeroU32::new(2).unwrap()));
+
+        let response = run(&limiter, "a").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(header(&response, "ratelimit-limit"), Some("2"));
+        assert_eq!(header(&response, "ratelimit-remaining"), Some("1"));
+        assert_eq!(header(&response, "ratelimit-reset"), Some("30"));
+
+        assert_eq!(run(&limiter, "a").await.status(), StatusCode::OK);
+
+        let response = run(&limiter, "a").await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(header(&response, "ratelimit-remaining"), Some("0"));
+        let retry_after: u64 = header(&response, "retry-after").unwrap().parse().unwrap();
+        assert!(retry_after > 0 && retry_after <= 30);
+    }
+
+    #[tokio::test]
+    async fn namespaces_keep_counters_apart() {
+        let quota = Quota::per_minute(NonZeroU32::new(1).unwrap());
+        let store: std::sync::Arc<dyn RateLimitStore> =
+            std::sync::Arc::new(crate::rate_limit::InMemoryRateLimitStore::new());
+        let api = RateLimiter::new(quota)
+            .with_shared_store(store.clone())
+            .with_namespace("api");
+        let admin = RateLimiter::new(quota)
+            .with_shared_store(store)
+            .with_namespace("admin");
+        assert_eq!(run(&api, "a").await.status(), StatusCode::OK);
+        assert_eq!(run(&admin, "a").await.status(), StatusCode::OK);
+        assert_eq!(run(&api, "a").await.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    struct FailingStore;
+
+    impl RateLimitStore for FailingStore {
+        fn acquire<'a>(
+            &'a self,
+            _key: &'a str,
+            _quota: &'a Quota,
+        ) -> BoxFuture<'a, Result<RateLimitDecision, RateLimitStoreError>> {
+            Box::pin(async { Err(RateLimitStoreError::new(anyhow::anyhow!("unavailable"))) })
+        }
+    }
+
+    #[tokio::test]
+    async fn store_failures_fail_open() {
+        let limiter = RateLimiter::new(Quota::per_minute(NonZeroU32::new(1).unwrap()))
+            .with_store(FailingStore);
+        let response = run(&limiter, "a").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(header(&response, "ratelimit-limit"), None);
+    }
+}
@@ -0,0 +1,73 @@
+//! Rate limiting.
+//!
+//! # Overview
+//!
+//! [`rate_limit_middleware`] caps the number of requests that each client can perform
+//! in a given time window, according to a [`Quota`].
+//! Requests that exceed the quota are rejected with a `429 Too Many Requests`.
+//!
+//! Pavex uses the Generic Cell Rate Algorithm (GCRA), a variant of the token bucket
+//! algorithm that only needs to store a single timestamp per client.
+//!
+//! ```rust
+//! use pavex::blueprint::{constructor::Lifecycle, Blueprint};
+//! use pavex::f;
+//! use pavex::rate_limit::{Quota, RateLimitKey, RateLimiter};
+//! use std::num::NonZeroU32;
+//!
+//! pub fn rate_limiter() -> RateLimiter {
+//!     // 10 requests per second, with bursts of up to 50 requests.
+//!     let quota = Quota::per_second(NonZeroU32::new(10).unwrap())
+//!         .allow_burst(NonZeroU32::new(50).unwrap());
+//!     RateLimiter::new(quota)
+//! }
+//!
+//! let mut bp = Blueprint::new();
+//! bp.constructor(f!(crate::rate_limiter), Lifecycle::Singleton);
+//! RateLimitKey::register(&mut bp);
+//! bp.wrap(f!(pavex::rate_limit::rate_limit_middleware));
+//! // Register your routes here...
+//! ```
+//!
+//! # Keying
+//!
+//! Requests are grouped by their [`RateLimitKey`].
+//! The [default constructor](RateLimitKey::default_constructor) uses the IP address
+//! of the client.
+//! Register your own constructor to key requests differently—e.g. by the
+//! authenticated user:
+//!
+//! ```rust
+//! use pavex::rate_limit::RateLimitKey;
+//! # pub struct User { id: u64 }
+//!
+//! pub fn rate_limit_key(user: &User) -> RateLimitKey {
+//!     RateLimitKey::new(format!("user:{}", user.id))
+//! }
+//! ```
+//!
+//! # Scoping
+//!
+//! [`rate_limit_middleware`] can be registered against the top-level blueprint, to
+//! limit all incoming requests, or against a nested blueprint, to only limit the
+//! routes it contains.
+//! Use [`RateLimiter::with_namespace`] to keep the counters of different limiters apart
+//! when they share the same [`RateLimitStore`].
+//!
+//! # Storage
+//!
+//! By default, counters are kept in memory, shared across all worker threads
+//! (see [`InMemoryRateLimitStore`]).
+//! Implement [`RateLimitStore`] to share them across multiple instances of your
+//! application.
+pub use key::RateLimitKey;
+pub use limiter::RateLimiter;
+pub use middleware::rate_limit_middleware;
+pub use quota::{Quota, RateLimitDecision};
+pub use store::{InMemoryRateLimitStore, RateLimitStore, RateLimitStoreError};
+
+mod key;
+mod limiter;
+mod middleware;
+mod quota;
+mod store;
@@ -0,0 +1,189 @@
+use std::num::NonZeroU32;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How many requests a client is allowed to perform over time.
+///
+/// A quota is defined by:
+///
+/// - a **replenish interval**: the time it takes to regain the right to perform a request;
+/// - a **maximum burst**: how many requests can be performed back-to-back by a client
+///   that has been idle for long enough.
+pub struct Quota {
+    max_burst: NonZeroU32,
+    replenish_interval: Duration,
+}
+
+impl Quota {
+    /// Allow `n` requests per second, with bursts of up to `n` requests.
+    ///
+    /// The replenish interval can't be shorter than one nanosecond:
+    /// `n` is effectively capped at one billion.
+    pub fn per_second(n: NonZeroU32) -> Self {
+        Self::per_period(Duration::from_secs(1), n)
+    }
+
+    /// Allow `n` requests per minute, with bursts of up to `n` requests.
+    pub fn per_minute(n: NonZeroU32) -> Self {
+        Self::per_period(Duration::from_secs(60), n)
+    }
+
+    /// Allow `n` requests per hour, with bursts of up to `n` requests.
+    pub fn per_hour(n: NonZeroU32) -> Self {
+        Self::per_period(Duration::from_secs(60 * 60), n)
+    }
+
+    /// Allow one request every `replenish_interval`, with no bursts.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `replenish_interval` is zero.
+    pub fn with_replenish_interval(replenish_interval: Duration) -> Self {
+        assert!(
+            !replenish_interval.is_zero(),
+            "The replenish interval of a quota can't be zero"
+        );
+        Self {
+            max_burst: NonZeroU32::MIN,
+            replenish_interval,
+        }
+    }
+
+    /// Set the maximum number of requests that can be performed back-to-back.
+    pub fn allow_burst(mut self, max_burst: NonZeroU32) -> Self {
+        self.max_burst = max_burst;
+        self
+    }
+
+    /// The maximum number of requests that can be performed back-to-back.
+    pub fn max_burst(&self) -> NonZeroU32 {
+        self.max_burst
+    }
+
+    /// The time it takes to regain the right to perform a request.
+    pub fn replenish_interval(&self) -> Duration {
+        self.replenish_interval
+    }
+
+    fn per_period(period: Duration, n: NonZeroU32) -> Self {
+        Self {
+            max_burst: n,
+            // We can't go below the resolution of `Duration`: requesting more than
+            // one request per nanosecond is equivalent to not limiting at all.
+            replenish_interval: (period / n.get()).max(Duration::from_nanos(1)),
+        }
+    }
+
+    /// Run the GCRA algorithm for a new request arriving at `now`.
+    ///
+    /// `tat` is the "theoretical arrival time" stored for the client, if any.
+    /// It returns the decision and, if the request is allowed, the new theoretical
+    /// arrival time that must be stored for the client.
+    ///
+    /// This is the building block for [`RateLimitStore`](super::RateLimitStore)
+    /// implementations: the read-evaluate-write cycle must be performed atomically.
+    pub fn evaluate(
+        &self,
+        tat: Option<SystemTime>,
+        now: SystemTime,
+    ) -> (RateLimitDecision, Option<SystemTime>) {
+        let interval = self.replenish_interval;
+        let burst_window = interval * self.max_burst.get();
+        let tat = tat.filter(|tat| *tat > now).unwrap_or(now);
+        let new_tat = tat + interval;
+        let allow_at = new_tat.checked_sub(burst_window).unwrap_or(now);
+        let limit = self.max_burst.get();
+        if now < allow_at {
+            let decision = RateLimitDecision {
+                limit,
+                remaining: 0,
+                reset_after: tat.duration_since(now).unwrap_or_default(),
+                retry_after: Some(allow_at.duration_since(now).unwrap_or_default()),
+            };
+            return (decision, None);
+        }
+        let reset_after = new_tat.duration_since(now).unwrap_or_default();
+        let remaining =
+            (burst_window.saturating_sub(reset_after).as_nanos() / interval.as_nanos()) as u32;
+        let decision = RateLimitDecision {
+            limit,
+            remaining,
+            reset_after,
+            retry_after: None,
+        };
+        (decision, Some(new_tat))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The outcome of a rate limiting check.
+pub struct RateLimitDecision {
+    /// The maximum number of requests that can be performed back-to-back.
+    pub limit: u32,
+    /// How many more requests can be performed right now.
+    pub remaining: u32,
+    /// How long it takes for the quota to be fully replenished.
+    pub reset_after: Duration,
+    /// If the request was rejected, how long the client should wait before retrying.
+    /// `None` if the request was allowed.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitDecision {
+    /// Returns `true` if the request is allowed.
+    pub fn is_allowed(&self) -> bool {
+        self.retry_after.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Quota;
+    use std::num::NonZeroU32;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn bursts_are_allowed_up_to_the_limit() {
+        let quota =
+            Quota::per_second(NonZeroU32::new(2).unwrap()).allow_burst(NonZeroU32::new(3).unwrap());
+        let now = SystemTime::now();
+        let mut tat = None;
+        for expected_remaining in [2, 1, 0] {
+            let (decision, new_tat) = quota.evaluate(tat, now);
+            assert!(decision.is_allowed());
+            assert_eq!(decision.remaining, expected_remaining);
+            tat = new_tat;
+        }
+        let (decision, new_tat) = quota.evaluate(tat, now);
+        assert!(!decision.is_allowed());
+        assert_eq!(new_tat, None);
+        assert_eq!(decision.retry_after, Some(Duration::from_millis(500)));
+        assert_eq!(decision.reset_after, Duration::from_millis(1500));
+
+        // After one replenish interval, one more request goes through.
+        let later = now + Duration::from_millis(500);
+        let (decision, _) = quota.evaluate(tat, later);
+        assert!(decision.is_allowed());
+        assert_eq!(decision.remaining, 0);
+    }
+
+    #[test]
+    fn idle_clients_get_their_full_burst_back() {
+        let quota = Quota::with_replenish_interval(Duration::from_secs(10))
+            .allow_burst(NonZeroU32::new(5).unwrap());
+        let now = SystemTime::now();
+        let (_, tat) = quota.evaluate(None, now);
+        let (decision, _) = quota.evaluate(tat, now + Duration::from_secs(3600));
+        assert_eq!(decision.remaining, 4);
+        assert_eq!(decision.limit, 5);
+    }
+
+    #[test]
+    fn the_replenish_interval_is_never_zero() {
+        let quota = Quota::per_second(NonZeroU32::MAX);
+        assert_eq!(quota.replenish_interval(), Duration::from_nanos(1));
+        let (decision, tat) = quota.evaluate(None, SystemTime::now());
+        assert!(decision.is_allowed());
+        assert!(tat.is_some());
+    }
+}
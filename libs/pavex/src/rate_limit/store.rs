@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use futures_util::future::BoxFuture;
+
+use super::{Quota, RateLimitDecision};
+
+/// A storage backend for rate limiting counters.
+///
+/// Pavex provides an [`InMemoryRateLimitStore`] out of the box.
+/// Implement this trait to share counters across multiple instances of your
+/// application—e.g. using a key-value store.
+///
+/// # Implementation notes
+///
+/// For each key, a store must persist a single timestamp: the "theoretical arrival time"
+/// computed by [`Quota::evaluate`].
+/// Reading the timestamp, evaluating the quota and writing the new timestamp back must
+/// happen atomically: concurrent requests for the same key would otherwise be able to
+/// exceed the quota.
+pub trait RateLimitStore: Send + Sync + 'static {
+    /// Check whether a new request for `key` is allowed by `quota`.
+    /// If it is, the request is counted against the quota.
+    fn acquire<'a>(
+        &'a self,
+        key: &'a str,
+        quota: &'a Quota,
+    ) -> BoxFuture<'a, Result<RateLimitDecision, RateLimitStoreError>>;
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+/// The error returned by a [`RateLimitStore`] when an operation fails.
+pub struct RateLimitStoreError(anyhow::Error);
+
+impl RateLimitStoreError {
+    /// Wrap an arbitrary error returned by a storage backend.
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Into<anyhow::Error>,
+    {
+        Self(error.into())
+    }
+}
+
+/// A [`RateLimitStore`] that keeps counters in memory.
+///
+/// Counters are shared across all the worker threads of your application, but they
+/// are not shared across multiple instances of it and they are lost on restart.
+///
+/// Entries for clients whose quota has been fully replenished are purged periodically,
+/// to keep memory usage bounded.
+#[derive(Debug, Default)]
+pub struct InMemoryRateLimitStore {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    tats: HashMap<String, SystemTime>,
+    n_acquired_since_purge: usize,
+}
+
+/// How many calls to `acquire` should happen between two purges of stale entries.
+const PURGE_EVERY: usize = 1024;
+
+impl InMemoryRateLimitStore {
+    /// Create a new, empty, in-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check `key` against `quota` at time `now`.
+    fn acquire_at(&self, key: &str, quota: &Quota, now: SystemTime) -> RateLimitDecision {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.n_acquired_since_purge += 1;
+        if state.n_acquired_since_purge >= PURGE_EVERY {
+            state.n_acquired_since_purge = 0;
+            // A theoretical arrival time in the past is equivalent to no entry at all.
+            state.tats.retain(|_, tat| *tat > now);
+        }
+        let (decision, new_tat) = quota.evaluate(state.tats.get(key).copied(), now);
+        if let Some(new_tat) = new_tat {
+            state.tats.insert(key.to_owned(), new_tat);
+        }
+        decision
+    }
+}
+
+impl RateLimitStore for InMemoryRateLimitStore {
+    fn acquire<'a>(
+        &'a self,
+        key: &'a str,
+        quota: &'a Quota,
+    ) -> BoxFuture<'a, Result<RateLimitDecision, RateLimitStoreError>> {
+        Box::pin(async move { Ok(self.acquire_at(key, quota, SystemTime::now())) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryRateLimitStore;
+    use crate::rate_limit::Quota;
+    use std::num::NonZeroU32;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn keys_are_limited_independently() {
+        let store = InMemoryRateLimitStore::new();
+        let quota = Quota::per_minute(NonZeroU32::new(1).unwrap());
+        let now = SystemTime::now();
+        assert!(store.acquire_at("a", &quota, now).is_allowed());
+        assert!(!store.acquire_at("a", &quota, now).is_allowed());
+        assert!(store.acquire_at("b", &quota, now).is_allowed());
+    }
+
+    #[test]
+    fn stale_entries_are_purged() {
+        let store = InMemoryRateLimitStore::new();
+        let quota = Quota::per_second(NonZeroU32::new(1).unwrap());
+        let now = SystemTime::now();
+        for i in 0..super::PURGE_EVERY - 1 {
+            store.acquire_at(&i.to_string(), &quota, now);
+        }
+        store.acquire_at("last", &quota, now + Duration::from_secs(2));
+        let state = store.state.lock().unwrap();
+        assert_eq!(state.tats.len(), 1);
+        assert!(state.tats.contains_key("last"));
+    }
+}
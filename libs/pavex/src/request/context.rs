@@ -0,0 +1,192 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::rc::Rc;
+
+/// A type map to share request-scoped data between components that don't
+/// know about each other.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::middleware::Next;
+/// use pavex::request::RequestContext;
+/// use pavex::response::Response;
+/// use std::future::IntoFuture;
+///
+/// #[derive(Clone)]
+/// pub struct TraceId(String);
+///
+/// /// A wrapping middleware that makes the trace id available
+/// /// to all downstream components.
+/// pub async fn trace_id<C>(next: Next<C>, context: &RequestContext) -> Response
+/// where
+///     C: IntoFuture<Output = Response>,
+/// {
+///     context.insert(TraceId("0af7651916cd43dd8448eb211c80319c".into()));
+///     next.await
+/// }
+///
+/// /// A request handler that reads the trace id set by the middleware.
+/// pub fn handler(context: &RequestContext) -> Response {
+///     match context.get::<TraceId>() {
+///         Some(trace_id) => Response::ok().set_typed_body(trace_id.0),
+///         None => Response::internal_server_error(),
+///     }
+/// }
+/// ```
+///
+/// # Framework primitive
+///
+/// `RequestContext` is a framework primitive—you don't need to register any constructor
+/// with [`Blueprint`] to use it in your application.
+/// A new, empty, context is created for every incoming request and shared by
+/// all the components involved in processing it.
+///
+/// # Use cases
+///
+/// Prefer constructors whenever possible: they are type-checked by Pavex when
+/// generating the code for your application, while the values stored in a
+/// `RequestContext` can only be retrieved at runtime.
+/// `RequestContext` is a good fit when integrating with third-party code that
+/// expects to attach arbitrary data to the request (e.g. `http::Extensions` in
+/// `tower`-based middlewares) or when a wrapping middleware needs to hand
+/// over data to downstream components without owning its construction.
+///
+/// # Cloning
+///
+/// `RequestContext` is cheap to clone: all clones point to the same underlying
+/// type map. Values inserted via a clone are visible to all other clones.
+///
+/// [`Blueprint`]: crate::blueprint::Blueprint
+#[derive(Clone, Default)]
+#[doc(alias("Extensions"))]
+#[doc(alias("TypeMap"))]
+pub struct RequestContext {
+    map: Rc<RefCell<HashMap<TypeId, Box<dyn Any>>>>,
+}
+
+impl RequestContext {
+    /// Create a new, empty, request context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a value into the context.
+    ///
+    /// If a value of the same type was already present, it is replaced and returned.
+    ///
+    /// # Panics
+    ///
+    /// It panics if called from within the closure passed to [`with`](Self::with).
+    pub fn insert<T: 'static>(&self, value: T) -> Option<T> {
+        self.map
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|previous| previous.downcast().ok())
+            .map(|previous| *previous)
+    }
+
+    /// Get a copy of the value of type `T` stored in the context, if there is one.
+    ///
+    /// Use [`with`](Self::with) if `T` doesn't implement [`Clone`] or if cloning it
+    /// is expensive.
+    pub fn get<T: Clone + 'static>(&self) -> Option<T> {
+        self.with(T::clone)
+    }
+
+    /// Invoke `f` with a reference to the value of type `T` stored in the context,
+    /// if there is one, and return its output.
+    ///
+    /// # Panics
+    ///
+    /// The context can't be modified while `f` is running: calling [`insert`](Self::insert)
+    /// or [`remove`](Self::remove) from within `f` panics.
+    pub fn with<T: 'static, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let map = self.map.borrow();
+        map.get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+            .map(f)
+    }
+
+    /// Returns `true` if the context contains a value of type `T`.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.map.borrow().contains_key(&TypeId::of::<T>())
+    }
+
+    /// Remove the value of type `T` from the context, returning it if it was present.
+    ///
+    /// # Panics
+    ///
+    /// It panics if called from within the closure passed to [`with`](Self::with).
+    pub fn remove<T: 'static>(&self) -> Option<T> {
+        self.map
+            .borrow_mut()
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+
+    /// The number of values stored in the context.
+    pub fn len(&self) -> usize {
+        self.map.borrow().len()
+    }
+
+    /// Returns `true` if the context doesn't contain any value.
+    pub fn is_empty(&self) -> bool {
+        self.map.borrow().is_empty()
+    }
+}
+
+impl std::fmt::Debug for RequestContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestContext")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestContext;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct UserId(u64);
+
+    #[test]
+    fn insert_get_and_remove() {
+        let context = RequestContext::new();
+        assert!(context.is_empty());
+        assert!(context.get::<UserId>().is_none());
+
+        assert_eq!(context.insert(UserId(1)), None);
+        assert_eq!(context.insert(UserId(2)), Some(UserId(1)));
+        assert_eq!(context.insert("a string"), None);
+        assert_eq!(context.len(), 2);
+        assert_eq!(context.get::<UserId>(), Some(UserId(2)));
+        assert_eq!(context.with(|s: &&str| s.len()), Some(8));
+
+        assert_eq!(context.remove::<UserId>(), Some(UserId(2)));
+        assert!(!context.contains::<UserId>());
+        assert!(context.contains::<&str>());
+    }
+
+    #[test]
+    fn clones_share_the_same_map() {
+        let context = RequestContext::new();
+        let clone = context.clone();
+        clone.insert(UserId(7));
+        assert_eq!(context.get::<UserId>(), Some(UserId(7)));
+    }
+
+    #[test]
+    fn values_can_be_read_while_the_context_is_modified() {
+        let context = RequestContext::new();
+        context.insert(UserId(1));
+        if let Some(UserId(id)) = context.get::<UserId>() {
+            context.insert(UserId(id + 1));
+        }
+        assert_eq!(context.get::<UserId>(), Some(UserId(2)));
+    }
+}
@@ -0,0 +1,135 @@
+//! Extract data from the domain targeted by an incoming request.
+//!
+//! Check out [`Blueprint::nest_at_domain`] to learn how to route requests based on their domain.
+//!
+//! [`Blueprint::nest_at_domain`]: crate::blueprint::Blueprint::nest_at_domain
+use serde::Deserialize;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+use crate::request::path::errors::ExtractPathParamsError;
+use crate::request::path::path_params::deserialize_params;
+use crate::request::path::RawPathParams;
+use crate::router::host::HOST_PARAMETER_PREFIX;
+
+/// Extract (typed) domain captures from the domain targeted by an incoming request.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::f;
+/// use pavex::blueprint::{router::GET, Blueprint};
+/// use pavex::request::host::HostParams;
+///
+/// fn blueprint() -> Blueprint {
+///     let mut bp = Blueprint::new();
+///     // Register the default constructor and error handler for `HostParams`.
+///     HostParams::register(&mut bp);
+///     bp.nest_at_domain(":tenant.example.com", tenant_bp());
+///     bp
+/// }
+///
+/// fn tenant_bp() -> Blueprint {
+///     let mut bp = Blueprint::new();
+///     bp.route(GET, "/users", f!(crate::list_users));
+///     bp
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Tenant {
+///     // The name of the field must match the name of the capture
+///     // used in `bp.nest_at_domain`.
+///     tenant: String,
+/// }
+///
+/// pub fn list_users(params: &HostParams<Tenant>) -> String {
+///     format!("Listing the users of {}", params.0.tenant)
+/// }
+/// ```
+///
+/// `tenant` will be set to `acme` for an incoming request to `acme.example.com/users`.
+///
+/// # Relationship with path parameters
+///
+/// Domain captures are not visible to [`PathParams`], and vice versa.
+/// They are included, instead, in [`RawPathParams`], with a `host.` prefix in front of their
+/// name—e.g. `host.tenant` for `:tenant`.
+///
+/// [`PathParams`]: struct@crate::request::path::PathParams
+pub struct HostParams<T>(
+    /// The extracted domain captures, deserialized into `T`, the type you specified.
+    pub T,
+);
+
+impl<T> HostParams<T> {
+    /// The default constructor for [`HostParams`].
+    ///
+    /// If the extraction fails, an [`ExtractPathParamsError`] is returned.
+    pub fn extract<'server, 'request>(
+        params: RawPathParams<'server, 'request>,
+    ) -> Result<Self, ExtractPathParamsError>
+    where
+        T: Deserialize<'request>,
+        'server: 'request,
+    {
+        let params = params
+            .iter()
+            .filter_map(|(id, value)| Some((id.strip_prefix(HOST_PARAMETER_PREFIX)?, value)));
+        deserialize_params(params).map(HostParams)
+    }
+}
+
+impl HostParams<()> {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`HostParams`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](HostParams::extract)
+    /// and [error handler](ExtractPathParamsError::into_response)
+    /// for [`HostParams`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(pavex::request::host::HostParams::extract),
+            Lifecycle::RequestScoped,
+        )
+        .error_handler(f!(
+            pavex::request::path::errors::ExtractPathParamsError::into_response
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HostParams;
+    use crate::request::path::PathParams;
+
+    #[derive(serde::Deserialize)]
+    struct Tenant {
+        tenant: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct User {
+        id: u64,
+    }
+
+    #[test]
+    fn host_and_path_parameters_are_kept_apart() {
+        let mut router = matchit::Router::new();
+        router
+            .insert("~com/example/:host.tenant/@/users/:id", ())
+            .unwrap();
+        let matched = router.at("~com/example/acme/@/users/42").unwrap();
+
+        let HostParams(host) =
+            HostParams::<Tenant>::extract(matched.params.clone().into()).unwrap();
+        assert_eq!(host.tenant, "acme");
+
+        let PathParams(path) = PathParams::<User>::extract(matched.params.into()).unwrap();
+        assert_eq!(path.id, 42);
+    }
+}
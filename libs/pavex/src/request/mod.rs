@@ -1,7 +1,13 @@
 //! Process and extract data from incoming HTTP requests.
+pub use context::RequestContext;
 pub use request_head::RequestHead;
 
 pub mod body;
+mod context;
+pub mod host;
+pub mod negotiation;
 pub mod path;
 pub mod query;
 mod request_head;
+#[cfg(test)]
+pub(crate) mod test_utils;
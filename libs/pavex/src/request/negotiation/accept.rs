@@ -0,0 +1,273 @@
+use http::header::ACCEPT;
+use mime::Mime;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+use crate::request::RequestHead;
+
+use super::errors::NotAcceptable;
+
+/// The media types that the client is willing to receive, as specified
+/// by the `Accept` header of the incoming request.
+///
+/// Use [`Accept::negotiate`] to pick, among the media types supported by your handler,
+/// the one that best matches the preferences of the client.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::request::negotiation::{errors::NotAcceptable, Accept};
+/// use pavex::response::{body::Json, Response};
+///
+/// pub fn get_home(accept: &Accept) -> Result<Response, NotAcceptable> {
+///     let response = match accept.negotiate(&["application/json", "text/plain"])? {
+///         "application/json" => {
+///             Response::ok().set_typed_body(Json::new("Home, sweet home").unwrap())
+///         }
+///         _ => Response::ok().set_typed_body("Home, sweet home"),
+///     };
+///     Ok(response)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Accept {
+    ranges: Vec<MediaRange>,
+}
+
+#[derive(Debug, Clone)]
+struct MediaRange {
+    mime: Mime,
+    quality: f32,
+}
+
+impl Accept {
+    /// The default constructor for [`Accept`].
+    ///
+    /// It parses all the `Accept` headers attached to the incoming request.  
+    /// Invalid media ranges are silently skipped.
+    pub fn extract(request_head: &RequestHead) -> Self {
+        let ranges = request_head
+            .headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(MediaRange::parse)
+            .collect();
+        Self { ranges }
+    }
+
+    /// Returns `true` if the request didn't specify any (valid) media range.
+    ///
+    /// In that case, the client is assumed to accept any media type.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns `true` if the client is willing to receive a response with the
+    /// given media type.
+    pub fn accepts(&self, media_type: &str) -> bool {
+        self.quality(media_type) > 0.0
+    }
+
+    /// Pick the media type that best matches the preferences of the client.
+    ///
+    /// The media types in `supported` are ranked according to the quality value (`q`)
+    /// assigned to them by the most specific matching media range in the `Accept` header.  
+    /// If multiple media types have the same quality value, the one that appears
+    /// first in `supported` wins: list them in order of preference.
+    ///
+    /// If the request doesn't specify an `Accept` header, the first supported media type
+    /// is returned.
+    ///
+    /// A [`NotAcceptable`] error is returned if none of the supported media types
+    /// is acceptable for the client.
+    pub fn negotiate<'a>(&self, supported: &[&'a str]) -> Result<&'a str, NotAcceptable> {
+        let mut best: Option<(&'a str, f32)> = None;
+        for &candidate in supported {
+            let quality = self.quality(candidate);
+            if quality <= 0.0 {
+                continue;
+            }
+            if let Some((_, best_quality)) = best {
+                if quality <= best_quality {
+                    continue;
+                }
+            }
+            best = Some((candidate, quality));
+        }
+        best.map(|(media_type, _)| media_type)
+            .ok_or_else(|| NotAcceptable {
+                supported: supported.iter().map(|s| s.to_string()).collect(),
+            })
+    }
+
+    /// The quality value assigned by the client to the given media type.
+    ///
+    /// It's `0` if the media type is invalid or not acceptable.
+    fn quality(&self, media_type: &str) -> f32 {
+        let Ok(media_type) = media_type.parse::<Mime>() else {
+            return 0.0;
+        };
+        if self.ranges.is_empty() {
+            return 1.0;
+        }
+        let mut best_match: Option<(u8, f32)> = None;
+        for range in &self.ranges {
+            let Some(specificity) = range.specificity(&media_type) else {
+                continue;
+            };
+            if let Some((best_specificity, _)) = best_match {
+                if specificity <= best_specificity {
+                    continue;
+                }
+            }
+            best_match = Some((specificity, range.quality));
+        }
+        best_match.map_or(0.0, |(_, quality)| quality)
+    }
+}
+
+impl MediaRange {
+    fn parse(s: &str) -> Option<Self> {
+        let mime = s.trim().parse::<Mime>().ok()?;
+        let quality = match mime.get_param("q") {
+            None => 1.0,
+            Some(q) => {
+                let q = q.as_str().parse::<f32>().ok()?;
+                if !(0.0..=1.0).contains(&q) {
+                    return None;
+                }
+                q
+            }
+        };
+        Some(Self { mime, quality })
+    }
+
+    /// Returns `None` if the range doesn't match the given media type.  
+    /// Otherwise, it returns a score that is higher the more specific the range is.
+    fn specificity(&self, media_type: &Mime) -> Option<u8> {
+        if self.mime.type_() == mime::STAR {
+            return Some(0);
+        }
+        if self.mime.type_() != media_type.type_() {
+            return None;
+        }
+        if self.mime.subtype() == mime::STAR {
+            return Some(1);
+        }
+        if self.mime.subtype() != media_type.subtype() || self.mime.suffix() != media_type.suffix()
+        {
+            return None;
+        }
+        let mut has_params = false;
+        for (name, value) in self.mime.params() {
+            if name == "q" {
+                continue;
+            }
+            has_params = true;
+            if media_type.get_param(name) != Some(value) {
+                return None;
+            }
+        }
+        Some(if has_params { 3 } else { 2 })
+    }
+}
+
+impl Accept {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`Accept`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Accept::extract) for [`Accept`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(pavex::request::negotiation::Accept::extract),
+            Lifecycle::RequestScoped,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Accept;
+    use crate::http::header::ACCEPT;
+
+    fn accept(values: &[&str]) -> Accept {
+        let mut builder = http::Request::builder();
+        for value in values {
+            builder = builder.header(ACCEPT, *value);
+        }
+        let (parts, _) = builder.body(()).unwrap().into_parts();
+        Accept::extract(&parts.into())
+    }
+
+    #[test]
+    fn missing_header_picks_the_first_supported_type() {
+        let accept = accept(&[]);
+        assert!(accept.is_empty());
+        let chosen = accept
+            .negotiate(&["text/html", "application/json"])
+            .unwrap();
+        assert_eq!(chosen, "text/html");
+    }
+
+    #[test]
+    fn quality_values_are_honored() {
+        let accept = accept(&["text/html;q=0.5, application/json"]);
+        let chosen = accept
+            .negotiate(&["text/html", "application/json"])
+            .unwrap();
+        assert_eq!(chosen, "application/json");
+    }
+
+    #[test]
+    fn most_specific_range_wins() {
+        let accept = accept(&["text/*;q=0.9", "text/plain;q=0.1", "*/*;q=0.2"]);
+        let chosen = accept
+            .negotiate(&["text/plain", "text/html", "application/json"])
+            .unwrap();
+        assert_eq!(chosen, "text/html");
+        assert!(accept.accepts("image/png"));
+    }
+
+    #[test]
+    fn server_order_breaks_ties() {
+        let accept = accept(&["application/json, text/html"]);
+        let chosen = accept
+            .negotiate(&["text/html", "application/json"])
+            .unwrap();
+        assert_eq!(chosen, "text/html");
+    }
+
+    #[test]
+    fn parameters_of_supported_types_do_not_prevent_a_match() {
+        let accept = accept(&["text/html"]);
+        let chosen = accept.negotiate(&["text/html; charset=utf-8"]).unwrap();
+        assert_eq!(chosen, "text/html; charset=utf-8");
+    }
+
+    #[test]
+    fn zero_quality_means_not_acceptable() {
+        let accept = accept(&["*/*, application/json;q=0"]);
+        assert!(!accept.accepts("application/json"));
+        let err = accept.negotiate(&["application/json"]).unwrap_err();
+        insta::assert_display_snapshot!(err, @"None of the media types supported by this endpoint is acceptable according to the `Accept` header. Supported media types: application/json");
+        assert_eq!(
+            err.into_response().status(),
+            http::StatusCode::NOT_ACCEPTABLE
+        );
+    }
+
+    #[test]
+    fn invalid_ranges_are_skipped() {
+        let accept = accept(&["hello world, text/plain;q=2, application/json;q=0.3"]);
+        let chosen = accept
+            .negotiate(&["text/plain", "application/json"])
+            .unwrap();
+        assert_eq!(chosen, "application/json");
+    }
+}
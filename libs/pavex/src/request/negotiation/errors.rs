@@ -0,0 +1,30 @@
+//! Errors that can happen when negotiating the representation of a resource.
+
+use crate::response::Response;
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "None of the media types supported by this endpoint is acceptable according to the `Accept` header. Supported media types: {}",
+    supported.join(", ")
+)]
+#[non_exhaustive]
+/// The error returned by [`Accept::negotiate`] when none of the supported media types
+/// is acceptable for the client.
+///
+/// Pavex provides [`NotAcceptable::into_response`] as the default error handler for
+/// this failure.
+///
+/// [`Accept::negotiate`]: crate::request::negotiation::Accept::negotiate
+pub struct NotAcceptable {
+    /// The media types supported by the endpoint.
+    pub supported: Vec<String>,
+}
+
+impl NotAcceptable {
+    /// Convert a [`NotAcceptable`] error into an HTTP response.
+    ///
+    /// It returns a `406 Not Acceptable` to the caller.
+    pub fn into_response(&self) -> Response {
+        Response::not_acceptable().set_typed_body(format!("{}", self))
+    }
+}
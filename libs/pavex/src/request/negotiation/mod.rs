@@ -0,0 +1,45 @@
+//! Pick the representation of a resource that best matches the preferences of the client.
+//!
+//! # Overview
+//!
+//! Clients specify the media types they are willing to receive using the
+//! [`Accept` header](https://www.rfc-editor.org/rfc/rfc9110#name-accept).
+//! The [`Accept`] extractor parses that header, while [`Accept::negotiate`] selects
+//! the best match among the media types supported by your handler.
+//!
+//! If none of the supported media types is acceptable for the client,
+//! a [`NotAcceptable`](errors::NotAcceptable) error is returned. Pavex provides
+//! [`NotAcceptable::into_response`](errors::NotAcceptable::into_response)
+//! as an error handler for it, returning a `406 Not Acceptable` to the caller.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pavex::http::header::ACCEPT;
+//! use pavex::request::negotiation::{errors::NotAcceptable, Accept};
+//! use pavex::response::{body::{Html, Json}, Response};
+//!
+//! #[derive(serde::Serialize)]
+//! pub struct Home {
+//!     address: String,
+//! }
+//!
+//! pub fn get_home(accept: &Accept) -> Result<Response, NotAcceptable> {
+//!     let home = Home { address: "221B Baker Street".into() };
+//!     let response = match accept.negotiate(&["application/json", "text/html"])? {
+//!         "application/json" => {
+//!             Response::ok().set_typed_body(Json::new(home).unwrap())
+//!         }
+//!         _ => {
+//!             let html: Html = format!("<p>{}</p>", home.address).into();
+//!             Response::ok().set_typed_body(html)
+//!         }
+//!     };
+//!     // The response depends on the `Accept` header: caches must take it into account.
+//!     Ok(response.append_vary(ACCEPT))
+//! }
+//! ```
+pub use accept::Accept;
+
+mod accept;
+pub mod errors;
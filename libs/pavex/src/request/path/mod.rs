@@ -41,6 +41,34 @@
 //!
 //! Check out [`PathParams`]' documentation for more details.
 //!
+//! # Parameter constraints
+//!
+//! You can restrict the values accepted by a path parameter by appending a constraint,
+//! in angle brackets, to its name:
+//!
+//! - a primitive type (e.g. `:id<u64>`): the value must parse as that type;
+//! - a regular expression (e.g. `:slug<[a-z0-9-]+>`): the value must match the expression,
+//!   in its entirety.
+//!
+//! ```rust
+//! use pavex::f;
+//! use pavex::blueprint::{router::GET, Blueprint};
+//!
+//! # fn blueprint() -> Blueprint {
+//! let mut bp = Blueprint::new();
+//! bp.route(GET, "/users/:id<u64>", f!(crate::get_user));
+//! bp.route(GET, "/posts/:slug<[a-z0-9-]+>", f!(crate::get_post));
+//! # bp
+//! # }
+//! ```
+//!
+//! Constraints are checked after a route has been matched: if a value doesn't satisfy its
+//! constraint, the request is handed over to the fallback that applies to the route
+//! (`404 Not Found` by default). Other routes that may match the same path are not tried.
+//! Pavex checks at compile-time that type constraints agree with the types of the
+//! corresponding fields in your [`PathParams`] struct.
+//! All routes registered for the same path must use the same constraints.
+//!
 //! [`PathParams`]: struct@PathParams
 
 pub use matched_path::MatchedPathPattern;
@@ -88,5 +116,5 @@ mod deserializer;
 pub mod errors;
 
 mod matched_path;
-mod path_params;
+pub(crate) mod path_params;
 mod raw_path_params;
@@ -6,7 +6,9 @@ use crate::f;
 use crate::request::path::deserializer::PathDeserializer;
 use crate::request::path::errors::{DecodeError, ExtractPathParamsError, InvalidUtf8InPathParam};
 
-use super::RawPathParams;
+use crate::router::host::HOST_PARAMETER_PREFIX;
+
+use super::{EncodedParamValue, RawPathParams};
 
 /// Extract (typed) path parameters from the path of an incoming request.
 ///
@@ -69,26 +71,39 @@ impl<T> PathParams<T> {
         // of an incoming request. So it's always true that 'key outlives 'value.
         'server: 'request,
     {
-        let mut decoded_params = Vec::with_capacity(params.len());
-        for (id, value) in params.iter() {
-            let decoded_value = value.decode().map_err(|e| {
-                let DecodeError {
-                    invalid_raw_segment,
-                    source,
-                } = e;
-                ExtractPathParamsError::InvalidUtf8InPathParameter(InvalidUtf8InPathParam {
-                    invalid_key: id.into(),
-                    invalid_raw_segment,
-                    source,
-                })
-            })?;
-            decoded_params.push((id, decoded_value));
-        }
-        let deserializer = PathDeserializer::new(&decoded_params);
-        T::deserialize(deserializer)
-            .map_err(ExtractPathParamsError::PathDeserializationError)
-            .map(PathParams)
+        // Domain captures are extracted via `HostParams`.
+        let params = params
+            .iter()
+            .filter(|(id, _)| !id.starts_with(HOST_PARAMETER_PREFIX));
+        deserialize_params(params).map(PathParams)
+    }
+}
+
+/// Percent-decode the values of the given parameters and deserialize them into `T`.
+pub(crate) fn deserialize_params<'server, 'request, T>(
+    params: impl Iterator<Item = (&'server str, EncodedParamValue<'request>)>,
+) -> Result<T, ExtractPathParamsError>
+where
+    T: Deserialize<'request>,
+    'server: 'request,
+{
+    let mut decoded_params = Vec::new();
+    for (id, value) in params {
+        let decoded_value = value.decode().map_err(|e| {
+            let DecodeError {
+                invalid_raw_segment,
+                source,
+            } = e;
+            ExtractPathParamsError::InvalidUtf8InPathParameter(InvalidUtf8InPathParam {
+                invalid_key: id.into(),
+                invalid_raw_segment,
+                source,
+            })
+        })?;
+        decoded_params.push((id, decoded_value));
     }
+    let deserializer = PathDeserializer::new(&decoded_params);
+    T::deserialize(deserializer).map_err(ExtractPathParamsError::PathDeserializationError)
 }
 
 impl PathParams<()> {
@@ -0,0 +1,502 @@
+//! A deserializer for `application/x-www-form-urlencoded` query strings that supports
+//! bracket-style nested keys (e.g. `filter[status]=open`).
+//!
+//! The query string is first parsed into a tree of [`Node`]s, grouping all values
+//! that share the same key. The tree is then handed over to `serde`.
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use serde::de::{
+    self, value::MapDeserializer, value::SeqDeserializer, DeserializeSeed, EnumAccess,
+    IntoDeserializer, VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+/// The error returned by [`QueryDeserializer`] when deserialization fails.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub(super) struct DeError(String);
+
+impl de::Error for DeError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        DeError(msg.to_string())
+    }
+}
+
+#[derive(Debug)]
+enum Node<'de> {
+    /// All the values associated with a key, in the order they appear in the query string.
+    Values(Vec<Cow<'de, str>>),
+    /// A nested structure, e.g. `filter[status]=open&filter[owner]=me`.
+    Map(IndexMap<Cow<'de, str>, Node<'de>>),
+}
+
+pub(super) struct QueryDeserializer<'de> {
+    query: &'de str,
+}
+
+impl<'de> QueryDeserializer<'de> {
+    pub(super) fn new(query: &'de str) -> Self {
+        Self { query }
+    }
+
+    /// Parse the (raw) query string into a tree of nested keys.
+    fn parse(self) -> Result<IndexMap<Cow<'de, str>, Node<'de>>, DeError> {
+        let mut root = IndexMap::new();
+        for (key, value) in form_urlencoded::parse(self.query.as_bytes()) {
+            let segments = split_key(key.clone());
+            insert(&mut root, segments, value).map_err(|_| {
+                de::Error::custom(format!(
+                    "`{key}` is used both as a single value and as the parent of nested keys"
+                ))
+            })?;
+        }
+        Ok(root)
+    }
+}
+
+/// Split a key into its segments: `filter[status][eq]` becomes `["filter", "status", "eq"]`.
+///
+/// A trailing `[]` is kept as part of the last segment, to preserve compatibility with
+/// fields that explicitly rename themselves to `name[]` to capture sequences.
+/// Keys with unbalanced brackets are treated as a single segment.
+fn split_key(key: Cow<'_, str>) -> Vec<Cow<'_, str>> {
+    let start = match key.find('[') {
+        Some(start) if start > 0 => start,
+        _ => return vec![key],
+    };
+    let mut segments = vec![key[..start].to_owned()];
+    let mut rest = &key[start..];
+    while !rest.is_empty() {
+        if rest == "[]" {
+            segments.last_mut().unwrap().push_str("[]");
+            break;
+        }
+        let Some(end) = rest.find(']') else {
+            return vec![key];
+        };
+        let segment = &rest[1..end];
+        if !rest.starts_with('[') || segment.is_empty() || segment.contains('[') {
+            return vec![key];
+        }
+        segments.push(segment.to_owned());
+        rest = &rest[end + 1..];
+    }
+    segments.into_iter().map(Cow::Owned).collect()
+}
+
+/// Insert a value in the tree, following the given key segments.
+///
+/// It fails if a key is used both for a single value and as the parent of nested keys.
+fn insert<'de>(
+    map: &mut IndexMap<Cow<'de, str>, Node<'de>>,
+    mut segments: Vec<Cow<'de, str>>,
+    value: Cow<'de, str>,
+) -> Result<(), ()> {
+    let head = segments.remove(0);
+    if segments.is_empty() {
+        return match map.entry(head).or_insert_with(|| Node::Values(Vec::new())) {
+            Node::Values(values) => {
+                values.push(value);
+                Ok(())
+            }
+            Node::Map(_) => Err(()),
+        };
+    }
+    match map
+        .entry(head)
+        .or_insert_with(|| Node::Map(IndexMap::new()))
+    {
+        Node::Map(nested) => insert(nested, segments, value),
+        Node::Values(_) => Err(()),
+    }
+}
+
+impl<'de> de::Deserializer<'de> for QueryDeserializer<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Node::Map(self.parse()?).deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for Node<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> Node<'de> {
+    /// Return the single value associated with this node, or an error if there
+    /// are none or more than one.
+    fn into_single_value(self) -> Result<Value<'de>, DeError> {
+        match self {
+            Node::Values(mut values) => match values.len() {
+                1 => Ok(Value(values.pop().unwrap())),
+                n => Err(de::Error::custom(format!(
+                    "expected a single value, but the key was repeated {n} times in the query string"
+                ))),
+            },
+            Node::Map(_) => Err(de::Error::custom(
+                "expected a single value, found nested keys",
+            )),
+        }
+    }
+}
+
+macro_rules! forward_to_single_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.into_single_value()?.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Node<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Map(map) => visitor.visit_map(MapDeserializer::new(
+                map.into_iter().map(|(k, v)| (Value(k), v)),
+            )),
+            Node::Values(values) if values.len() == 1 => Node::Values(values)
+                .into_single_value()?
+                .deserialize_any(visitor),
+            Node::Values(values) => {
+                visitor.visit_seq(SeqDeserializer::new(values.into_iter().map(Value)))
+            }
+        }
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Values(values) => {
+                visitor.visit_seq(SeqDeserializer::new(values.into_iter().map(Value)))
+            }
+            Node::Map(_) => Err(de::Error::custom("expected a sequence, found nested keys")),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Map(_) => self.deserialize_any(visitor),
+            Node::Values(_) => Err(de::Error::custom(
+                "expected nested keys (e.g. `key[field]=value`), found a single value",
+            )),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // An empty value (e.g. `?page=`) is treated as a missing one.
+        match &self {
+            Node::Values(values) if values.len() == 1 && values[0].is_empty() => {
+                visitor.visit_none()
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.into_single_value()?
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.into_single_value()?
+            .deserialize_unit_struct(name, visitor)
+    }
+
+    forward_to_single_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_unit
+        deserialize_identifier
+    }
+}
+
+/// A single (percent-decoded) value, or a key.
+struct Value<'de>(Cow<'de, str>);
+
+impl<'de> IntoDeserializer<'de, DeError> for Value<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let value = self.0.parse().map_err(|_| {
+                    de::Error::custom(format!(
+                        "`{}` is not a valid {}",
+                        self.0,
+                        stringify!($method).trim_start_matches("deserialize_")
+                    ))
+                })?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Value<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Cow::Borrowed(s) => visitor.visit_borrowed_str(s),
+            Cow::Owned(s) => visitor.visit_string(s),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(std::iter::once(self)))
+    }
+
+    parse_value! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> EnumAccess<'de> for Value<'de> {
+    type Error = DeError;
+    type Variant = UnitOnly;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self).map(|v| (v, UnitOnly))
+    }
+}
+
+/// Only unit variants can be represented as a single value in a query string.
+struct UnitOnly;
+
+impl<'de> VariantAccess<'de> for UnitOnly {
+    type Error = DeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, _seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::UnitVariant,
+            &"newtype variant",
+        ))
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::UnitVariant,
+            &"tuple variant",
+        ))
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::UnitVariant,
+            &"struct variant",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::split_key;
+
+    #[test]
+    fn test_split_key() {
+        let split = |key: &'static str| split_key(Cow::Borrowed(key));
+        assert_eq!(split("filter"), vec!["filter"]);
+        assert_eq!(split("filter[status]"), vec!["filter", "status"]);
+        assert_eq!(split("filter[status][eq]"), vec!["filter", "status", "eq"]);
+        assert_eq!(split("room_ids[]"), vec!["room_ids[]"]);
+        assert_eq!(split("filter[tags][]"), vec!["filter", "tags[]"]);
+        // Malformed keys are kept as they are.
+        assert_eq!(split("filter[status"), vec!["filter[status"]);
+        assert_eq!(split("filter[]status"), vec!["filter[]status"]);
+        assert_eq!(split("[status]"), vec!["[status]"]);
+    }
+}
@@ -2,6 +2,8 @@
 
 use crate::response::Response;
 
+use super::deserializer::DeError;
+
 /// The error returned by [`QueryParams::extract`] when the extraction fails.
 ///
 /// See [`QueryParams::extract`] and the documentation of each error variant for more details.
@@ -31,17 +33,27 @@ impl ExtractQueryParamsError {
 }
 
 #[derive(Debug, thiserror::Error)]
-#[error(transparent)]
+#[error("{inner}")]
 /// Something went wrong when trying to deserialize the percent-decoded query parameters into
 /// the target type you specified—`T` in [`QueryParams<T>`].
 ///
+/// The error message includes the path to the offending field (e.g. `filter.status`),
+/// if the failure can be attributed to a specific field.
+///
 /// [`QueryParams<T>`]: crate::request::query::QueryParams
 pub struct QueryDeserializationError {
-    inner: serde_html_form::de::Error,
+    inner: serde_path_to_error::Error<DeError>,
 }
 
 impl QueryDeserializationError {
-    pub(super) fn new(e: serde_html_form::de::Error) -> Self {
+    pub(super) fn new(e: serde_path_to_error::Error<DeError>) -> Self {
         Self { inner: e }
     }
+
+    /// The path to the field that failed to deserialize, e.g. `filter.status`.
+    ///
+    /// It's set to `.` if the failure can't be attributed to a specific field.
+    pub fn path(&self) -> String {
+        self.inner.path().to_string()
+    }
 }
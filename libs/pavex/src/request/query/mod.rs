@@ -1,6 +1,9 @@
 //! Extract data from the query parameters of incoming requests.
 
+mod deserializer;
 pub mod errors;
 mod query_params;
+mod raw_query_params;
 
 pub use query_params::QueryParams;
+pub use raw_query_params::RawQueryParams;
@@ -3,6 +3,7 @@ use crate::blueprint::Blueprint;
 use crate::f;
 use crate::request::RequestHead;
 
+use super::deserializer::QueryDeserializer;
 use super::errors::{ExtractQueryParamsError, QueryDeserializationError};
 
 /// Extract (typed) query parameters from the query of an incoming request.
@@ -82,7 +83,7 @@ fn parse<'a, T>(s: &'a str) -> Result<T, ExtractQueryParamsError>
 where
     T: serde::Deserialize<'a>,
 {
-    serde_html_form::from_str(s)
+    serde_path_to_error::deserialize(QueryDeserializer::new(s))
         .map_err(QueryDeserializationError::new)
         .map_err(ExtractQueryParamsError::QueryDeserializationError)
 }
@@ -127,6 +128,42 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_sequence_with_interleaved_keys() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Search {
+            tag: Vec<String>,
+            q: String,
+        }
+
+        let query = "tag=a&q=pavex&tag=b%20c&tag=d";
+        let expected = Search {
+            tag: vec!["a".into(), "b c".into(), "d".into()],
+            q: "pavex".into(),
+        };
+        let actual: Search = parse(query).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_plus_is_decoded_as_a_space() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Search {
+            q: String,
+            #[serde(rename = "tags[]")]
+            tags: Vec<String>,
+        }
+
+        // `+` is a space, while a percent-encoded `+` (`%2B`) is a literal plus sign.
+        let query = "q=hello+world&tags[]=c%2B%2B&tags[]=a+b";
+        let expected = Search {
+            q: "hello world".into(),
+            tags: vec!["c++".into(), "a b".into()],
+        };
+        let actual: Search = parse(query).unwrap();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn test_sequence_with_brackets() {
         #[derive(serde::Deserialize, Debug, PartialEq)]
@@ -142,4 +179,118 @@ mod tests {
         let actual: Home = parse(query).unwrap();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_nested_keys() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Search {
+            q: String,
+            filter: Filter,
+        }
+
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Filter {
+            status: String,
+            #[serde(rename = "tags[]")]
+            tags: Vec<String>,
+            owner: Option<u32>,
+        }
+
+        let query = "q=pavex&filter%5Bstatus%5D=open&filter[tags][]=bug&filter[tags][]=ui";
+        let expected = Search {
+            q: "pavex".into(),
+            filter: Filter {
+                status: "open".into(),
+                tags: vec!["bug".into(), "ui".into()],
+                owner: None,
+            },
+        };
+        let actual: Search = parse(query).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_optional_fields() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Page {
+            page: Option<u32>,
+            q: Option<String>,
+            #[serde(default, rename = "tags[]")]
+            tags: Vec<Option<u32>>,
+        }
+
+        // Missing values.
+        let expected = Page {
+            page: None,
+            q: None,
+            tags: vec![],
+        };
+        let actual: Page = parse("").unwrap();
+        assert_eq!(expected, actual);
+
+        // Empty values.
+        let expected = Page {
+            page: None,
+            q: None,
+            tags: vec![Some(1), None],
+        };
+        let actual: Page = parse("page=&q=&tags[]=1&tags[]=").unwrap();
+        assert_eq!(expected, actual);
+
+        // Non-empty values.
+        let expected = Page {
+            page: Some(2),
+            q: Some("pavex".into()),
+            tags: vec![],
+        };
+        let actual: Page = parse("page=2&q=pavex").unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_error_reports_the_path_to_the_offending_field() {
+        #[derive(serde::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Search {
+            filter: Filter,
+        }
+
+        #[derive(serde::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Filter {
+            owner: u32,
+        }
+
+        let query = "filter[owner]=me";
+        let err = parse::<Search>(query).unwrap_err();
+        let ExtractQueryParamsError::QueryDeserializationError(e) = &err;
+        assert_eq!(e.path(), "filter.owner");
+        insta::assert_display_snapshot!(err, @"filter.owner: `me` is not a valid u32");
+    }
+
+    #[test]
+    fn test_repeated_key_for_a_single_value() {
+        #[derive(serde::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Home {
+            home_id: u32,
+        }
+
+        let query = "home_id=1&home_id=2";
+        let err = parse::<Home>(query).unwrap_err();
+        insta::assert_display_snapshot!(err, @"home_id: expected a single value, but the key was repeated 2 times in the query string");
+    }
+
+    #[test]
+    fn test_conflicting_keys() {
+        #[derive(serde::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Search {
+            filter: String,
+        }
+
+        let query = "filter=open&filter[status]=open";
+        let err = parse::<Search>(query).unwrap_err();
+        insta::assert_display_snapshot!(err, @"`filter[status]` is used both as a single value and as the parent of nested keys");
+    }
 }
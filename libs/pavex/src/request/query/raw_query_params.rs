@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+use crate::request::RequestHead;
+
+/// Extract (raw) query parameters from the query of an incoming request.
+///
+/// Keys and values are percent-decoded, but no further processing takes place:
+/// keys are not split into nested structures and repeated keys are preserved.  
+/// Use [`QueryParams`](super::QueryParams) if you want to deserialize the query
+/// parameters into a type of your choice.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::request::query::RawQueryParams;
+///
+/// pub fn search(params: &RawQueryParams) -> String {
+///     let query = params.get("q").unwrap_or_default();
+///     let tags: Vec<_> = params.get_all("tag").collect();
+///     format!("Searching for `{query}` with tags {tags:?}")
+/// }
+/// ```
+///
+/// The `?q=pavex&tag=rust&tag=web` query string yields `pavex` as `q` and `["rust", "web"]` as tags.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RawQueryParams<'request>(Vec<(Cow<'request, str>, Cow<'request, str>)>);
+
+impl<'request> RawQueryParams<'request> {
+    /// The default constructor for [`RawQueryParams`].
+    ///
+    /// It never fails: invalid percent-encoded sequences are replaced with
+    /// the Unicode replacement character.
+    pub fn extract(request_head: &'request RequestHead) -> Self {
+        let query = request_head.target.query().unwrap_or_default();
+        Self(form_urlencoded::parse(query.as_bytes()).collect())
+    }
+
+    /// Returns the number of key-value pairs in the query string.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the query string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the first value associated with the given key, if any.
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&str> {
+        let key = key.as_ref();
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_ref())
+    }
+
+    /// Returns all the values associated with the given key, in the order they
+    /// appear in the query string.
+    pub fn get_all<'a>(&'a self, key: impl AsRef<str> + 'a) -> impl Iterator<Item = &'a str> + 'a {
+        self.0
+            .iter()
+            .filter(move |(k, _)| k == key.as_ref())
+            .map(|(_, v)| v.as_ref())
+    }
+
+    /// Returns an iterator over all the key-value pairs in the query string,
+    /// in the order they appear.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+}
+
+impl RawQueryParams<'_> {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`RawQueryParams`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](RawQueryParams::extract) for [`RawQueryParams`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(pavex::request::query::RawQueryParams::extract),
+            Lifecycle::RequestScoped,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawQueryParams;
+
+    #[test]
+    fn repeated_and_encoded_keys() {
+        let (parts, _) = http::Request::builder()
+            .uri("/search?q=hello%20world&tag=a&tag=b&filter%5Bstatus%5D=open&empty")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let head = parts.into();
+        let params = RawQueryParams::extract(&head);
+        assert_eq!(params.len(), 5);
+        assert_eq!(params.get("q"), Some("hello world"));
+        assert_eq!(params.get("tag"), Some("a"));
+        assert_eq!(params.get_all("tag").collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(
+            params.get_all(String::from("tag")).collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert_eq!(params.get("filter[status]"), Some("open"));
+        assert_eq!(params.get("empty"), Some(""));
+        assert_eq!(params.get("missing"), None);
+    }
+
+    #[test]
+    fn missing_query() {
+        let (parts, _) = http::Request::builder().body(()).unwrap().into_parts();
+        let head = parts.into();
+        let params = RawQueryParams::extract(&head);
+        assert!(params.is_empty());
+        assert_eq!(params.iter().count(), 0);
+    }
+}
@@ -0,0 +1,14 @@
+//! Helpers to build requests in unit tests.
+use http::Method;
+
+use super::RequestHead;
+
+/// Build a [`RequestHead`] with the given method, target and headers.
+pub(crate) fn request_head(method: Method, uri: &str, headers: &[(&str, &str)]) -> RequestHead {
+    let mut builder = http::Request::builder().method(method).uri(uri);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    let (parts, _) = builder.body(()).unwrap().into_parts();
+    parts.into()
+}
@@ -1,5 +1,5 @@
 use bytes::Bytes;
-use http::header::CONTENT_TYPE;
+use http::header::{CONTENT_TYPE, VARY};
 use http_body_util::Empty;
 
 use crate::http::StatusCode;
@@ -221,6 +221,87 @@ impl Response {
         self
     }
 
+    /// Add a header name to the `Vary` header of the [`Response`].
+    ///
+    /// The `Vary` header tells caches which request headers were used to
+    /// select the representation returned in the response—e.g. `Accept`
+    /// when performing [content negotiation](crate::request::negotiation).  
+    /// The header name is not added again if it is already listed
+    /// (or if the response varies on `*`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::http::header::{ACCEPT, ACCEPT_ENCODING, VARY};
+    /// use pavex::response::Response;
+    ///
+    /// let response = Response::ok()
+    ///     .append_vary(ACCEPT)
+    ///     .append_vary(ACCEPT_ENCODING)
+    ///     .append_vary(ACCEPT);
+    ///
+    /// assert_eq!(response.headers()[VARY], "accept, accept-encoding");
+    /// ```
+    pub fn append_vary(mut self, key: crate::http::HeaderName) -> Self {
+        let headers = self.inner.headers_mut();
+        let mut names: Vec<String> = headers
+            .get_all(VARY)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|name| name.trim().to_ascii_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+        if names.iter().any(|name| name == "*" || name == key.as_str()) {
+            return self;
+        }
+        names.push(key.as_str().to_owned());
+        let value = crate::http::HeaderValue::from_str(&names.join(", "))
+            .expect("A comma-separated list of header names is always a valid header value");
+        headers.insert(VARY, value);
+        self
+    }
+
+    /// Set the `ETag` header, replacing any existing value.
+    ///
+    /// Check out the [`conditional`](crate::conditional) module for more details on
+    /// how entity tags are used to serve conditional requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::conditional::ETag;
+    /// use pavex::http::header::ETAG;
+    /// use pavex::response::Response;
+    ///
+    /// let response = Response::ok().set_etag(&ETag::strong("v1"));
+    /// assert_eq!(response.headers()[ETAG], "\"v1\"");
+    /// ```
+    pub fn set_etag(self, etag: &crate::conditional::ETag) -> Self {
+        self.insert_header(crate::http::header::ETAG, etag.to_header_value())
+    }
+
+    /// Set the `Last-Modified` header, replacing any existing value.
+    ///
+    /// HTTP dates have a resolution of one second: the sub-second part of
+    /// `last_modified` is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pavex::http::header::LAST_MODIFIED;
+    /// use pavex::response::Response;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let response = Response::ok().set_last_modified(UNIX_EPOCH + Duration::from_secs(1445412480));
+    /// assert_eq!(response.headers()[LAST_MODIFIED], "Wed, 21 Oct 2015 07:28:00 GMT");
+    /// ```
+    pub fn set_last_modified(self, last_modified: std::time::SystemTime) -> Self {
+        let value = crate::http::HeaderValue::try_from(httpdate::fmt_http_date(last_modified))
+            .expect("An HTTP date is always a valid header value");
+        self.insert_header(crate::http::header::LAST_MODIFIED, value)
+    }
+
     /// Set the [`Response`] body.
     ///
     /// The provided body must implement the [`TypedBody`] trait.  
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::request::path::RawPathParams;
+
+/// Check if the value of the route parameter named `name` can be parsed as a `T`.
+///
+/// It returns `false` if the parameter is missing or if its value is not a well-formed
+/// UTF-8 string once percent-decoded.
+///
+/// It's used by the code generated by Pavex to enforce type constraints on route
+/// parameters (e.g. `/users/:id<u64>`), you shouldn't need to call it directly.
+#[doc(hidden)]
+pub fn param_parses_as<T: FromStr>(params: &RawPathParams<'_, '_>, name: &str) -> bool {
+    params
+        .iter()
+        .find(|(key, _)| *key == name)
+        .and_then(|(_, value)| value.decode().ok())
+        .is_some_and(|value| value.parse::<T>().is_ok())
+}
+
+/// A regular expression that the value of a route parameter must match, in its entirety.
+///
+/// It's used by the code generated by Pavex to enforce pattern constraints on route
+/// parameters (e.g. `/posts/:slug<[a-z-]+>`), you shouldn't need to use it directly.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ParamPattern(Regex);
+
+impl ParamPattern {
+    /// Compile the pattern.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `pattern` is not a valid regular expression.
+    /// Pavex validates all patterns at compile-time, before generating code that invokes
+    /// this constructor.
+    pub fn new(pattern: &str) -> Self {
+        let regex = Regex::new(&format!("^(?:{pattern})$"))
+            .expect("Invalid pattern for a route parameter constraint");
+        Self(regex)
+    }
+
+    /// Check if the (percent-decoded) value of the route parameter named `name`
+    /// matches the pattern.
+    pub fn is_match(&self, params: &RawPathParams<'_, '_>, name: &str) -> bool {
+        params
+            .iter()
+            .find(|(key, _)| *key == name)
+            .and_then(|(_, value)| value.decode().ok())
+            .is_some_and(|value| self.0.is_match(&value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{param_parses_as, ParamPattern};
+
+    #[test]
+    fn constraints_are_checked_against_decoded_values() {
+        let mut router = matchit::Router::new();
+        router.insert("/posts/:id/:slug", ()).unwrap();
+
+        let matched = router.at("/posts/42/hello-world").unwrap();
+        let params = matched.params.into();
+        assert!(param_parses_as::<u64>(&params, "id"));
+        assert!(!param_parses_as::<u64>(&params, "slug"));
+        assert!(!param_parses_as::<u64>(&params, "missing"));
+        assert!(ParamPattern::new("[a-z-]+").is_match(&params, "slug"));
+        // The whole value must match.
+        assert!(!ParamPattern::new("[a-z]+").is_match(&params, "slug"));
+
+        let matched = router.at("/posts/-1/caf%C3%A9").unwrap();
+        let params = matched.params.into();
+        assert!(!param_parses_as::<u64>(&params, "id"));
+        assert!(ParamPattern::new("caf.").is_match(&params, "slug"));
+    }
+}
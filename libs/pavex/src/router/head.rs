@@ -0,0 +1,62 @@
+use http::header::CONTENT_LENGTH;
+use http::HeaderValue;
+
+use crate::response::body::raw::RawBody;
+use crate::response::{Response, ResponseBody};
+
+/// Turn the response to a `GET` request into the response to the equivalent `HEAD` request.
+///
+/// The body is discarded.
+/// If the size of the body was known in advance and the response doesn't have a
+/// `Content-Length` header, it's set to the size of the discarded body—clients
+/// expect the same headers they would have received for a `GET` request.
+///
+/// It's used by the code generated by Pavex when [automatic `HEAD` handling] is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::http::header::CONTENT_LENGTH;
+/// use pavex::response::Response;
+/// use pavex::router::into_head_response;
+///
+/// let response = into_head_response(Response::ok().set_typed_body("Hello!"));
+/// assert_eq!(response.headers()[CONTENT_LENGTH], "6");
+/// ```
+///
+/// [automatic `HEAD` handling]: crate::blueprint::Blueprint::auto_head
+pub fn into_head_response(response: Response) -> Response {
+    let (head, body) = response.into_parts();
+    let mut response = Response::from_parts(head, ResponseBody::default());
+    if !response.headers().contains_key(CONTENT_LENGTH) {
+        if let Some(len) = body.size_hint().exact() {
+            response = response.insert_header(CONTENT_LENGTH, HeaderValue::from(len));
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::CONTENT_LENGTH;
+    use http_body_util::BodyExt;
+
+    use super::into_head_response;
+    use crate::response::Response;
+
+    #[tokio::test]
+    async fn body_is_discarded_and_length_is_preserved() {
+        let response = into_head_response(Response::ok().set_typed_body("Hello, world!"));
+        assert_eq!(response.headers()[CONTENT_LENGTH], "13");
+        let (_, body) = response.into_parts();
+        assert!(body.collect().await.unwrap().to_bytes().is_empty());
+
+        // An explicit `Content-Length` is left untouched.
+        let response = into_head_response(
+            Response::ok()
+                .set_typed_body("Hello, world!")
+                .insert_header(CONTENT_LENGTH, 42.into()),
+        );
+        assert_eq!(response.headers()[CONTENT_LENGTH], "42");
+    }
+}
@@ -0,0 +1,89 @@
+use http::header::HOST;
+
+use crate::request::RequestHead;
+
+/// The prefix added to the name of domain captures in [`RawPathParams`].
+///
+/// [`RawPathParams`]: crate::request::path::RawPathParams
+pub(crate) const HOST_PARAMETER_PREFIX: &str = "host.";
+
+/// Compute the key used to match an incoming request against the routes
+/// registered via [`Blueprint::nest_at_domain`].
+///
+/// The labels of the requested domain are listed in reverse order, followed by
+/// an `@` segment and the request path—e.g. a request for `acme.example.com/users`
+/// is turned into `~com/example/acme/@/users`.
+///
+/// It returns `None` if the request doesn't specify a domain, or if it's malformed.
+///
+/// It's used by the code generated by Pavex, you shouldn't need to call it directly.
+///
+/// [`Blueprint::nest_at_domain`]: crate::blueprint::Blueprint::nest_at_domain
+#[doc(hidden)]
+pub fn host_routing_key(request_head: &RequestHead) -> Option<String> {
+    let host = match request_head.target.host() {
+        Some(host) => host,
+        None => {
+            let header = request_head.headers.get(HOST)?.to_str().ok()?;
+            // Ignore the port, if any.
+            match header.rsplit_once(':') {
+                Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+                _ => header,
+            }
+        }
+    };
+    // A fully qualified domain name may end with a `.`.
+    let host = host.strip_suffix('.').unwrap_or(host);
+    if host.is_empty()
+        || !host
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'.')
+    {
+        return None;
+    }
+
+    let mut key = String::with_capacity(host.len() + request_head.target.path().len() + 3);
+    key.push('~');
+    for (i, label) in host.rsplit('.').enumerate() {
+        if label.is_empty() {
+            return None;
+        }
+        if i > 0 {
+            key.push('/');
+        }
+        key.push_str(&label.to_ascii_lowercase());
+    }
+    key.push_str("/@");
+    key.push_str(request_head.target.path());
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use super::host_routing_key;
+    use crate::request::test_utils::request_head;
+
+    fn routing_key(uri: &str, host: Option<&str>) -> Option<String> {
+        let headers: Vec<_> = host.map(|host| ("host", host)).into_iter().collect();
+        host_routing_key(&request_head(Method::GET, uri, &headers))
+    }
+
+    #[test]
+    fn domain_labels_are_reversed() {
+        let key = routing_key("/users", Some("Acme.Example.com:8080"));
+        assert_eq!(key.as_deref(), Some("~com/example/acme/@/users"));
+
+        let key = routing_key("https://admin.example.com./users", None);
+        assert_eq!(key.as_deref(), Some("~com/example/admin/@/users"));
+    }
+
+    #[test]
+    fn missing_or_malformed_hosts_are_ignored() {
+        assert_eq!(routing_key("/users", None), None);
+        assert_eq!(routing_key("/users", Some("a..com")), None);
+        assert_eq!(routing_key("/users", Some("[::1]:8080")), None);
+        assert_eq!(routing_key("/users", Some("a/b@c")), None);
+    }
+}
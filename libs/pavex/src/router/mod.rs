@@ -1,6 +1,22 @@
 //! Dispatch requests to the appropriate handler.
 pub use allowed_methods::{AllowedMethods, MethodAllowList};
+pub use constraints::{param_parses_as, ParamPattern};
 pub use fallback::default_fallback;
+pub use head::into_head_response;
+pub use host::host_routing_key;
+pub use options::auto_options;
+pub use redirect::{redirect, Redirect};
+pub use trailing_slash::redirect_trailing_slash;
+pub use urls::push_path_param;
+pub use version::{routing_keys, VersionSelector};
 
 mod allowed_methods;
+mod constraints;
 mod fallback;
+mod head;
+pub(crate) mod host;
+mod options;
+mod redirect;
+mod trailing_slash;
+mod urls;
+mod version;
@@ -0,0 +1,42 @@
+use crate::http::header::ALLOW;
+use crate::response::Response;
+
+use super::AllowedMethods;
+
+/// The request handler used by Pavex to answer `OPTIONS` requests when
+/// [automatic `OPTIONS` handling] is enabled.
+///
+/// It returns a `204 No Content` response with an `Allow` header listing
+/// the HTTP methods registered for the requested path.
+/// The `Allow` header is omitted if the path accepts all HTTP methods, including custom ones.
+///
+/// [automatic `OPTIONS` handling]: crate::blueprint::Blueprint::auto_options
+pub fn auto_options(allowed_methods: &AllowedMethods) -> Response {
+    let response = Response::no_content();
+    match allowed_methods.allow_header_value() {
+        Some(header_value) => response.insert_header(ALLOW, header_value),
+        None => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::ALLOW;
+    use http::{Method, StatusCode};
+
+    use super::auto_options;
+    use crate::router::{AllowedMethods, MethodAllowList};
+
+    #[test]
+    fn lists_the_allowed_methods() {
+        let allowed_methods: AllowedMethods =
+            MethodAllowList::from_iter([Method::GET, Method::POST, Method::OPTIONS]).into();
+        let response = auto_options(&allowed_methods);
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(response.headers()[ALLOW], "GET,POST,OPTIONS");
+
+        let response = auto_options(&AllowedMethods::All);
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(response.headers().get(ALLOW).is_none());
+    }
+}
@@ -0,0 +1,135 @@
+use http::header::LOCATION;
+use http::{HeaderValue, StatusCode};
+
+use crate::request::path::RawPathParams;
+use crate::response::Response;
+
+/// The target of a route registered via [`Blueprint::redirect`].
+///
+/// It's injected by the code generated by Pavex into the request pipeline of redirect routes,
+/// you shouldn't need to use it directly.
+///
+/// [`Blueprint::redirect`]: crate::blueprint::Blueprint::redirect
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct Redirect {
+    to: &'static str,
+    status: StatusCode,
+}
+
+impl Redirect {
+    /// Create a new [`Redirect`].
+    ///
+    /// # Panics
+    ///
+    /// It panics if `status` is not a valid status code.
+    pub fn new(to: &'static str, status: u16) -> Self {
+        let status = StatusCode::from_u16(status).expect("Invalid status code for a redirect");
+        Self { to, status }
+    }
+
+    /// Replace the path parameters referenced in the redirect target—e.g. `:id` or `*path`—with
+    /// the values they matched in the request path.
+    ///
+    /// References to unknown parameters are left as they are.
+    ///
+    /// If the location is a path, leading slashes (and backslashes) are collapsed into a single
+    /// `/`: a captured value such as `/evil.com` would otherwise turn `/*path` into the
+    /// protocol-relative `//evil.com`, redirecting the client to another origin.
+    fn location(&self, url_params: &RawPathParams) -> String {
+        let mut location = String::with_capacity(self.to.len());
+        let mut rest = self.to;
+        while let Some(i) = rest.find([':', '*']) {
+            location.push_str(&rest[..i]);
+            let after = &rest[i + 1..];
+            let name_len = param_name_len(after);
+            let name = &after[..name_len];
+            match url_params.get(name).filter(|_| name_len > 0) {
+                Some(value) => location.push_str(value),
+                None => location.push_str(&rest[i..i + 1 + name_len]),
+            }
+            rest = &after[name_len..];
+        }
+        location.push_str(rest);
+        if location.starts_with('/') {
+            let path = location.trim_start_matches(['/', '\\']);
+            if path.len() + 1 != location.len() {
+                location = format!("/{path}");
+            }
+        }
+        location
+    }
+}
+
+/// The length of the parameter name at the beginning of `s`.
+///
+/// Parameter names are made of ASCII letters, digits and underscores, and they can't start
+/// with a digit.
+fn param_name_len(s: &str) -> usize {
+    if !s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        return 0;
+    }
+    s.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(s.len())
+}
+
+/// The request handler used by Pavex for routes registered via [`Blueprint::redirect`].
+///
+/// It returns a response with the status code of the redirect and a `Location` header
+/// pointing at its target, with the path parameters filled in.
+///
+/// [`Blueprint::redirect`]: crate::blueprint::Blueprint::redirect
+#[doc(hidden)]
+pub fn redirect(redirect: &Redirect, url_params: &RawPathParams) -> Response {
+    let response = Response::new(redirect.status);
+    // The target has been validated when processing the blueprint and the parameter values
+    // come from the request path: they're always valid header values.
+    match HeaderValue::try_from(redirect.location(url_params)) {
+        Ok(location) => response.insert_header(LOCATION, location),
+        Err(_) => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::LOCATION;
+    use http::StatusCode;
+
+    use super::{redirect, Redirect};
+    use crate::request::path::RawPathParams;
+
+    #[test]
+    fn path_params_are_interpolated() {
+        let mut router = matchit::Router::new();
+        router.insert("/old/:id/files/*path", ()).unwrap();
+        let matched = router.at("/old/a%20b/files/docs/readme.md").unwrap();
+        let url_params: RawPathParams = matched.params.into();
+
+        let target = Redirect::new("https://example.com:8080/new/:id/*path", 301);
+        let response = redirect(&target, &url_params);
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            response.headers()[LOCATION],
+            "https://example.com:8080/new/a%20b/docs/readme.md"
+        );
+    }
+
+    #[test]
+    fn interpolated_paths_cannot_point_to_another_origin() {
+        let mut router = matchit::Router::new();
+        router.insert("/old/*path", ()).unwrap();
+        let target = Redirect::new("/*path", 308);
+
+        for (request_path, expected) in [
+            ("/old//evil.com", "/evil.com"),
+            ("/old/\\evil.com", "/evil.com"),
+            ("/old///evil.com/a//b", "/evil.com/a//b"),
+            ("/old/docs/readme.md", "/docs/readme.md"),
+        ] {
+            let matched = router.at(request_path).unwrap();
+            let url_params: RawPathParams = matched.params.into();
+            let response = redirect(&target, &url_params);
+            assert_eq!(response.headers()[LOCATION], expected, "{request_path}");
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use http::header::LOCATION;
+use http::HeaderValue;
+
+use crate::request::RequestHead;
+use crate::response::Response;
+
+/// Redirect the incoming request, with a `308 Permanent Redirect`, to the same path
+/// with its trailing slash added (if missing) or removed (if present).
+/// The query string, if any, is preserved.
+///
+/// It's used by the code generated by Pavex when the [trailing slash policy] is set
+/// to [`TrailingSlash::Redirect`], you shouldn't need to call it directly.
+///
+/// [trailing slash policy]: crate::blueprint::Blueprint::trailing_slash
+/// [`TrailingSlash::Redirect`]: crate::blueprint::router::TrailingSlash::Redirect
+#[doc(hidden)]
+pub fn redirect_trailing_slash(request_head: &RequestHead) -> Response {
+    let path = request_head.target.path();
+    let mut location = match path.strip_suffix('/') {
+        Some(stripped) => stripped.to_owned(),
+        None => format!("{path}/"),
+    };
+    if let Some(query) = request_head.target.query() {
+        location.push('?');
+        location.push_str(query);
+    }
+    let response = Response::permanent_redirect();
+    // The path and the query of a `Uri` are always valid header values.
+    match HeaderValue::try_from(location) {
+        Ok(location) => response.insert_header(LOCATION, location),
+        Err(_) => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::LOCATION;
+    use http::{Method, StatusCode};
+
+    use super::redirect_trailing_slash;
+    use crate::request::test_utils::request_head;
+
+    #[test]
+    fn toggles_the_trailing_slash() {
+        let response = redirect_trailing_slash(&request_head(Method::GET, "/users/", &[]));
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(response.headers()[LOCATION], "/users");
+
+        let response = redirect_trailing_slash(&request_head(Method::GET, "/users?page=2", &[]));
+        assert_eq!(response.headers()[LOCATION], "/users/?page=2");
+    }
+}
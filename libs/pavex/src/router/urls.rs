@@ -0,0 +1,61 @@
+use std::fmt::{Display, Write};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// The characters that must be percent-encoded in the value of a catch-all parameter.
+///
+/// It follows the definition of the `path` percent-encode set in the
+/// [URL standard](https://url.spec.whatwg.org/#path-percent-encode-set), plus `%`.
+const CATCH_ALL: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The characters that must be percent-encoded in the value of a path parameter.
+///
+/// Unlike catch-all parameters, the value of a path parameter must fit in a single
+/// path segment, therefore `/` must be encoded as well.
+const SEGMENT: &AsciiSet = &CATCH_ALL.add(b'/');
+
+/// Append the percent-encoded value of a path parameter to `path`.
+///
+/// The value of a catch-all parameter may span multiple path segments: `/` is left as is.
+///
+/// It's used by the URL builders generated by Pavex for [named routes], you shouldn't
+/// need to call it directly.
+///
+/// [named routes]: crate::blueprint::router::RegisteredRoute::name
+#[doc(hidden)]
+pub fn push_path_param(path: &mut String, value: impl Display, catch_all: bool) {
+    let value = value.to_string();
+    let ascii_set = if catch_all { CATCH_ALL } else { SEGMENT };
+    // Writing to a `String` never fails.
+    let _ = write!(path, "{}", utf8_percent_encode(&value, ascii_set));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::push_path_param;
+
+    #[test]
+    fn path_params_are_percent_encoded() {
+        let mut path = "/users/".to_string();
+        push_path_param(&mut path, "John Doe/Jr?", false);
+        assert_eq!(path, "/users/John%20Doe%2FJr%3F");
+
+        let mut path = "/files/".to_string();
+        push_path_param(&mut path, "a b/c.txt", true);
+        assert_eq!(path, "/files/a%20b/c.txt");
+
+        let mut path = "/orders/".to_string();
+        push_path_param(&mut path, 42u64, false);
+        assert_eq!(path, "/orders/42");
+    }
+}
@@ -0,0 +1,149 @@
+use http::header::ACCEPT;
+
+use crate::request::RequestHead;
+use crate::router::host_routing_key;
+
+/// A rule to route requests to a version of the API, registered via
+/// [`RegisteredApiVersion::select_by_header`] or [`RegisteredApiVersion::select_by_media_type`].
+///
+/// It's used by the code generated by Pavex, you shouldn't need to use it directly.
+///
+/// [`RegisteredApiVersion::select_by_header`]: crate::blueprint::router::RegisteredApiVersion::select_by_header
+/// [`RegisteredApiVersion::select_by_media_type`]: crate::blueprint::router::RegisteredApiVersion::select_by_media_type
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum VersionSelector {
+    /// The request carries the header `name`, set to `value`.
+    Header {
+        name: &'static str,
+        value: &'static str,
+    },
+    /// The `Accept` header of the request includes the media type.
+    MediaType(&'static str),
+}
+
+impl VersionSelector {
+    fn matches(&self, request_head: &RequestHead) -> bool {
+        match self {
+            VersionSelector::Header { name, value } => request_head
+                .headers
+                .get_all(*name)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .any(|v| v.trim().eq_ignore_ascii_case(value)),
+            VersionSelector::MediaType(media_type) => request_head
+                .headers
+                .get_all(ACCEPT)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .filter_map(|v| v.split(';').next())
+                .any(|v| v.trim().eq_ignore_ascii_case(media_type)),
+        }
+    }
+}
+
+/// Compute the keys used to match an incoming request against the registered routes,
+/// in order of priority.
+///
+/// `versions` associates the path prefix of each API version with the rule used to
+/// route requests to it. The first version whose selector matches the request is used.
+/// If `match_domains` is `true`, the keys for the domain of the request
+/// (see [`host_routing_key`]) come first.
+///
+/// It's used by the code generated by Pavex, you shouldn't need to call it directly.
+#[doc(hidden)]
+pub fn routing_keys(
+    request_head: &RequestHead,
+    match_domains: bool,
+    versions: &[(&'static str, VersionSelector)],
+) -> Vec<String> {
+    let path = request_head.target.path();
+    let version_prefix = versions
+        .iter()
+        .find(|(_, selector)| selector.matches(request_head))
+        .map(|(prefix, _)| *prefix);
+    let host_key = match_domains
+        .then(|| host_routing_key(request_head))
+        .flatten();
+
+    let mut keys = Vec::with_capacity(4);
+    if let Some(host_key) = host_key {
+        // The host key ends with the request path.
+        let host_prefix = &host_key[..host_key.len() - path.len()];
+        if let Some(version_prefix) = version_prefix {
+            keys.push(format!("{host_prefix}{version_prefix}{path}"));
+        }
+        keys.push(host_key);
+    }
+    if let Some(version_prefix) = version_prefix {
+        keys.push(format!("{version_prefix}{path}"));
+    }
+    keys.push(path.to_owned());
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{routing_keys, VersionSelector};
+    use crate::request::test_utils::request_head;
+    use http::Method;
+
+    const VERSIONS: [(&str, VersionSelector); 2] = [
+        (
+            "/v2",
+            VersionSelector::Header {
+                name: "api-version",
+                value: "2",
+            },
+        ),
+        (
+            "/v3",
+            VersionSelector::MediaType("application/vnd.example.v3+json"),
+        ),
+    ];
+
+    #[test]
+    fn versioned_keys_come_first() {
+        let keys = routing_keys(
+            &request_head(Method::GET, "/users", &[("Api-Version", "2")]),
+            false,
+            &VERSIONS,
+        );
+        assert_eq!(keys, vec!["/v2/users", "/users"]);
+
+        let accept = "text/html, application/vnd.example.v3+json; q=0.9";
+        let keys = routing_keys(
+            &request_head(Method::GET, "/users", &[("Accept", accept)]),
+            false,
+            &VERSIONS,
+        );
+        assert_eq!(keys, vec!["/v3/users", "/users"]);
+
+        let keys = routing_keys(
+            &request_head(Method::GET, "/users", &[("Api-Version", "1")]),
+            false,
+            &VERSIONS,
+        );
+        assert_eq!(keys, vec!["/users"]);
+    }
+
+    #[test]
+    fn domain_keys_come_before_path_keys() {
+        let request = request_head(
+            Method::GET,
+            "/users",
+            &[("Host", "example.com"), ("Api-Version", "2")],
+        );
+        let keys = routing_keys(&request, true, &VERSIONS);
+        assert_eq!(
+            keys,
+            vec![
+                "~com/example/@/v2/users",
+                "~com/example/@/users",
+                "/v2/users",
+                "/users"
+            ]
+        );
+    }
+}
@@ -7,6 +7,7 @@ use hyper_util::rt::TokioIo;
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::error::TrySendError;
 
+use crate::connection::ConnectionInfo;
 use crate::server::ShutdownMode;
 
 /// A handle to dispatch incoming connections to a worker thread.
@@ -221,9 +222,26 @@ where
         handler: fn(http::Request<hyper::body::Incoming>, ApplicationState) -> HandlerFuture,
         application_state: ApplicationState,
     ) {
+        // If we can't determine the peer address, we still serve the connection:
+        // requests that need `ConnectionInfo` will fail, all others will succeed.
+        let connection_info = match connection.peer_addr() {
+            Ok(peer_addr) => Some(ConnectionInfo::new(peer_addr)),
+            Err(e) => {
+                tracing::warn!(
+                    error.msg = %e,
+                    error.details = ?e,
+                    "Failed to retrieve the peer address of an incoming connection. \
+                    `ConnectionInfo` won't be available for its requests."
+                );
+                None
+            }
+        };
         // A tiny bit of glue to adapt our handler to hyper's service interface.
-        let handler = hyper::service::service_fn(move |request| {
+        let handler = hyper::service::service_fn(move |mut request: http::Request<_>| {
             let state = application_state.clone();
+            if let Some(connection_info) = connection_info {
+                request.extensions_mut().insert(connection_info);
+            }
             async move {
                 let handler = (handler)(request, state);
                 let response = handler.await;
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+/// Configuration for server-side [`Session`]s.
+///
+/// # Configuration
+///
+/// [`SessionConfig`] implements [`serde::Deserialize`], so you can load it as part
+/// of your application configuration.
+/// Timeouts are expressed in seconds.
+///
+/// [`Session`]: super::Session
+pub struct SessionConfig {
+    /// The name of the cookie used to store the session id.
+    ///
+    /// Defaults to `id`.
+    pub cookie_name: String,
+    /// A session expires if it hasn't been accessed for longer than `idle_timeout`.
+    ///
+    /// Defaults to 1 day.
+    #[serde(with = "seconds")]
+    pub idle_timeout: Duration,
+    /// A session expires after `absolute_timeout` since its creation,
+    /// regardless of activity.
+    ///
+    /// Defaults to 7 days.
+    #[serde(with = "seconds")]
+    pub absolute_timeout: Duration,
+}
+
+impl SessionConfig {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`SessionConfig`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::default) for [`SessionConfig`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(<pavex::session::SessionConfig as std::default::Default>::default),
+            Lifecycle::Singleton,
+        )
+    }
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            cookie_name: "id".into(),
+            idle_timeout: Duration::from_secs(60 * 60 * 24),
+            absolute_timeout: Duration::from_secs(60 * 60 * 24 * 7),
+        }
+    }
+}
+
+mod seconds {
+    use serde::{Deserialize, Deserializer};
+    use std::time::Duration;
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::response::Response;
+
+use super::SessionStoreError;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+/// The error returned by the methods of [`Session`](super::Session) and
+/// by [`session_middleware`](super::session_middleware).
+pub enum SessionError {
+    #[error("Failed to interact with the session store")]
+    /// The [`SessionStore`](super::SessionStore) failed to load, save or delete a session.
+    Store(#[from] SessionStoreError),
+    #[error("Failed to (de)serialize the value of the session key `{key}`")]
+    /// A session value couldn't be serialized or deserialized.
+    Serialization {
+        /// The session key whose value couldn't be (de)serialized.
+        key: String,
+        #[source]
+        /// The underlying serialization error.
+        source: serde_json::Error,
+    },
+}
+
+impl SessionError {
+    /// Convert a [`SessionError`] into an HTTP response.
+    ///
+    /// It always returns a `500 Internal Server Error`: session failures are
+    /// never caused by the client.
+    pub fn into_response(&self) -> Response {
+        Response::internal_server_error()
+    }
+}
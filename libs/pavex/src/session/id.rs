@@ -0,0 +1,72 @@
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+/// The number of characters in a session id.
+///
+/// Each character is drawn from an alphabet of 62 symbols (`[a-zA-Z0-9]`),
+/// thus providing ~256 bits of entropy.
+const SESSION_ID_LENGTH: usize = 43;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+/// The identifier of a server-side session.
+///
+/// It is stored in a cookie on the client side.
+/// Session ids are generated using a cryptographically secure random number
+/// generator and are made of alphanumeric characters only, which makes them safe
+/// to use as keys in any storage backend—e.g. as file names.
+pub struct SessionId(String);
+
+impl SessionId {
+    /// Generate a new random session id.
+    pub fn random() -> Self {
+        let id = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(SESSION_ID_LENGTH)
+            .map(char::from)
+            .collect();
+        Self(id)
+    }
+
+    /// Parse a session id, e.g. the value of the session cookie.
+    ///
+    /// It returns `None` if `s` is not a well-formed session id.
+    pub fn parse(s: &str) -> Option<Self> {
+        if s.len() == SESSION_ID_LENGTH && s.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            Some(Self(s.to_owned()))
+        } else {
+            None
+        }
+    }
+
+    /// The session id, as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for SessionId {
+    // Session ids are secrets: we don't want them to end up in logs.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionId(**redacted**)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SessionId;
+
+    #[test]
+    fn random_ids_are_well_formed() {
+        let id = SessionId::random();
+        assert_eq!(SessionId::parse(id.as_str()), Some(id.clone()));
+        assert_ne!(SessionId::random(), id);
+    }
+
+    #[test]
+    fn malformed_ids_are_rejected() {
+        assert!(SessionId::parse("").is_none());
+        assert!(SessionId::parse("abc").is_none());
+        let traversal = format!("../{}", "a".repeat(40));
+        assert!(SessionId::parse(&traversal).is_none());
+    }
+}
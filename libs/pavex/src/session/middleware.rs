@@ -0,0 +1,25 @@
+use std::future::IntoFuture;
+
+use crate::middleware::Next;
+use crate::response::Response;
+
+use super::{Session, SessionError};
+
+/// Persist the changes to the [`Session`] after the request handler has returned.
+///
+/// The session is only written to the store if it has been modified.
+/// The session cookie is set, refreshed or removed as needed via
+/// [`ResponseCookies`](crate::cookie::ResponseCookies): this middleware must
+/// therefore be wrapped by [`cookie_middleware`](crate::cookie::cookie_middleware)
+/// or [`cookie_middleware_with_config`](crate::cookie::cookie_middleware_with_config).
+pub async fn session_middleware<C>(
+    next: Next<C>,
+    session: &Session,
+) -> Result<Response, SessionError>
+where
+    C: IntoFuture<Output = Response>,
+{
+    let response = next.await;
+    session.persist().await?;
+    Ok(response)
+}
@@ -0,0 +1,59 @@
+//! Server-side sessions.
+//!
+//! # Overview
+//!
+//! A [`Session`] is a set of key-value pairs attached to a client.
+//! The data lives server-side, in a [`SessionStore`]: the client only holds
+//! a random session id, stored in a cookie.
+//!
+//! ```rust
+//! use pavex::response::Response;
+//! use pavex::session::{Session, SessionError};
+//!
+//! pub async fn visit_counter(session: &Session) -> Result<Response, SessionError> {
+//!     let visits: u64 = session.get("visits").await?.unwrap_or(0);
+//!     session.insert("visits", visits + 1).await?;
+//!     Ok(Response::ok().set_typed_body(format!("You visited this page {} times", visits + 1)))
+//! }
+//! ```
+//!
+//! # Lifecycle
+//!
+//! - The session is loaded lazily, the first time you access it within a request.
+//! - Changes are persisted by [`session_middleware`], after the request handler has
+//!   returned, and only if the session has been modified.
+//! - An empty session is never persisted: if you remove all its data, the session
+//!   is deleted from the store and the session cookie is removed.
+//! - Sessions expire after a period of inactivity ([`SessionConfig::idle_timeout`])
+//!   and, regardless of activity, after a maximum lifetime ([`SessionConfig::absolute_timeout`]).
+//!
+//! # Session id rotation
+//!
+//! Call [`Session::cycle_id`] whenever the privilege level of the client changes
+//! (e.g. on login or logout) to protect your users against session fixation attacks.
+//!
+//! # Setup
+//!
+//! Register the [`SessionKit`](crate::kit::SessionKit) **after** the
+//! [`CookieKit`](crate::kit::CookieKit): the session cookie is set by
+//! [`session_middleware`] via [`ResponseCookies`](crate::cookie::ResponseCookies),
+//! and it must be attached to the response by the cookie middleware.
+//!
+//! This module is only available if the `session` feature is enabled.
+//! It's not enabled by default.
+pub use config::SessionConfig;
+pub use errors::SessionError;
+pub use id::SessionId;
+pub use middleware::session_middleware;
+pub use session_::Session;
+pub use store::{
+    FileSessionStore, InMemorySessionStore, SessionRecord, SessionStore, SessionStoreError,
+    SharedSessionStore,
+};
+
+mod config;
+mod errors;
+mod id;
+mod middleware;
+mod session_;
+mod store;
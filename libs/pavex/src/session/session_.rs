@@ -0,0 +1,436 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::blueprint::constructor::{
+    CloningStrategy, Constructor, Lifecycle, RegisteredConstructor,
+};
+use crate::blueprint::Blueprint;
+use crate::cookie::{Cookie, RequestCookies, ResponseCookies};
+use crate::f;
+
+use super::{SessionConfig, SessionError, SessionId, SessionRecord, SharedSessionStore};
+
+/// A server-side session, attached to the current request.
+///
+/// Check out the [module-level documentation](super) for an overview.
+///
+/// # Cloning
+///
+/// [`Session`] is cheap to clone: all clones refer to the same underlying session.
+#[derive(Clone)]
+pub struct Session {
+    inner: Rc<Inner>,
+}
+
+struct Inner {
+    store: SharedSessionStore,
+    config: SessionConfig,
+    cookies: ResponseCookies,
+    state: RefCell<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The session id sent by the client, if any.
+    incoming_id: Option<SessionId>,
+    /// `true` if we've already tried to load the session from the store.
+    loaded: bool,
+    /// The id of the current session.
+    /// It's `None` if the session is new or its id has been cycled: a fresh id will be
+    /// generated when the session is persisted.
+    id: Option<SessionId>,
+    /// Ids that must be deleted from the store when the session is persisted.
+    stale_ids: Vec<SessionId>,
+    data: HashMap<String, serde_json::Value>,
+    created_at: Option<SystemTime>,
+    /// `true` if the session must be saved to the store.
+    dirty: bool,
+}
+
+impl Session {
+    /// Create a new [`Session`] for the current request.
+    ///
+    /// It doesn't hit the store: the session is loaded lazily, the first time it's accessed.
+    pub fn new(
+        request_cookies: &RequestCookies,
+        response_cookies: &ResponseCookies,
+        store: &SharedSessionStore,
+        config: &SessionConfig,
+    ) -> Self {
+        let incoming_id = request_cookies
+            .get(&config.cookie_name)
+            .and_then(|c| SessionId::parse(c.value()));
+        Self {
+            inner: Rc::new(Inner {
+                store: store.clone(),
+                config: config.clone(),
+                cookies: response_cookies.clone(),
+                state: RefCell::new(State {
+                    incoming_id,
+                    ..Default::default()
+                }),
+            }),
+        }
+    }
+
+    /// Get the value associated with `key`, if there is one.
+    pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, SessionError> {
+        self.load().await?;
+        let state = self.inner.state.borrow();
+        let Some(value) = state.data.get(key) else {
+            return Ok(None);
+        };
+        T::deserialize(value)
+            .map(Some)
+            .map_err(|source| SessionError::Serialization {
+                key: key.to_owned(),
+                source,
+            })
+    }
+
+    /// Associate `value` with `key`, replacing the previous value, if any.
+    pub async fn insert<T: Serialize>(&self, key: &str, value: T) -> Result<(), SessionError> {
+        let value = serde_json::to_value(value).map_err(|source| SessionError::Serialization {
+            key: key.to_owned(),
+            source,
+        })?;
+        self.load().await?;
+        let mut state = self.inner.state.borrow_mut();
+        state.data.insert(key.to_owned(), value);
+        state.dirty = true;
+        Ok(())
+    }
+
+    /// Remove the value associated with `key`, if there is one.
+    pub async fn remove(&self, key: &str) -> Result<(), SessionError> {
+        self.load().await?;
+        let mut state = self.inner.state.borrow_mut();
+        if state.data.remove(key).is_some() {
+            state.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Remove all values from the session.
+    ///
+    /// Since empty sessions are never persisted, the session will be deleted from the
+    /// store and the session cookie will be removed.
+    pub async fn clear(&self) -> Result<(), SessionError> {
+        self.load().await?;
+        let mut state = self.inner.state.borrow_mut();
+        if !state.data.is_empty() {
+            state.data.clear();
+            state.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Assign a new id to the session, keeping its data.
+    ///
+    /// The old id is invalidated. Call this method whenever the privilege level
+    /// of the client changes (e.g. on login) to prevent session fixation attacks.
+    pub async fn cycle_id(&self) -> Result<(), SessionError> {
+        self.load().await?;
+        let mut state = self.inner.state.borrow_mut();
+        if let Some(id) = state.id.take() {
+            state.stale_ids.push(id);
+        }
+        state.dirty = true;
+        Ok(())
+    }
+
+    /// Destroy the session: its data is removed and its id is invalidated.
+    ///
+    /// Values inserted after calling this method will be stored in a brand-new session.
+    pub async fn invalidate(&self) -> Result<(), SessionError> {
+        self.load().await?;
+        let mut state = self.inner.state.borrow_mut();
+        if let Some(id) = state.id.take() {
+            state.stale_ids.push(id);
+        }
+        state.data.clear();
+        state.created_at = None;
+        state.dirty = true;
+        Ok(())
+    }
+
+    /// Load the session from the store, unless it has already been loaded.
+    async fn load(&self) -> Result<(), SessionError> {
+        let incoming_id = {
+            let state = self.inner.state.borrow();
+            if state.loaded {
+                return Ok(());
+            }
+            state.incoming_id.clone()
+        };
+        let record = match &incoming_id {
+            Some(id) => self.inner.store.load(id).await?,
+            None => None,
+        };
+
+        let mut state = self.inner.state.borrow_mut();
+        if state.loaded {
+            // Another clone of this session has loaded it while we were waiting.
+            return Ok(());
+        }
+        state.loaded = true;
+        let (Some(id), Some(record)) = (incoming_id, record) else {
+            return Ok(());
+        };
+        let config = &self.inner.config;
+        let now = SystemTime::now();
+        if record.expires_at <= now || record.created_at + config.absolute_timeout <= now {
+            state.stale_ids.push(id);
+            return Ok(());
+        }
+        // Extend the idle timeout window, but avoid writing to the store on every
+        // single request: we only refresh the record if more than half of the
+        // idle timeout has already elapsed.
+        if let Ok(remaining) = record.expires_at.duration_since(now) {
+            if remaining < config.idle_timeout / 2 {
+                state.dirty = true;
+            }
+        }
+        state.id = Some(id);
+        state.data = record.state;
+        state.created_at = Some(record.created_at);
+        Ok(())
+    }
+
+    /// Persist the changes to the store and update the session cookie accordingly.
+    ///
+    /// It's a no-op if the session hasn't been modified.
+    /// It's invoked by [`session_middleware`](super::session_middleware).
+    pub(super) async fn persist(&self) -> Result<(), SessionError> {
+        let (stale_ids, to_save, had_cookie) = {
+            let mut state = self.inner.state.borrow_mut();
+            if !state.loaded {
+                return Ok(());
+            }
+            let had_cookie = state.incoming_id.is_some();
+            let mut stale_ids = std::mem::take(&mut state.stale_ids);
+            if state.data.is_empty() {
+                // Empty sessions are never persisted.
+                if let Some(id) = state.id.take() {
+                    stale_ids.push(id);
+                }
+                state.dirty = false;
+                (stale_ids, None, had_cookie)
+            } else if state.dirty {
+                let now = SystemTime::now();
+                let created_at = *state.created_at.get_or_insert(now);
+                let config = &self.inner.config;
+                let expires_at = std::cmp::min(
+                    now + config.idle_timeout,
+                    created_at + config.absolute_timeout,
+                );
+                let is_new = state.id.is_none();
+                let id = state.id.get_or_insert_with(SessionId::random).to_owned();
+                let record = SessionRecord {
+                    state: state.data.clone(),
+                    created_at,
+                    expires_at,
+                };
+                state.dirty = false;
+                (stale_ids, Some((id, record, is_new)), had_cookie)
+            } else {
+                (stale_ids, None, had_cookie)
+            }
+        };
+
+        let store = &self.inner.store;
+        for id in &stale_ids {
+            store.delete(id).await?;
+        }
+        let cookie_name = self.inner.config.cookie_name.clone();
+        match to_save {
+            Some((id, record, is_new)) => {
+                store.save(&id, &record).await?;
+                if is_new {
+                    // The cookie outlives the idle timeout: the store is the source of truth
+                    // when it comes to expiry.
+                    let max_age = (record.created_at + self.inner.config.absolute_timeout)
+                        .duration_since(SystemTime::now())
+                        .unwrap_or_default();
+                    let cookie = Cookie::build((cookie_name, id.as_str().to_owned()))
+                        .max_age(max_age.try_into().unwrap_or(cookie::time::Duration::MAX));
+                    self.inner.cookies.add(cookie.into());
+                }
+            }
+            None => {
+                let has_session = self.inner.state.borrow().id.is_some();
+                if had_cookie && !has_session {
+                    self.inner.cookies.remove(Cookie::from(cookie_name));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.inner.state.borrow();
+        f.debug_struct("Session")
+            .field("loaded", &state.loaded)
+            .field("dirty", &state.dirty)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Session {
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`Session`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Session::new) for [`Session`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(f!(crate::session::Session::new), Lifecycle::RequestScoped)
+            .cloning(CloningStrategy::CloneIfNecessary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::Session;
+    use crate::cookie::{RequestCookies, ResponseCookies};
+    use crate::http::header::COOKIE;
+    use crate::session::{SessionConfig, SessionId, SessionRecord, SharedSessionStore};
+
+    struct Request {
+        response_cookies: ResponseCookies,
+        session: Session,
+    }
+
+    fn request(store: &SharedSessionStore, session_id: Option<&SessionId>) -> Request {
+        let mut builder = http::Request::builder();
+        if let Some(id) = session_id {
+            builder = builder.header(COOKIE, format!("id={}", id.as_str()));
+        }
+        let (parts, _) = builder.body(()).unwrap().into_parts();
+        let request_cookies = RequestCookies::extract(&parts.into());
+        let response_cookies = ResponseCookies::from_request_cookies(&request_cookies);
+        let session = Session::new(
+            &request_cookies,
+            &response_cookies,
+            store,
+            &SessionConfig::default(),
+        );
+        Request {
+            response_cookies,
+            session,
+        }
+    }
+
+    /// The session id set by the server in the response, if any.
+    fn issued_id(request: &Request) -> Option<SessionId> {
+        request
+            .response_cookies
+            .get_cloned("id")
+            .and_then(|c| SessionId::parse(c.value()))
+    }
+
+    #[tokio::test]
+    async fn untouched_sessions_are_not_persisted() {
+        let r = request(&SharedSessionStore::in_memory(), None);
+        assert_eq!(r.session.get::<u64>("visits").await.unwrap(), None);
+        r.session.persist().await.unwrap();
+        assert!(issued_id(&r).is_none());
+    }
+
+    #[tokio::test]
+    async fn sessions_roundtrip_across_requests() {
+        let store = SharedSessionStore::in_memory();
+        let first = request(&store, None);
+        first.session.insert("user_id", 42u64).await.unwrap();
+        first.session.persist().await.unwrap();
+        let id = issued_id(&first).expect("A session cookie should have been set");
+
+        let second = request(&store, Some(&id));
+        assert_eq!(
+            second.session.get::<u64>("user_id").await.unwrap(),
+            Some(42)
+        );
+        second.session.persist().await.unwrap();
+        // Nothing changed, no need to touch the cookie.
+        assert!(second
+            .response_cookies
+            .get_cloned("id")
+            .unwrap()
+            .max_age()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn cycling_the_id_invalidates_the_old_one() {
+        let store = SharedSessionStore::in_memory();
+        let first = request(&store, None);
+        first.session.insert("role", "guest").await.unwrap();
+        first.session.persist().await.unwrap();
+        let old_id = issued_id(&first).unwrap();
+
+        let second = request(&store, Some(&old_id));
+        second.session.cycle_id().await.unwrap();
+        second.session.insert("role", "admin").await.unwrap();
+        second.session.persist().await.unwrap();
+        let new_id = issued_id(&second).unwrap();
+        assert_ne!(old_id, new_id);
+        assert!(store.load(&old_id).await.unwrap().is_none());
+
+        let third = request(&store, Some(&new_id));
+        assert_eq!(
+            third
+                .session
+                .get::<String>("role")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("admin")
+        );
+    }
+
+    #[tokio::test]
+    async fn invalidated_sessions_are_deleted() {
+        let store = SharedSessionStore::in_memory();
+        let first = request(&store, None);
+        first.session.insert("user_id", 42u64).await.unwrap();
+        first.session.persist().await.unwrap();
+        let id = issued_id(&first).unwrap();
+
+        let second = request(&store, Some(&id));
+        second.session.invalidate().await.unwrap();
+        second.session.persist().await.unwrap();
+        assert!(store.load(&id).await.unwrap().is_none());
+        // The cookie has been removed.
+        let removal = second.response_cookies.get_cloned("id");
+        assert!(removal.is_none());
+    }
+
+    #[tokio::test]
+    async fn expired_sessions_are_ignored() {
+        let store = SharedSessionStore::in_memory();
+        let id = SessionId::random();
+        let now = SystemTime::now();
+        let record = SessionRecord {
+            state: [("user_id".to_string(), serde_json::json!(42))].into(),
+            created_at: now - Duration::from_secs(60 * 60 * 24 * 30),
+            expires_at: now + Duration::from_secs(60),
+        };
+        store.save(&id, &record).await.unwrap();
+
+        // The idle timeout hasn't elapsed, but the absolute timeout has.
+        let r = request(&store, Some(&id));
+        assert_eq!(r.session.get::<u64>("user_id").await.unwrap(), None);
+        r.session.persist().await.unwrap();
+        assert!(store.load(&id).await.unwrap().is_none());
+    }
+}
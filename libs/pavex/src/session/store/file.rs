@@ -0,0 +1,149 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use futures_util::future::BoxFuture;
+use rand::Rng;
+
+use super::{SessionRecord, SessionStore, SessionStoreError};
+use crate::session::SessionId;
+
+#[derive(Debug, Clone)]
+/// A [`SessionStore`] that keeps each session in a JSON file.
+///
+/// Every session is stored in `<directory>/<session id>.json`.
+/// The directory is created on first write, if it doesn't exist yet.
+///
+/// # Limitations
+///
+/// This is a reference implementation: it's easy to inspect and it survives restarts,
+/// but it doesn't purge sessions that are never accessed again after expiring and
+/// it can only be shared across instances via a shared filesystem.
+pub struct FileSessionStore {
+    directory: PathBuf,
+}
+
+impl FileSessionStore {
+    /// Create a new store that keeps sessions in `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, id: &SessionId) -> PathBuf {
+        // Session ids are alphanumeric, therefore they are always valid file names.
+        self.directory.join(format!("{}.json", id.as_str()))
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn load<'a>(
+        &'a self,
+        id: &'a SessionId,
+    ) -> BoxFuture<'a, Result<Option<SessionRecord>, SessionStoreError>> {
+        Box::pin(async move {
+            let contents = match tokio::fs::read(self.path(id)).await {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(SessionStoreError::new(e)),
+            };
+            serde_json::from_slice(&contents)
+                .map(Some)
+                .map_err(SessionStoreError::new)
+        })
+    }
+
+    fn save<'a>(
+        &'a self,
+        id: &'a SessionId,
+        record: &'a SessionRecord,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>> {
+        Box::pin(async move {
+            let contents = serde_json::to_vec(record).map_err(SessionStoreError::new)?;
+            tokio::fs::create_dir_all(&self.directory)
+                .await
+                .map_err(SessionStoreError::new)?;
+            // Write to a temporary file first, then rename it: readers never observe
+            // a partially written session.
+            // The temporary file name is unique, since the same session can be saved
+            // concurrently by parallel requests.
+            let path = self.path(id);
+            let suffix: u64 = rand::thread_rng().gen();
+            let tmp_path = path.with_extension(format!("{suffix:016x}.json.tmp"));
+            tokio::fs::write(&tmp_path, contents)
+                .await
+                .map_err(SessionStoreError::new)?;
+            if let Err(e) = tokio::fs::rename(&tmp_path, &path).await {
+                let _ = tokio::fs::remove_file(&tmp_path).await;
+                return Err(SessionStoreError::new(e));
+            }
+            Ok(())
+        })
+    }
+
+    fn delete<'a>(&'a self, id: &'a SessionId) -> BoxFuture<'a, Result<(), SessionStoreError>> {
+        Box::pin(async move {
+            match tokio::fs::remove_file(self.path(id)).await {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(SessionStoreError::new(e)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::{Duration, SystemTime};
+
+    use super::FileSessionStore;
+    use crate::session::{SessionId, SessionRecord, SessionStore};
+
+    #[tokio::test]
+    async fn save_load_and_delete() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = FileSessionStore::new(directory.path().join("sessions"));
+        let id = SessionId::random();
+        assert_eq!(store.load(&id).await.unwrap(), None);
+
+        let now = SystemTime::now();
+        let record = SessionRecord {
+            state: HashMap::from([("user_id".to_string(), serde_json::json!(42))]),
+            created_at: now,
+            expires_at: now + Duration::from_secs(60),
+        };
+        store.save(&id, &record).await.unwrap();
+        assert_eq!(store.load(&id).await.unwrap(), Some(record));
+
+        store.delete(&id).await.unwrap();
+        assert_eq!(store.load(&id).await.unwrap(), None);
+        // Deleting a missing session is not an error.
+        store.delete(&id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn concurrent_saves_of_the_same_session() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = FileSessionStore::new(directory.path());
+        let id = SessionId::random();
+        let now = SystemTime::now();
+        let records: Vec<_> = (0..16)
+            .map(|i| SessionRecord {
+                state: HashMap::from([("counter".to_string(), serde_json::json!(i))]),
+                created_at: now,
+                expires_at: now + Duration::from_secs(60),
+            })
+            .collect();
+
+        let saves = records.iter().map(|record| store.save(&id, record));
+        for outcome in futures_util::future::join_all(saves).await {
+            outcome.unwrap();
+        }
+        let saved = store.load(&id).await.unwrap().unwrap();
+        assert!(records.contains(&saved));
+        // No temporary file is left behind.
+        let n_files = std::fs::read_dir(directory.path()).unwrap().count();
+        assert_eq!(n_files, 1);
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use futures_util::future::BoxFuture;
+
+use super::{SessionRecord, SessionStore, SessionStoreError};
+use crate::session::SessionId;
+
+#[derive(Debug, Default)]
+/// A [`SessionStore`] that keeps all sessions in memory.
+///
+/// Sessions are lost when the application is restarted and they are not shared
+/// across multiple instances of the same application.
+/// It's a good fit for tests and local development.
+pub struct InMemorySessionStore {
+    records: Mutex<HashMap<SessionId, SessionRecord>>,
+}
+
+impl InMemorySessionStore {
+    /// Create a new, empty, in-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of sessions currently held in the store, including expired ones.
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap().len()
+    }
+
+    /// Returns `true` if the store doesn't hold any session.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all expired sessions from the store.
+    pub fn purge_expired(&self) {
+        let now = SystemTime::now();
+        self.records
+            .lock()
+            .unwrap()
+            .retain(|_, record| record.expires_at > now);
+    }
+}
+
+impl SessionStore for InMemorySessionStore {
+    fn load<'a>(
+        &'a self,
+        id: &'a SessionId,
+    ) -> BoxFuture<'a, Result<Option<SessionRecord>, SessionStoreError>> {
+        let record = self.records.lock().unwrap().get(id).cloned();
+        Box::pin(async move { Ok(record) })
+    }
+
+    fn save<'a>(
+        &'a self,
+        id: &'a SessionId,
+        record: &'a SessionRecord,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>> {
+        self.records
+            .lock()
+            .unwrap()
+            .insert(id.to_owned(), record.to_owned());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete<'a>(&'a self, id: &'a SessionId) -> BoxFuture<'a, Result<(), SessionStoreError>> {
+        self.records.lock().unwrap().remove(id);
+        Box::pin(async { Ok(()) })
+    }
+}
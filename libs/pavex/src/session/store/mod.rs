@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use futures_util::future::BoxFuture;
+
+use crate::blueprint::constructor::{Constructor, Lifecycle, RegisteredConstructor};
+use crate::blueprint::Blueprint;
+use crate::f;
+
+use super::SessionId;
+
+pub use file::FileSessionStore;
+pub use memory::InMemorySessionStore;
+
+mod file;
+mod memory;
+
+/// A storage backend for server-side sessions.
+///
+/// Pavex provides two implementations out of the box:
+///
+/// - [`InMemorySessionStore`], for tests and single-instance deployments;
+/// - [`FileSessionStore`], a reference implementation backed by the filesystem.
+///
+/// Implement this trait to store sessions somewhere else—e.g. in a database or in a
+/// key-value store shared by multiple instances of your application.
+///
+/// # Expiry
+///
+/// [`Session`](super::Session) ignores records whose [`expires_at`](SessionRecord::expires_at)
+/// is in the past, and it takes care of deleting them.
+/// Stores may still want to purge expired records on their own (e.g. using a TTL),
+/// since sessions that are never accessed again would otherwise be kept forever.
+pub trait SessionStore: Send + Sync + 'static {
+    /// Load the session record associated with `id`, if there is one.
+    fn load<'a>(
+        &'a self,
+        id: &'a SessionId,
+    ) -> BoxFuture<'a, Result<Option<SessionRecord>, SessionStoreError>>;
+
+    /// Create or overwrite the session record associated with `id`.
+    fn save<'a>(
+        &'a self,
+        id: &'a SessionId,
+        record: &'a SessionRecord,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>>;
+
+    /// Delete the session record associated with `id`.
+    ///
+    /// Deleting a record that doesn't exist is not an error.
+    fn delete<'a>(&'a self, id: &'a SessionId) -> BoxFuture<'a, Result<(), SessionStoreError>>;
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// The server-side state of a session, as persisted by a [`SessionStore`].
+pub struct SessionRecord {
+    /// The key-value pairs stored in the session.
+    pub state: HashMap<String, serde_json::Value>,
+    /// When the session was created.
+    /// It is used to enforce the [absolute timeout](super::SessionConfig::absolute_timeout).
+    pub created_at: SystemTime,
+    /// When the session expires, unless it's accessed again before then.
+    pub expires_at: SystemTime,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+/// The error returned by a [`SessionStore`] when an operation fails.
+pub struct SessionStoreError(anyhow::Error);
+
+impl SessionStoreError {
+    /// Wrap an arbitrary error returned by a storage backend.
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Into<anyhow::Error>,
+    {
+        Self(error.into())
+    }
+}
+
+#[derive(Clone)]
+/// A type-erased [`SessionStore`], cheap to clone.
+///
+/// This is the type that [`Session`](super::Session) expects to be registered
+/// as a singleton with your [`Blueprint`].
+///
+/// # Example
+///
+/// ```rust
+/// use pavex::session::{FileSessionStore, SharedSessionStore};
+///
+/// pub fn session_store() -> SharedSessionStore {
+///     SharedSessionStore::new(FileSessionStore::new("sessions"))
+/// }
+/// ```
+pub struct SharedSessionStore(Arc<dyn SessionStore>);
+
+impl SharedSessionStore {
+    /// Wrap a [`SessionStore`].
+    pub fn new<S: SessionStore>(store: S) -> Self {
+        Self(Arc::new(store))
+    }
+
+    /// A [`SharedSessionStore`] backed by a new, empty, [`InMemorySessionStore`].
+    pub fn in_memory() -> Self {
+        Self::new(InMemorySessionStore::new())
+    }
+
+    /// Register the [default constructor](Self::default_constructor)
+    /// for [`SharedSessionStore`] with a [`Blueprint`].
+    pub fn register(bp: &mut Blueprint) -> RegisteredConstructor {
+        Self::default_constructor().register(bp)
+    }
+
+    /// The [default constructor](Self::in_memory) for [`SharedSessionStore`].
+    pub fn default_constructor() -> Constructor {
+        Constructor::new(
+            f!(crate::session::SharedSessionStore::in_memory),
+            Lifecycle::Singleton,
+        )
+    }
+}
+
+impl std::ops::Deref for SharedSessionStore {
+    type Target = dyn SessionStore;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl std::fmt::Debug for SharedSessionStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedSessionStore").finish_non_exhaustive()
+    }
+}
@@ -13,6 +13,56 @@ pub struct Blueprint {
     pub creation_location: Location,
     /// All registered components, in the order they were registered.
     pub components: Vec<Component>,
+    /// Settings that affect the behaviour of the generated router.
+    ///
+    /// They can only be customized on the root blueprint.
+    #[serde(default)]
+    pub router_settings: RouterSettings,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+/// Settings that affect the behaviour of the generated router.
+pub struct RouterSettings {
+    /// If `true`, `HEAD` requests are routed to the `GET` handler of a path
+    /// when there is no `HEAD` handler registered for it.
+    #[serde(default)]
+    pub auto_head: bool,
+    /// If `true`, `OPTIONS` requests are answered with a `204 No Content` response
+    /// listing the allowed methods when there is no `OPTIONS` handler registered for a path.
+    #[serde(default)]
+    pub auto_options: bool,
+    /// Per-path overrides for `auto_options`, in the order they were registered.
+    #[serde(default)]
+    pub auto_options_overrides: Vec<AutoOptionsOverride>,
+    /// How the router handles requests whose path only differs from a registered route
+    /// by a trailing slash.
+    #[serde(default)]
+    pub trailing_slash: TrailingSlash,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// How the router handles requests whose path only differs from a registered route
+/// by a trailing slash—e.g. `/users/` when `/users` is registered.
+pub enum TrailingSlash {
+    /// The two paths are distinct: only the registered one matches.
+    #[default]
+    Strict,
+    /// The request is redirected, with a `308 Permanent Redirect`, to the registered path.
+    Redirect,
+    /// Both paths are handled by the registered route.
+    MatchBoth,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+/// Enable or disable automatic `OPTIONS` handling for a specific path,
+/// regardless of the blueprint-wide setting.
+pub struct AutoOptionsOverride {
+    /// The path of the route, including the prefixes of the blueprints it's nested under.
+    pub path: String,
+    /// Whether automatic `OPTIONS` handling is enabled for this path.
+    pub enabled: bool,
+    /// The location where the override was registered against the `Blueprint`.
+    pub location: Location,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -72,6 +122,28 @@ pub struct Route {
     pub request_handler: Callable,
     /// The callable in charge of processing errors returned by the request handler, if any.
     pub error_handler: Option<Callable>,
+    /// The name of the route, if any.
+    /// It's used to generate a function that builds the URL for this route.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Constructors that only apply to this route, overriding the ones registered
+    /// against the `Blueprint`—e.g. a larger body size limit for an upload endpoint.
+    #[serde(default)]
+    pub constructors: Vec<Constructor>,
+    /// The target of the redirect, if the route was registered via `Blueprint::redirect`.
+    /// The request handler of a redirect route is provided by the framework.
+    #[serde(default)]
+    pub redirect: Option<Redirect>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+/// The target of a route registered via `Blueprint::redirect`.
+pub struct Redirect {
+    /// The URL the client is redirected to.
+    /// It may reference the parameters of the route path—e.g. `/users/:id`.
+    pub to: String,
+    /// The status code of the redirect response—e.g. `301`.
+    pub status: u16,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -127,8 +199,8 @@ pub struct Callable {
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
-/// A `Blueprint` that has been nested inside another `Blueprint` via `Blueprint::nest` or
-/// `Blueprint::nest_at`.
+/// A `Blueprint` that has been nested inside another `Blueprint` via `Blueprint::nest`,
+/// `Blueprint::nest_at`, `Blueprint::nest_at_domain` or `Blueprint::nest_version`.
 pub struct NestedBlueprint {
     /// The nested `Blueprint`.
     pub blueprint: Blueprint,
@@ -136,10 +208,67 @@ pub struct NestedBlueprint {
     /// `Blueprint`.
     /// If `None`, the routes coming from the nested `Blueprint` will be registered as-they-are.
     pub path_prefix: Option<String>,
+    /// The domain that incoming requests must target to be routed to the nested `Blueprint`,
+    /// set via `Blueprint::nest_at_domain`.
+    /// If `None`, the routes coming from the nested `Blueprint` match requests for any domain.
+    #[serde(default)]
+    pub domain: Option<String>,
+    /// The API version exposed by the nested `Blueprint`, set via `Blueprint::nest_version`.
+    /// If `None`, the nested `Blueprint` is not a version of an API.
+    #[serde(default)]
+    pub version: Option<ApiVersion>,
     /// The location where the `Blueprint` was nested under its parent `Blueprint`.
     pub nesting_location: Location,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+/// A version of an API, nested via `Blueprint::nest_version`.
+pub struct ApiVersion {
+    /// The name of the version—e.g. `v2`.
+    /// It's also used as path prefix for the routes of the version—e.g. `/v2`.
+    pub name: String,
+    /// The version whose routes are inherited by this version, if any.
+    #[serde(default)]
+    pub inherits_from: Option<InheritedVersion>,
+    /// Inherited routes that must not be exposed by this version.
+    #[serde(default)]
+    pub removed_routes: Vec<RemovedRoute>,
+    /// How to route requests to this version without the version prefix in their path, if at all.
+    #[serde(default)]
+    pub selector: Option<VersionSelector>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+/// The version an `ApiVersion` inherits routes from.
+pub struct InheritedVersion {
+    /// The name of the inherited version—e.g. `v1`.
+    /// It must be nested, via `Blueprint::nest_version`, against the same parent `Blueprint`.
+    pub name: String,
+    /// The location where the inheritance was declared.
+    pub location: Location,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+/// An inherited route that an `ApiVersion` doesn't expose.
+pub struct RemovedRoute {
+    /// The path of the route, relative to the version prefix.
+    pub path: String,
+    /// The method guard of the route.
+    pub method_guard: MethodGuard,
+    /// The location where the route was removed.
+    pub location: Location,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+/// How to pick an `ApiVersion` for requests whose path doesn't include the version prefix.
+pub enum VersionSelector {
+    /// The request carries a header with the specified value—e.g. `Api-Version: 2`.
+    Header { name: String, value: String },
+    /// The `Accept` header of the request includes the specified media type—e.g.
+    /// `application/vnd.example.v2+json`.
+    MediaType(String),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Lifecycle {
     Singleton,
@@ -185,6 +314,10 @@ pub enum Lint {
     /// You registered a component that's never used in the generated
     /// server SDK code.
     Unused,
+    /// You're extracting query parameters into a type that doesn't derive
+    /// `serde::Deserialize`: Pavex can't verify that its custom implementation
+    /// is compatible with the format of a query string.
+    CustomDeserialize,
 }
 
 #[derive(
@@ -22,6 +22,7 @@ use pavex_cli::utils;
 use pavex_cli::version::latest_released_version;
 use pavexc_cli_client::commands::generate::{BlueprintArgument, GenerateError};
 use pavexc_cli_client::commands::new::NewError;
+use pavexc_cli_client::commands::routes::RoutesError;
 use pavexc_cli_client::Client;
 use secrecy::{Secret, SecretString};
 use semver::Version;
@@ -81,6 +82,42 @@ impl FromStr for Color {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
+enum RoutesFormat {
+    Table,
+    Json,
+}
+
+impl Display for RoutesFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoutesFormat::Table => write!(f, "table"),
+            RoutesFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for RoutesFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(RoutesFormat::Table),
+            "json" => Ok(RoutesFormat::Json),
+            s => Err(anyhow::anyhow!("Invalid output format: {}", s)),
+        }
+    }
+}
+
+impl From<RoutesFormat> for pavexc_cli_client::commands::routes::RoutesFormat {
+    fn from(value: RoutesFormat) -> Self {
+        match value {
+            RoutesFormat::Table => Self::Table,
+            RoutesFormat::Json => Self::Json,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Generate the server SDK code for an application blueprint.
@@ -93,12 +130,30 @@ enum Commands {
         /// the application to the specified path.
         #[clap(long, value_parser)]
         diagnostics: Option<PathBuf>,
+        /// Optional.
+        /// If provided, Pavex will derive an OpenAPI document from the routes
+        /// of the application and save it, as JSON, to the specified path.
+        #[clap(long, value_parser)]
+        openapi: Option<PathBuf>,
         /// The directory that will contain the newly generated server SDK crate.
         /// If the directory path is relative,
         /// it is interpreted as relative to the root of the current workspace.
         #[clap(short, long, value_parser)]
         output: PathBuf,
     },
+    /// List the routes registered against an application blueprint.
+    ///
+    /// For each route, it shows the method, the path, the request handler,
+    /// the fallback in effect, the middleware chain and where the route was registered.
+    Routes {
+        /// The source path for the serialized application blueprint.
+        #[clap(short, long, value_parser)]
+        blueprint: PathBuf,
+        /// The output format.
+        /// Use `json` for a machine-readable output, e.g. to compare routes across branches.
+        #[clap(long, default_value_t = RoutesFormat::Table)]
+        format: RoutesFormat,
+    },
     /// Scaffold a new Pavex project at <PATH>.
     New {
         /// The directory that will contain the project files.
@@ -205,10 +260,23 @@ fn main() -> Result<ExitCode, miette::Error> {
         Commands::Generate {
             blueprint,
             diagnostics,
+            openapi,
             output,
         } => {
             check_activation(&State::new(&locator), &mut shell).map_err(utils::anyhow2miette)?;
-            generate(&mut shell, client, &locator, blueprint, diagnostics, output)
+            generate(
+                &mut shell,
+                client,
+                &locator,
+                blueprint,
+                diagnostics,
+                openapi,
+                output,
+            )
+        }
+        Commands::Routes { blueprint, format } => {
+            check_activation(&State::new(&locator), &mut shell).map_err(utils::anyhow2miette)?;
+            routes(&mut shell, client, &locator, blueprint, format)
         }
         Commands::New { path } => {
             check_activation(&State::new(&locator), &mut shell).map_err(utils::anyhow2miette)?;
@@ -236,6 +304,7 @@ fn generate(
     locator: &PavexLocator,
     blueprint: PathBuf,
     diagnostics: Option<PathBuf>,
+    openapi: Option<PathBuf>,
     output: PathBuf,
 ) -> Result<ExitCode, anyhow::Error> {
     let pavexc_cli_path = if let Some(pavexc_override) = pavex_cli::env::pavexc_override() {
@@ -255,6 +324,9 @@ fn generate(
     if let Some(diagnostics) = diagnostics {
         cmd = cmd.diagnostics_path(diagnostics)
     };
+    if let Some(openapi) = openapi {
+        cmd = cmd.openapi_path(openapi)
+    };
 
     match cmd.execute() {
         Ok(()) => Ok(ExitCode::SUCCESS),
@@ -263,6 +335,33 @@ fn generate(
     }
 }
 
+#[tracing::instrument("List routes", skip(client, locator, shell))]
+fn routes(
+    shell: &mut Shell,
+    mut client: Client,
+    locator: &PavexLocator,
+    blueprint: PathBuf,
+    format: RoutesFormat,
+) -> Result<ExitCode, anyhow::Error> {
+    let pavexc_cli_path = if let Some(pavexc_override) = pavex_cli::env::pavexc_override() {
+        pavexc_override
+    } else {
+        // Match the version of the `pavexc` binary with the version of the `pavex` library
+        // crate used in the current workspace.
+        let package_graph = compute_package_graph()
+            .context("Failed to compute package graph for the current workspace")?;
+        get_or_install_from_graph(shell, locator, &package_graph)
+            .context("Failed to get or install the `pavexc` binary")?
+    };
+    client = client.pavexc_cli_path(pavexc_cli_path);
+
+    match client.routes(blueprint).format(format.into()).execute() {
+        Ok(()) => Ok(ExitCode::SUCCESS),
+        Err(RoutesError::NonZeroExitCode(e)) => Ok(ExitCode::from(e.code as u8)),
+        Err(e) => Err(e.into()),
+    }
+}
+
 #[tracing::instrument("Scaffold new project", skip(client, locator, shell))]
 fn scaffold_project(
     mut client: Client,
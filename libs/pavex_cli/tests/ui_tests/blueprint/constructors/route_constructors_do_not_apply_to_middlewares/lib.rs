@@ -0,0 +1,45 @@
+use std::future::IntoFuture;
+
+use pavex::blueprint::{
+    constructor::{Constructor, Lifecycle},
+    router::GET,
+    Blueprint,
+};
+use pavex::f;
+use pavex::http::{HeaderName, HeaderValue};
+use pavex::middleware::Next;
+use pavex::response::Response;
+
+pub struct Greeting(pub String);
+
+pub fn greeting() -> Greeting {
+    Greeting("hello".into())
+}
+
+pub fn loud_greeting() -> Greeting {
+    Greeting("HELLO".into())
+}
+
+pub async fn tag<C>(next: Next<C>, greeting: &Greeting) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    let value = HeaderValue::from_str(&greeting.0).unwrap();
+    next.await
+        .insert_header(HeaderName::from_static("x-middleware-greeting"), value)
+}
+
+pub fn greet(greeting: &Greeting) -> Response {
+    Response::ok().set_typed_body(greeting.0.clone())
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(f!(crate::greeting), Lifecycle::RequestScoped);
+    bp.wrap(f!(crate::tag));
+    bp.route(GET, "/loud", f!(crate::greet)).constructor(Constructor::new(
+        f!(crate::loud_greeting),
+        Lifecycle::RequestScoped,
+    ));
+    bp
+}
@@ -0,0 +1,32 @@
+use pavex::blueprint::{
+    constructor::{Constructor, Lifecycle},
+    router::GET,
+    Blueprint,
+};
+use pavex::f;
+use pavex::response::Response;
+
+pub struct Greeting(pub String);
+
+pub fn greeting() -> Greeting {
+    Greeting("hello".into())
+}
+
+pub fn loud_greeting() -> Greeting {
+    Greeting("HELLO".into())
+}
+
+pub fn greet(greeting: &Greeting) -> Response {
+    Response::ok().set_typed_body(greeting.0.clone())
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(f!(crate::greeting), Lifecycle::RequestScoped);
+    bp.route(GET, "/quiet", f!(crate::greet));
+    bp.route(GET, "/loud", f!(crate::greet)).constructor(Constructor::new(
+        f!(crate::loud_greeting),
+        Lifecycle::RequestScoped,
+    ));
+    bp
+}
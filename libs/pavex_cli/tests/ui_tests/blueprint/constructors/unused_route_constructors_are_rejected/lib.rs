@@ -0,0 +1,26 @@
+use pavex::blueprint::{
+    constructor::{Constructor, Lifecycle},
+    router::GET,
+    Blueprint,
+};
+use pavex::f;
+use pavex::response::Response;
+
+pub struct Greeting(pub String);
+
+pub fn loud_greeting() -> Greeting {
+    Greeting("HELLO".into())
+}
+
+pub fn handler() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(GET, "/home", f!(crate::handler)).constructor(Constructor::new(
+        f!(crate::loud_greeting),
+        Lifecycle::RequestScoped,
+    ));
+    bp
+}
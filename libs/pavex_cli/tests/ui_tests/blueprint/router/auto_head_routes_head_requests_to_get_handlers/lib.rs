@@ -0,0 +1,38 @@
+use pavex::blueprint::{
+    router::{GET, HEAD, POST},
+    Blueprint,
+};
+use pavex::f;
+use pavex::http::HeaderValue;
+use pavex::response::Response;
+
+pub fn get_article() -> Response {
+    Response::ok().set_typed_body("Hello, world!")
+}
+
+pub fn head_feed() -> Response {
+    Response::ok().insert_header(
+        pavex::http::HeaderName::from_static("x-handler"),
+        HeaderValue::from_static("head_feed"),
+    )
+}
+
+pub fn get_feed() -> Response {
+    Response::ok().set_typed_body("feed")
+}
+
+pub fn create_article() -> Response {
+    Response::created()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.auto_head(true);
+    bp.route(GET, "/article", f!(crate::get_article));
+    // An explicit `HEAD` handler takes precedence.
+    bp.route(GET, "/feed", f!(crate::get_feed));
+    bp.route(HEAD, "/feed", f!(crate::head_feed));
+    // There is no `GET` handler to fall back to.
+    bp.route(POST, "/articles", f!(crate::create_article));
+    bp
+}
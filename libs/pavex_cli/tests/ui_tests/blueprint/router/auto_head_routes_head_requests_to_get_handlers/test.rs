@@ -0,0 +1,72 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+#[tokio::test]
+async fn head_requests_are_handled_by_the_get_handler() {
+    let port = spawn_test_server().await;
+    let client = reqwest::Client::new();
+    let response = client
+        .head(&format!("http://localhost:{}/article", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    // The headers match the ones of the `GET` response...
+    assert_eq!(response.headers()["content-length"], "13");
+    // ...but the body is discarded.
+    let body = response.bytes().await.expect("Failed to read the body");
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn explicit_head_handlers_take_precedence() {
+    let port = spawn_test_server().await;
+    let client = reqwest::Client::new();
+    let response = client
+        .head(&format!("http://localhost:{}/feed", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.headers()["x-handler"], "head_feed");
+}
+
+#[tokio::test]
+async fn head_requests_without_a_get_handler_are_rejected() {
+    let port = spawn_test_server().await;
+    let client = reqwest::Client::new();
+    let response = client
+        .head(&format!("http://localhost:{}/articles", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(
+        StatusCode::METHOD_NOT_ALLOWED.as_u16(),
+        response.status().as_u16()
+    );
+}
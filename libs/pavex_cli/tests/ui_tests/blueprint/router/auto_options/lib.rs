@@ -0,0 +1,35 @@
+use pavex::blueprint::{
+    router::{GET, OPTIONS, POST},
+    Blueprint,
+};
+use pavex::f;
+use pavex::response::Response;
+
+pub fn list_articles() -> Response {
+    Response::ok()
+}
+
+pub fn create_article() -> Response {
+    Response::created()
+}
+
+pub fn feed_options() -> Response {
+    Response::ok().set_typed_body("custom")
+}
+
+pub fn legacy() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.auto_options(true);
+    bp.auto_options_at("/legacy", false);
+    bp.route(GET, "/articles", f!(crate::list_articles));
+    bp.route(POST, "/articles", f!(crate::create_article));
+    // An explicit `OPTIONS` handler takes precedence.
+    bp.route(GET, "/feed", f!(crate::list_articles));
+    bp.route(OPTIONS, "/feed", f!(crate::feed_options));
+    bp.route(GET, "/legacy", f!(crate::legacy));
+    bp
+}
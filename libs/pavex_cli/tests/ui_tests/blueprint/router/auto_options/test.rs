@@ -0,0 +1,67 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+async fn options(port: u16, path: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .request(
+            reqwest::Method::OPTIONS,
+            &format!("http://localhost:{}{}", port, path),
+        )
+        .send()
+        .await
+        .expect("Failed to make request")
+}
+
+#[tokio::test]
+async fn options_requests_are_answered_automatically() {
+    let port = spawn_test_server().await;
+    let response = options(port, "/articles").await;
+    assert_eq!(StatusCode::NO_CONTENT.as_u16(), response.status().as_u16());
+    let allow = response.headers()["allow"].to_str().unwrap();
+    let mut allowed_methods: Vec<_> = allow.split(',').collect();
+    allowed_methods.sort();
+    assert_eq!(allowed_methods, ["GET", "OPTIONS", "POST"]);
+}
+
+#[tokio::test]
+async fn explicit_options_handlers_take_precedence() {
+    let port = spawn_test_server().await;
+    let response = options(port, "/feed").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "custom");
+}
+
+#[tokio::test]
+async fn auto_options_can_be_disabled_for_a_path() {
+    let port = spawn_test_server().await;
+    let response = options(port, "/legacy").await;
+    assert_eq!(
+        StatusCode::METHOD_NOT_ALLOWED.as_u16(),
+        response.status().as_u16()
+    );
+}
@@ -0,0 +1,16 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::response::Response;
+
+pub fn handler() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.auto_options(true);
+    // The route is registered as `/articles`, without a trailing slash.
+    bp.auto_options_at("/articles/", false);
+    bp.route(GET, "/articles", f!(crate::handler));
+    bp
+}
@@ -0,0 +1,12 @@
+use pavex::blueprint::Blueprint;
+use pavex::http::StatusCode;
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY);
+    bp.redirect("/profile/:id", "/people/:id", StatusCode::MOVED_PERMANENTLY);
+    // Registering the same redirect twice is fine.
+    bp.redirect("/about", "/team", StatusCode::FOUND);
+    bp.redirect("/about", "/team", StatusCode::FOUND);
+    bp
+}
@@ -0,0 +1,25 @@
+use pavex::blueprint::{
+    router::{MethodGuard, PROPFIND, WEBDAV},
+    Blueprint,
+};
+use pavex::f;
+use pavex::http::Method;
+
+pub fn handler_1() -> pavex::response::Response {
+    todo!()
+}
+
+pub fn handler_2() -> pavex::response::Response {
+    todo!()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(WEBDAV, "/files", f!(crate::handler_1));
+    bp.route(PROPFIND, "/files", f!(crate::handler_2));
+
+    let purge: MethodGuard = Method::from_bytes(b"PURGE").unwrap().into();
+    bp.route(purge.clone(), "/cache", f!(crate::handler_1));
+    bp.route(purge, "/cache", f!(crate::handler_2));
+    bp
+}
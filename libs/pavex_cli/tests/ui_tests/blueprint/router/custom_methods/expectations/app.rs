@@ -0,0 +1,148 @@
+//! Do NOT edit this code.
+//! It was automatically generated by Pavex.
+//! All manual edits will be lost next time the code is generated.
+extern crate alloc;
+struct ServerState {
+    router: pavex_matchit::Router<u32>,
+    #[allow(dead_code)]
+    application_state: ApplicationState,
+}
+pub struct ApplicationState {}
+pub async fn build_application_state() -> crate::ApplicationState {
+    crate::ApplicationState {}
+}
+pub fn run(
+    server_builder: pavex::server::Server,
+    application_state: ApplicationState,
+) -> pavex::server::ServerHandle {
+    let server_state = std::sync::Arc::new(ServerState {
+        router: build_router(),
+        application_state,
+    });
+    server_builder.serve(route_request, server_state)
+}
+fn build_router() -> pavex_matchit::Router<u32> {
+    let mut router = pavex_matchit::Router::new();
+    router.insert("/cache", 0u32).unwrap();
+    router.insert("/dav", 1u32).unwrap();
+    router.insert("/files", 2u32).unwrap();
+    router
+}
+async fn route_request(
+    request: http::Request<hyper::body::Incoming>,
+    server_state: std::sync::Arc<ServerState>,
+) -> pavex::response::Response {
+    let (request_head, request_body) = request.into_parts();
+    #[allow(unused)]
+    let request_body = pavex::request::body::RawIncomingBody::from(request_body);
+    let request_head: pavex::request::RequestHead = request_head.into();
+    let matched_route = match server_state.router.at(&request_head.target.path()) {
+        Ok(m) => m,
+        Err(_) => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            return route_4::handler(&allowed_methods).await;
+        }
+    };
+    let route_id = matched_route.value;
+    #[allow(unused)]
+    let url_params: pavex::request::path::RawPathParams<'_, '_> = matched_route
+        .params
+        .into();
+    match route_id {
+        0u32 => {
+            match &request_head.method {
+                s if s.as_str() == "PURGE" => route_3::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::try_from("PURGE")
+                                .expect("PURGE is not a valid (custom) HTTP method"),
+                        ])
+                        .into();
+                    route_4::handler(&allowed_methods).await
+                }
+            }
+        }
+        1u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_2::handler().await,
+                s if s.as_str() == "COPY" || s.as_str() == "LOCK"
+                    || s.as_str() == "MKCOL" || s.as_str() == "MOVE"
+                    || s.as_str() == "PROPFIND" || s.as_str() == "PROPPATCH"
+                    || s.as_str() == "UNLOCK" => route_2::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::try_from("COPY")
+                                .expect("COPY is not a valid (custom) HTTP method"),
+                            pavex::http::Method::GET,
+                            pavex::http::Method::try_from("LOCK")
+                                .expect("LOCK is not a valid (custom) HTTP method"),
+                            pavex::http::Method::try_from("MKCOL")
+                                .expect("MKCOL is not a valid (custom) HTTP method"),
+                            pavex::http::Method::try_from("MOVE")
+                                .expect("MOVE is not a valid (custom) HTTP method"),
+                            pavex::http::Method::try_from("PROPFIND")
+                                .expect("PROPFIND is not a valid (custom) HTTP method"),
+                            pavex::http::Method::try_from("PROPPATCH")
+                                .expect("PROPPATCH is not a valid (custom) HTTP method"),
+                            pavex::http::Method::try_from("UNLOCK")
+                                .expect("UNLOCK is not a valid (custom) HTTP method"),
+                        ])
+                        .into();
+                    route_4::handler(&allowed_methods).await
+                }
+            }
+        }
+        2u32 => {
+            match &request_head.method {
+                s if s.as_str() == "PROPFIND" => route_0::handler().await,
+                s if s.as_str() == "MKCOL" => route_1::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::try_from("PROPFIND")
+                                .expect("PROPFIND is not a valid (custom) HTTP method"),
+                            pavex::http::Method::try_from("MKCOL")
+                                .expect("MKCOL is not a valid (custom) HTTP method"),
+                        ])
+                        .into();
+                    route_4::handler(&allowed_methods).await
+                }
+            }
+        }
+        i => unreachable!("Unknown route id: {}", i),
+    }
+}
+pub mod route_0 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::list_properties();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_1 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::create_collection();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_2 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::dav();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_3 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::purge();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_4 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use pavex::blueprint::{
+    router::{MethodGuard, GET, MKCOL, PROPFIND, WEBDAV},
+    Blueprint,
+};
+use pavex::f;
+use pavex::http::Method;
+use pavex::response::Response;
+
+pub fn list_properties() -> Response {
+    Response::ok().set_typed_body("PROPFIND /files")
+}
+
+pub fn create_collection() -> Response {
+    Response::ok().set_typed_body("MKCOL /files")
+}
+
+pub fn dav() -> Response {
+    Response::ok().set_typed_body("WEBDAV or GET /dav")
+}
+
+pub fn purge() -> Response {
+    Response::ok().set_typed_body("PURGE /cache")
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(PROPFIND, "/files", f!(crate::list_properties));
+    bp.route(MKCOL, "/files", f!(crate::create_collection));
+    bp.route(WEBDAV.or(GET), "/dav", f!(crate::dav));
+
+    let purge: MethodGuard = Method::from_bytes(b"PURGE").unwrap().into();
+    bp.route(purge, "/cache", f!(crate::purge));
+    bp
+}
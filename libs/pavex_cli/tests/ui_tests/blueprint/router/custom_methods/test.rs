@@ -0,0 +1,112 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+async fn send(port: u16, method: &[u8], path: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .request(
+            reqwest::Method::from_bytes(method).unwrap(),
+            &format!("http://localhost:{}{}", port, path),
+        )
+        .send()
+        .await
+        .expect("Failed to make request")
+}
+
+#[tokio::test]
+async fn webdav_methods_are_routed() {
+    let port = spawn_test_server().await;
+
+    let response = send(port, b"PROPFIND", "/files").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "PROPFIND /files");
+
+    let response = send(port, b"MKCOL", "/files").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "MKCOL /files");
+}
+
+#[tokio::test]
+async fn webdav_guard_matches_all_webdav_methods() {
+    let port = spawn_test_server().await;
+
+    for method in [
+        &b"PROPFIND"[..],
+        b"PROPPATCH",
+        b"MKCOL",
+        b"COPY",
+        b"MOVE",
+        b"LOCK",
+        b"UNLOCK",
+        b"GET",
+    ] {
+        let response = send(port, method, "/dav").await;
+        assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+        assert_eq!(response.text().await.unwrap(), "WEBDAV or GET /dav");
+    }
+
+    let response = send(port, b"POST", "/dav").await;
+    assert_eq!(
+        StatusCode::METHOD_NOT_ALLOWED.as_u16(),
+        response.status().as_u16()
+    );
+}
+
+#[tokio::test]
+async fn custom_methods_are_routed() {
+    let port = spawn_test_server().await;
+
+    let response = send(port, b"PURGE", "/cache").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "PURGE /cache");
+}
+
+#[tokio::test]
+async fn custom_methods_are_case_sensitive() {
+    let port = spawn_test_server().await;
+
+    let response = send(port, b"purge", "/cache").await;
+    assert_eq!(
+        StatusCode::METHOD_NOT_ALLOWED.as_u16(),
+        response.status().as_u16()
+    );
+    assert_eq!(response.headers()["allow"], "PURGE");
+}
+
+#[tokio::test]
+async fn allow_header_lists_webdav_methods() {
+    let port = spawn_test_server().await;
+
+    let response = send(port, b"GET", "/files").await;
+    assert_eq!(
+        StatusCode::METHOD_NOT_ALLOWED.as_u16(),
+        response.status().as_u16()
+    );
+    let allow = response.headers()["allow"].to_str().unwrap();
+    assert!(allow.contains("PROPFIND"), "{allow}");
+    assert!(allow.contains("MKCOL"), "{allow}");
+}
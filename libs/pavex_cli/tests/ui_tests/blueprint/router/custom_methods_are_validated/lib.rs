@@ -0,0 +1,16 @@
+use pavex::blueprint::{router::MethodGuard, Blueprint};
+use pavex::f;
+use pavex::http::Method;
+
+pub fn handler() -> pavex::response::Response {
+    todo!()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    let get: MethodGuard = Method::from_bytes(b"get").unwrap().into();
+    bp.route(get, "/home", f!(crate::handler));
+    let propfind: MethodGuard = Method::from_bytes(b"Propfind").unwrap().into();
+    bp.route(propfind, "/files", f!(crate::handler));
+    bp
+}
@@ -0,0 +1,42 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::request::host::HostParams;
+use pavex::response::Response;
+
+#[derive(serde::Deserialize)]
+pub struct Tenant {
+    pub tenant: String,
+}
+
+pub fn list_users() -> Response {
+    Response::ok().set_typed_body("users")
+}
+
+pub fn list_admins() -> Response {
+    Response::ok().set_typed_body("admins")
+}
+
+pub fn list_tenant_users(params: &HostParams<Tenant>) -> Response {
+    Response::ok().set_typed_body(format!("{} users", params.0.tenant))
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    HostParams::register(&mut bp);
+    bp.route(GET, "/users", f!(crate::list_users));
+    bp.nest_at_domain("admin.example.com", admin_bp());
+    bp.nest_at_domain(":tenant.example.com", tenant_bp());
+    bp
+}
+
+fn admin_bp() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(GET, "/users", f!(crate::list_admins));
+    bp
+}
+
+fn tenant_bp() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(GET, "/users", f!(crate::list_tenant_users));
+    bp
+}
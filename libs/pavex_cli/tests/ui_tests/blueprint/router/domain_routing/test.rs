@@ -0,0 +1,72 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+async fn get_users(port: u16, host: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(&format!("http://localhost:{}/users", port))
+        .header("host", host)
+        .send()
+        .await
+        .expect("Failed to make request")
+}
+
+#[tokio::test]
+async fn literal_domains_take_precedence_over_captures() {
+    let port = spawn_test_server().await;
+    let response = get_users(port, "admin.example.com").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "admins");
+}
+
+#[tokio::test]
+async fn domain_captures_can_be_extracted() {
+    let port = spawn_test_server().await;
+    let response = get_users(port, "acme.example.com:8080").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "acme users");
+}
+
+#[tokio::test]
+async fn other_domains_are_routed_to_the_routes_without_a_domain() {
+    let port = spawn_test_server().await;
+    let response = get_users(port, "example.com").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "users");
+}
+
+#[tokio::test]
+async fn unknown_paths_on_a_domain_are_not_found() {
+    let port = spawn_test_server().await;
+    let response = reqwest::Client::new()
+        .get(&format!("http://localhost:{}/unknown", port))
+        .header("host", "admin.example.com")
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::NOT_FOUND.as_u16(), response.status().as_u16());
+}
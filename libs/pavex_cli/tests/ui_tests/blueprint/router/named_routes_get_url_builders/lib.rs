@@ -0,0 +1,43 @@
+use pavex::blueprint::{constructor::Lifecycle, router::GET, Blueprint};
+use pavex::f;
+use pavex::request::path::PathParams;
+use pavex::response::Response;
+
+#[PathParams]
+pub struct UserParams {
+    pub id: u64,
+}
+
+pub fn home() -> Response {
+    Response::ok()
+}
+
+pub fn show_user(params: PathParams<UserParams>) -> Response {
+    Response::ok().set_typed_body(format!("user {}", params.0.id))
+}
+
+pub fn download_file() -> Response {
+    Response::ok()
+}
+
+pub fn unnamed() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(
+        f!(pavex::request::path::PathParams::extract),
+        Lifecycle::RequestScoped,
+    )
+    .error_handler(f!(
+        pavex::request::path::errors::ExtractPathParamsError::into_response
+    ));
+    bp.route(GET, "/", f!(crate::home)).name("home");
+    bp.route(GET, "/users/:id", f!(crate::show_user))
+        .name("user.show");
+    bp.route(GET, "/teams/:team/files/*path", f!(crate::download_file))
+        .name("team.files.download");
+    bp.route(GET, "/unnamed", f!(crate::unnamed));
+    bp
+}
@@ -0,0 +1,55 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+#[test]
+fn static_paths_are_returned_as_they_are() {
+    assert_eq!(application::urls::home(), "/");
+}
+
+#[test]
+fn path_params_structs_are_used_as_input() {
+    let path = application::urls::user::show(&app::UserParams { id: 42 });
+    assert_eq!(path, "/users/42");
+}
+
+#[test]
+fn parameters_are_percent_encoded() {
+    let path = application::urls::team::files::download("a/b", "docs/my file.txt");
+    assert_eq!(path, "/teams/a%2Fb/files/docs/my%20file.txt");
+}
+
+#[tokio::test]
+async fn built_paths_match_their_route() {
+    let port = spawn_test_server().await;
+    let path = application::urls::user::show(&app::UserParams { id: 7 });
+    let response = reqwest::get(&format!("http://localhost:{}{}", port, path))
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "user 7");
+}
@@ -0,0 +1,16 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::response::Response;
+use pavex::router::Redirect;
+
+pub fn handler(_redirect: &Redirect) -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY);
+    bp.route(GET, "/users/:id", f!(crate::handler));
+    bp
+}
@@ -0,0 +1,8 @@
+use pavex::blueprint::Blueprint;
+use pavex::http::StatusCode;
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.redirect("/profile/:id", "/users/:id", StatusCode::OK);
+    bp
+}
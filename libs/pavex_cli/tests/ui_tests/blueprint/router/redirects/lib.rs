@@ -0,0 +1,20 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::response::Response;
+
+pub fn get_user() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(GET, "/users/:id", f!(crate::get_user));
+    bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY);
+    bp.redirect(
+        "/old/*path",
+        "https://example.com/new/*path",
+        StatusCode::TEMPORARY_REDIRECT,
+    );
+    bp
+}
@@ -0,0 +1,281 @@
+//! Do NOT edit this code.
+//! It was automatically generated by Pavex.
+//! All manual edits will be lost next time the code is generated.
+extern crate alloc;
+struct ServerState {
+    router: pavex_matchit::Router<u32>,
+    #[allow(dead_code)]
+    application_state: ApplicationState,
+}
+pub struct ApplicationState {}
+pub async fn build_application_state() -> crate::ApplicationState {
+    crate::ApplicationState {}
+}
+pub fn run(
+    server_builder: pavex::server::Server,
+    application_state: ApplicationState,
+) -> pavex::server::ServerHandle {
+    let server_state = std::sync::Arc::new(ServerState {
+        router: build_router(),
+        application_state,
+    });
+    server_builder.serve(route_request, server_state)
+}
+fn build_router() -> pavex_matchit::Router<u32> {
+    let mut router = pavex_matchit::Router::new();
+    router.insert("/api*catch_all", 0u32).unwrap();
+    router.insert("/api/users", 1u32).unwrap();
+    router.insert("/profile/:id", 2u32).unwrap();
+    router.insert("/proxy/*rest", 3u32).unwrap();
+    router.insert("/users", 4u32).unwrap();
+    router.insert("~com/example/admin/@/dashboard", 6u32).unwrap();
+    router.insert("~io/acme/@/dashboard", 8u32).unwrap();
+    router.insert("~com/example/admin/@*catch_all", 5u32).unwrap();
+    router.insert("~io/acme/@*catch_all", 7u32).unwrap();
+    router
+}
+async fn route_request(
+    request: http::Request<hyper::body::Incoming>,
+    server_state: std::sync::Arc<ServerState>,
+) -> pavex::response::Response {
+    let (request_head, request_body) = request.into_parts();
+    #[allow(unused)]
+    let request_body = pavex::request::body::RawIncomingBody::from(request_body);
+    let request_head: pavex::request::RequestHead = request_head.into();
+    let host_routing_key = pavex::router::host_routing_key(&request_head);
+    let matched_route = match host_routing_key
+        .as_deref()
+        .and_then(|key| server_state.router.at(key).ok())
+        .map(Ok)
+        .unwrap_or_else(|| server_state.router.at(&request_head.target.path()))
+    {
+        Ok(m) => m,
+        Err(_) => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            return route_4::handler(&allowed_methods).await;
+        }
+    };
+    let route_id = matched_route.value;
+    #[allow(unused)]
+    let url_params: pavex::request::path::RawPathParams<'_, '_> = matched_route
+        .params
+        .into();
+    match route_id {
+        0u32 => route_10::middleware_0().await,
+        1u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_9::middleware_0().await,
+                _ => route_10::middleware_0().await,
+            }
+        }
+        2u32 => {
+            let redirect_target = pavex::router::Redirect::new("/users/:id", 301u16);
+            route_3::handler(&url_params, &redirect_target).await
+        }
+        3u32 => {
+            match &request_head.method {
+                &pavex::http::Method::CONNECT
+                | &pavex::http::Method::DELETE
+                | &pavex::http::Method::GET
+                | &pavex::http::Method::HEAD
+                | &pavex::http::Method::OPTIONS
+                | &pavex::http::Method::PATCH
+                | &pavex::http::Method::POST
+                | &pavex::http::Method::PUT
+                | &pavex::http::Method::TRACE => route_2::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::CONNECT,
+                            pavex::http::Method::DELETE,
+                            pavex::http::Method::GET,
+                            pavex::http::Method::HEAD,
+                            pavex::http::Method::OPTIONS,
+                            pavex::http::Method::PATCH,
+                            pavex::http::Method::POST,
+                            pavex::http::Method::PUT,
+                            pavex::http::Method::TRACE,
+                        ])
+                        .into();
+                    route_4::handler(&allowed_methods).await
+                }
+            }
+        }
+        4u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_0::handler().await,
+                &pavex::http::Method::POST => route_1::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::GET,
+                            pavex::http::Method::POST,
+                        ])
+                        .into();
+                    route_4::handler(&allowed_methods).await
+                }
+            }
+        }
+        5u32 => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            route_8::handler(&allowed_methods).await
+        }
+        6u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_7::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::GET,
+                        ])
+                        .into();
+                    route_8::handler(&allowed_methods).await
+                }
+            }
+        }
+        7u32 => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            route_6::handler(&allowed_methods).await
+        }
+        8u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_5::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::GET,
+                        ])
+                        .into();
+                    route_6::handler(&allowed_methods).await
+                }
+            }
+        }
+        i => unreachable!("Unknown route id: {}", i),
+    }
+}
+pub mod route_0 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::list_users();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_1 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::create_user();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_2 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::proxy();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_3 {
+    pub async fn handler(
+        v0: &pavex::request::path::RawPathParams<'_, '_>,
+        v1: &pavex::router::Redirect,
+    ) -> pavex::response::Response {
+        let v2 = pavex::router::redirect(v1, v0);
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v2)
+    }
+}
+pub mod route_4 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
+pub mod route_5 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::dashboard();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_6 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
+pub mod route_7 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::dashboard();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_8 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
+pub mod route_9 {
+    pub async fn middleware_0() -> pavex::response::Response {
+        let v0 = crate::route_9::Next0 {
+            next: handler,
+        };
+        let v1 = pavex::middleware::Next::new(v0);
+        app::timeout(v1).await
+    }
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::list_users();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+    pub struct Next0<T>
+    where
+        T: std::future::Future<Output = pavex::response::Response>,
+    {
+        next: fn() -> T,
+    }
+    impl<T> std::future::IntoFuture for Next0<T>
+    where
+        T: std::future::Future<Output = pavex::response::Response>,
+    {
+        type Output = pavex::response::Response;
+        type IntoFuture = T;
+        fn into_future(self) -> Self::IntoFuture {
+            (self.next)()
+        }
+    }
+}
+pub mod route_10 {
+    pub async fn middleware_0() -> pavex::response::Response {
+        let v0 = crate::route_10::Next0 {
+            next: handler,
+        };
+        let v1 = pavex::middleware::Next::new(v0);
+        app::timeout(v1).await
+    }
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::api_fallback();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+    pub struct Next0<T>
+    where
+        T: std::future::Future<Output = pavex::response::Response>,
+    {
+        next: fn() -> T,
+    }
+    impl<T> std::future::IntoFuture for Next0<T>
+    where
+        T: std::future::Future<Output = pavex::response::Response>,
+    {
+        type Output = pavex::response::Response;
+        type IntoFuture = T;
+        fn into_future(self) -> Self::IntoFuture {
+            (self.next)()
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::future::IntoFuture;
+
+use pavex::blueprint::{
+    router::{ANY, GET, POST},
+    Blueprint,
+};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::middleware::Next;
+use pavex::response::Response;
+
+pub async fn timeout<C>(next: Next<C>) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    next.await
+}
+
+pub fn list_users() -> Response {
+    Response::ok()
+}
+
+pub fn create_user() -> Response {
+    Response::created()
+}
+
+pub fn proxy() -> Response {
+    Response::ok()
+}
+
+pub fn dashboard() -> Response {
+    Response::ok()
+}
+
+pub fn api_fallback() -> Response {
+    Response::not_found()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.route(GET, "/users", f!(crate::list_users));
+    bp.route(POST, "/users", f!(crate::create_user));
+    bp.route(ANY, "/proxy/*rest", f!(crate::proxy));
+    bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY);
+    bp.nest_at("/api", {
+        let mut bp = Blueprint::new();
+        bp.wrap(f!(crate::timeout));
+        bp.route(GET, "/users", f!(crate::list_users));
+        bp.fallback(f!(crate::api_fallback));
+        bp
+    });
+    bp.nest_at_domain("admin.example.com", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/dashboard", f!(crate::dashboard));
+        bp
+    });
+    // Routes are sorted by the displayed path, not by the (reversed) domain labels.
+    bp.nest_at_domain("acme.io", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/dashboard", f!(crate::dashboard));
+        bp
+    });
+    bp
+}
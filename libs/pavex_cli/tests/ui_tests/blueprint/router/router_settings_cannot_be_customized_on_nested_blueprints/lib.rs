@@ -0,0 +1,23 @@
+use pavex::blueprint::{
+    router::{TrailingSlash, GET},
+    Blueprint,
+};
+use pavex::f;
+
+pub fn handler() -> pavex::response::Response {
+    todo!()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.nest_at("/api", api());
+    bp
+}
+
+fn api() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.auto_head(true);
+    bp.trailing_slash(TrailingSlash::Redirect);
+    bp.route(GET, "/users", f!(crate::handler));
+    bp
+}
@@ -0,0 +1,22 @@
+use pavex::blueprint::{
+    router::{TrailingSlash, GET},
+    Blueprint,
+};
+use pavex::f;
+use pavex::response::Response;
+
+pub fn list_users() -> Response {
+    Response::ok()
+}
+
+pub fn list_users_with_slash() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.trailing_slash(TrailingSlash::MatchBoth);
+    bp.route(GET, "/users", f!(crate::list_users));
+    bp.route(GET, "/users/", f!(crate::list_users_with_slash));
+    bp
+}
@@ -0,0 +1,22 @@
+use pavex::blueprint::{
+    router::{TrailingSlash, GET},
+    Blueprint,
+};
+use pavex::f;
+use pavex::response::Response;
+
+pub fn list_users() -> Response {
+    Response::ok().set_typed_body("users")
+}
+
+pub fn list_teams() -> Response {
+    Response::ok().set_typed_body("teams")
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.trailing_slash(TrailingSlash::MatchBoth);
+    bp.route(GET, "/users", f!(crate::list_users));
+    bp.route(GET, "/teams/", f!(crate::list_teams));
+    bp
+}
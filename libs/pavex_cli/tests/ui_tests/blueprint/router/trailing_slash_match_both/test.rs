@@ -0,0 +1,50 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+async fn get(port: u16, path: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(&format!("http://localhost:{}{}", port, path))
+        .send()
+        .await
+        .expect("Failed to make request")
+}
+
+#[tokio::test]
+async fn both_forms_are_handled_by_the_registered_route() {
+    let port = spawn_test_server().await;
+    for path in ["/users", "/users/"] {
+        let response = get(port, path).await;
+        assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+        assert_eq!(response.text().await.unwrap(), "users");
+    }
+    for path in ["/teams", "/teams/"] {
+        let response = get(port, path).await;
+        assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+        assert_eq!(response.text().await.unwrap(), "teams");
+    }
+}
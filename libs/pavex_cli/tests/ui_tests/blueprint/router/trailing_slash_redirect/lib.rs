@@ -0,0 +1,28 @@
+use pavex::blueprint::{
+    router::{TrailingSlash, GET},
+    Blueprint,
+};
+use pavex::f;
+use pavex::response::Response;
+
+pub fn list_users() -> Response {
+    Response::ok().set_typed_body("users")
+}
+
+pub fn list_teams() -> Response {
+    Response::ok().set_typed_body("teams")
+}
+
+pub fn list_teams_with_slash() -> Response {
+    Response::ok().set_typed_body("teams/")
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.trailing_slash(TrailingSlash::Redirect);
+    bp.route(GET, "/users", f!(crate::list_users));
+    // Both forms are registered: no redirect takes place.
+    bp.route(GET, "/teams", f!(crate::list_teams));
+    bp.route(GET, "/teams/", f!(crate::list_teams_with_slash));
+    bp
+}
@@ -0,0 +1,49 @@
+use pavex::blueprint::{
+    router::{DELETE, GET},
+    Blueprint,
+};
+use pavex::f;
+
+pub mod v1 {
+    use pavex::response::Response;
+
+    pub fn list_users() -> Response {
+        Response::ok().set_typed_body("v1 users")
+    }
+
+    pub fn list_teams() -> Response {
+        Response::ok().set_typed_body("v1 teams")
+    }
+
+    pub fn delete_user() -> Response {
+        Response::no_content()
+    }
+}
+
+pub mod v2 {
+    use pavex::response::Response;
+
+    pub fn list_users() -> Response {
+        Response::ok().set_typed_body("v2 users")
+    }
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.nest_version("v1", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/users", f!(crate::v1::list_users));
+        bp.route(GET, "/teams", f!(crate::v1::list_teams));
+        bp.route(DELETE, "/users/:id", f!(crate::v1::delete_user));
+        bp
+    });
+    bp.nest_version("v2", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/users", f!(crate::v2::list_users));
+        bp
+    })
+    .inherit_from("v1")
+    .remove_route(DELETE, "/users/:id")
+    .select_by_header("Api-Version", "2");
+    bp
+}
@@ -0,0 +1,73 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+async fn send(method: reqwest::Method, port: u16, path: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .request(method, &format!("http://localhost:{}{}", port, path))
+        .send()
+        .await
+        .expect("Failed to make request")
+}
+
+#[tokio::test]
+async fn routes_registered_against_a_version_override_inherited_ones() {
+    let port = spawn_test_server().await;
+    let response = send(reqwest::Method::GET, port, "/v2/users").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "v2 users");
+}
+
+#[tokio::test]
+async fn routes_are_inherited() {
+    let port = spawn_test_server().await;
+    let response = send(reqwest::Method::GET, port, "/v2/teams").await;
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "v1 teams");
+}
+
+#[tokio::test]
+async fn removed_routes_are_not_inherited() {
+    let port = spawn_test_server().await;
+    let response = send(reqwest::Method::DELETE, port, "/v1/users/1").await;
+    assert_eq!(StatusCode::NO_CONTENT.as_u16(), response.status().as_u16());
+    let response = send(reqwest::Method::DELETE, port, "/v2/users/1").await;
+    assert_eq!(StatusCode::NOT_FOUND.as_u16(), response.status().as_u16());
+}
+
+#[tokio::test]
+async fn versions_can_be_selected_via_a_header() {
+    let port = spawn_test_server().await;
+    let response = reqwest::Client::new()
+        .get(&format!("http://localhost:{}/users", port))
+        .header("api-version", "2")
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "v2 users");
+}
@@ -0,0 +1,39 @@
+use pavex::blueprint::{
+    router::{GET, POST},
+    Blueprint,
+};
+use pavex::f;
+
+pub mod v1 {
+    use pavex::response::Response;
+
+    pub fn users() -> Response {
+        Response::ok().set_typed_body("v1 users")
+    }
+}
+
+pub mod v2 {
+    use pavex::response::Response;
+
+    pub fn list_users() -> Response {
+        Response::ok().set_typed_body("v2 users")
+    }
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.nest_version("v1", {
+        let mut bp = Blueprint::new();
+        bp.route(GET.or(POST), "/users", f!(crate::v1::users));
+        bp
+    });
+    bp.nest_version("v2", {
+        let mut bp = Blueprint::new();
+        // It doesn't override `GET.or(POST) /users`: both handle `GET /users` requests.
+        bp.route(GET, "/users", f!(crate::v2::list_users));
+        bp
+    })
+    .inherit_from("v1")
+    .select_by_header("Api-Version", "2");
+    bp
+}
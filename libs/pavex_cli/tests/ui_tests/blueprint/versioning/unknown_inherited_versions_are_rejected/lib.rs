@@ -0,0 +1,18 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::response::Response;
+
+pub fn list_users() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.nest_version("v1", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/users", f!(crate::list_users));
+        bp
+    });
+    bp.nest_version("v3", Blueprint::new()).inherit_from("v2");
+    bp
+}
@@ -0,0 +1,63 @@
+use std::future::IntoFuture;
+
+use pavex::blueprint::{constructor::Lifecycle, router::GET, Blueprint};
+use pavex::f;
+use pavex::middleware::Next;
+use pavex::request::{RequestContext, RequestHead};
+use pavex::response::Response;
+
+#[derive(Clone)]
+pub struct TraceId(pub String);
+
+/// A request-scoped type that takes ownership of (a clone of) the context.
+pub struct Audit(RequestContext);
+
+impl Audit {
+    pub fn new(context: RequestContext) -> Self {
+        Self(context)
+    }
+
+    pub fn trace_id(&self) -> Option<String> {
+        self.0.get::<TraceId>().map(|t| t.0)
+    }
+}
+
+pub async fn trace_id<C>(next: Next<C>, context: &RequestContext, head: &RequestHead) -> Response
+where
+    C: IntoFuture<Output = Response>,
+{
+    let trace_id = head
+        .headers
+        .get("x-trace-id")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("missing")
+        .to_owned();
+    context.insert(TraceId(trace_id));
+    next.await
+}
+
+pub fn traced(context: &RequestContext, audit: Audit, head: &RequestHead) -> Response {
+    let from_context = context
+        .get::<TraceId>()
+        .map(|t| t.0)
+        .unwrap_or_default();
+    let from_audit = audit.trace_id().unwrap_or_default();
+    Response::ok().set_typed_body(format!("{} {from_context} {from_audit}", head.method))
+}
+
+pub fn untraced(context: &RequestContext) -> Response {
+    Response::ok().set_typed_body(format!("{}", context.contains::<TraceId>()))
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(f!(crate::Audit::new), Lifecycle::RequestScoped);
+    bp.route(GET, "/untraced", f!(crate::untraced));
+    bp.nest({
+        let mut bp = Blueprint::new();
+        bp.wrap(f!(crate::trace_id));
+        bp.route(GET, "/traced", f!(crate::traced));
+        bp
+    });
+    bp
+}
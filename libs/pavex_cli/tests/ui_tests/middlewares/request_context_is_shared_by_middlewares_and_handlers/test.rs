@@ -0,0 +1,63 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+#[tokio::test]
+async fn values_inserted_by_a_middleware_are_visible_to_the_handler() {
+    let port = spawn_test_server().await;
+    let client = reqwest::Client::new();
+    let response = client
+        .get(&format!("http://localhost:{}/traced", port))
+        .header("x-trace-id", "0af7651916cd43dd")
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    // Both the borrowed context and the clone owned by `Audit` see the value.
+    assert_eq!(
+        response.text().await.unwrap(),
+        "GET 0af7651916cd43dd 0af7651916cd43dd"
+    );
+}
+
+#[tokio::test]
+async fn each_request_gets_a_fresh_context() {
+    let port = spawn_test_server().await;
+    let client = reqwest::Client::new();
+    client
+        .get(&format!("http://localhost:{}/traced", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    let response = client
+        .get(&format!("http://localhost:{}/untraced", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "false");
+}
@@ -0,0 +1,28 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::response::Response;
+
+/// The home page of the store.
+pub fn store_home() -> Response {
+    Response::ok()
+}
+
+/// The home page of the blog.
+pub fn blog_home() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.nest_at_domain("store.example.com", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/", f!(crate::store_home));
+        bp
+    });
+    bp.nest_at_domain("blog.example.com", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/", f!(crate::blog_home));
+        bp
+    });
+    bp
+}
@@ -0,0 +1,301 @@
+//! Do NOT edit this code.
+//! It was automatically generated by Pavex.
+//! All manual edits will be lost next time the code is generated.
+extern crate alloc;
+struct ServerState {
+    router: pavex_matchit::Router<u32>,
+    #[allow(dead_code)]
+    application_state: ApplicationState,
+}
+pub struct ApplicationState {}
+pub async fn build_application_state() -> crate::ApplicationState {
+    crate::ApplicationState {}
+}
+pub fn run(
+    server_builder: pavex::server::Server,
+    application_state: ApplicationState,
+) -> pavex::server::ServerHandle {
+    let server_state = std::sync::Arc::new(ServerState {
+        router: build_router(),
+        application_state,
+    });
+    server_builder.serve(route_request, server_state)
+}
+fn build_router() -> pavex_matchit::Router<u32> {
+    let mut router = pavex_matchit::Router::new();
+    router.insert("/profile/:id", 0u32).unwrap();
+    router.insert("/users", 1u32).unwrap();
+    router.insert("/users/:id", 2u32).unwrap();
+    router.insert("~com/example/:host.tenant/@/dashboard", 4u32).unwrap();
+    router.insert("~com/example/admin/@/dashboard", 6u32).unwrap();
+    router.insert("~com/example/:host.tenant/@*catch_all", 3u32).unwrap();
+    router.insert("~com/example/admin/@*catch_all", 5u32).unwrap();
+    router
+}
+async fn route_request(
+    request: http::Request<hyper::body::Incoming>,
+    server_state: std::sync::Arc<ServerState>,
+) -> pavex::response::Response {
+    let (request_head, request_body) = request.into_parts();
+    #[allow(unused)]
+    let request_body = pavex::request::body::RawIncomingBody::from(request_body);
+    let request_head: pavex::request::RequestHead = request_head.into();
+    let host_routing_key = pavex::router::host_routing_key(&request_head);
+    let matched_route = match host_routing_key
+        .as_deref()
+        .and_then(|key| server_state.router.at(key).ok())
+        .map(Ok)
+        .unwrap_or_else(|| server_state.router.at(&request_head.target.path()))
+    {
+        Ok(m) => m,
+        Err(_) => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            return route_4::handler(&allowed_methods).await;
+        }
+    };
+    let route_id = matched_route.value;
+    #[allow(unused)]
+    let url_params: pavex::request::path::RawPathParams<'_, '_> = matched_route
+        .params
+        .into();
+    match route_id {
+        0u32 => {
+            let redirect_target = pavex::router::Redirect::new("/users/:id", 301u16);
+            route_3::handler(&url_params, &redirect_target).await
+        }
+        1u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_0::handler(&request_head).await,
+                &pavex::http::Method::POST => {
+                    route_1::handler(request_body, &request_head).await
+                }
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::GET,
+                            pavex::http::Method::POST,
+                        ])
+                        .into();
+                    route_4::handler(&allowed_methods).await
+                }
+            }
+        }
+        2u32 => {
+            if pavex::router::param_parses_as::<u64>(&url_params, "id") {
+                {
+                    match &request_head.method {
+                        &pavex::http::Method::GET => route_2::handler(url_params).await,
+                        _ => {
+                            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                                    pavex::http::Method::GET,
+                                ])
+                                .into();
+                            route_4::handler(&allowed_methods).await
+                        }
+                    }
+                }
+            } else {
+                let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                        vec![],
+                    )
+                    .into();
+                route_4::handler(&allowed_methods).await
+            }
+        }
+        3u32 => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            route_8::handler(&allowed_methods).await
+        }
+        4u32 => {
+            match &request_head.method {
+                &pavex::http::Method::GET => route_7::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::GET,
+                        ])
+                        .into();
+                    route_8::handler(&allowed_methods).await
+                }
+            }
+        }
+        5u32 => {
+            let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter(
+                    vec![],
+                )
+                .into();
+            route_6::handler(&allowed_methods).await
+        }
+        6u32 => {
+            match &request_head.method {
+                &pavex::http::Method::POST => route_5::handler().await,
+                _ => {
+                    let allowed_methods: pavex::router::AllowedMethods = pavex::router::MethodAllowList::from_iter([
+                            pavex::http::Method::POST,
+                        ])
+                        .into();
+                    route_6::handler(&allowed_methods).await
+                }
+            }
+        }
+        i => unreachable!("Unknown route id: {}", i),
+    }
+}
+/// Build the paths of the named routes registered in your `Blueprint`.
+pub mod urls {
+    pub mod users {
+        /// Build the path of the `users.list` route—`/users`.
+        pub fn list() -> String {
+            let mut path = String::new();
+            path.push_str("/users");
+            path
+        }
+        /// Build the path of the `users.show` route—`/users/:id`.
+        pub fn show(params: &app::UserParams) -> String {
+            let mut path = String::new();
+            path.push_str("/users/");
+            pavex::router::push_path_param(&mut path, &params.id, false);
+            path
+        }
+    }
+}
+pub mod route_0 {
+    pub async fn handler(v0: &pavex::request::RequestHead) -> pavex::response::Response {
+        let v1 = pavex::request::query::QueryParams::extract(v0);
+        let v2 = match v1 {
+            Ok(ok) => ok,
+            Err(v2) => {
+                return {
+                    let v3 = pavex::request::query::errors::ExtractQueryParamsError::into_response(
+                        &v2,
+                    );
+                    <pavex::response::Response as pavex::response::IntoResponse>::into_response(
+                        v3,
+                    )
+                };
+            }
+        };
+        let v3 = app::list_users(&v2);
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v3)
+    }
+}
+pub mod route_1 {
+    pub async fn handler(
+        v0: pavex::request::body::RawIncomingBody,
+        v1: &pavex::request::RequestHead,
+    ) -> pavex::response::Response {
+        let v2 = <pavex::request::body::BodySizeLimit as std::default::Default>::default();
+        let v3 = pavex::request::body::BufferedBody::extract(v1, v0, v2).await;
+        let v4 = match v3 {
+            Ok(ok) => ok,
+            Err(v4) => {
+                return {
+                    let v5 = pavex::request::body::errors::ExtractBufferedBodyError::into_response(
+                        &v4,
+                    );
+                    <pavex::response::Response as pavex::response::IntoResponse>::into_response(
+                        v5,
+                    )
+                };
+            }
+        };
+        let v5 = pavex::request::body::JsonBody::extract(v1, &v4);
+        let v6 = match v5 {
+            Ok(ok) => ok,
+            Err(v6) => {
+                return {
+                    let v7 = pavex::request::body::errors::ExtractJsonBodyError::into_response(
+                        &v6,
+                    );
+                    <pavex::response::Response as pavex::response::IntoResponse>::into_response(
+                        v7,
+                    )
+                };
+            }
+        };
+        let v7 = app::create_user(&v6);
+        let v8 = match v7 {
+            Ok(ok) => ok,
+            Err(v8) => {
+                return {
+                    let v9 = app::create_user_error(&v8);
+                    <pavex::response::Response as pavex::response::IntoResponse>::into_response(
+                        v9,
+                    )
+                };
+            }
+        };
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v8)
+    }
+}
+pub mod route_2 {
+    pub async fn handler(
+        v0: pavex::request::path::RawPathParams<'_, '_>,
+    ) -> pavex::response::Response {
+        let v1 = pavex::request::path::PathParams::extract(v0);
+        let v2 = match v1 {
+            Ok(ok) => ok,
+            Err(v2) => {
+                return {
+                    let v3 = pavex::request::path::errors::ExtractPathParamsError::into_response(
+                        &v2,
+                    );
+                    <pavex::response::Response as pavex::response::IntoResponse>::into_response(
+                        v3,
+                    )
+                };
+            }
+        };
+        let v3 = app::get_user(&v2);
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v3)
+    }
+}
+pub mod route_3 {
+    pub async fn handler(
+        v0: &pavex::request::path::RawPathParams<'_, '_>,
+        v1: &pavex::router::Redirect,
+    ) -> pavex::response::Response {
+        let v2 = pavex::router::redirect(v1, v0);
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v2)
+    }
+}
+pub mod route_4 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
+pub mod route_5 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::update_admin_dashboard();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_6 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
+pub mod route_7 {
+    pub async fn handler() -> pavex::response::Response {
+        let v0 = app::dashboard();
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v0)
+    }
+}
+pub mod route_8 {
+    pub async fn handler(
+        v0: &pavex::router::AllowedMethods,
+    ) -> pavex::response::Response {
+        let v1 = pavex::router::default_fallback(v0).await;
+        <pavex::response::Response as pavex::response::IntoResponse>::into_response(v1)
+    }
+}
\ No newline at end of file
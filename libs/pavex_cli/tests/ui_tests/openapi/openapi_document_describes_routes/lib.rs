@@ -0,0 +1,118 @@
+use pavex::blueprint::{
+    router::{GET, POST},
+    Blueprint,
+};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::kit::ApiKit;
+use pavex::request::body::JsonBody;
+use pavex::request::path::PathParams;
+use pavex::request::query::QueryParams;
+use pavex::response::Response;
+
+#[PathParams]
+pub struct UserParams {
+    /// The identifier of the user.
+    pub id: u64,
+}
+
+/// How to paginate a list.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pagination {
+    /// The page to return, starting from 1.
+    pub page: Option<u32>,
+    #[serde(default)]
+    pub per_page: u32,
+    #[serde(skip)]
+    pub cursor: Option<String>,
+}
+
+/// A new user.
+#[derive(serde::Deserialize)]
+pub struct NewUser {
+    pub name: String,
+    #[serde(rename = "emailAddress")]
+    pub email: Option<String>,
+    pub roles: Vec<Role>,
+    pub profile: Profile,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    SuperAdmin,
+    #[serde(rename = "regular")]
+    Member,
+}
+
+/// Flattened fields can't be described: the schema is left empty.
+#[derive(serde::Deserialize)]
+pub struct Profile {
+    pub bio: String,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub struct CreateUserError;
+
+impl std::fmt::Display for CreateUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to create the user")
+    }
+}
+
+/// List all users.
+///
+/// Users are sorted by creation date.
+pub fn list_users(_pagination: &QueryParams<Pagination>) -> Response {
+    Response::ok()
+}
+
+/// Create a new user.
+pub fn create_user(_body: &JsonBody<NewUser>) -> Result<Response, CreateUserError> {
+    Ok(Response::created())
+}
+
+pub fn create_user_error(_e: &CreateUserError) -> Response {
+    Response::internal_server_error()
+}
+
+/// Retrieve a user.
+pub fn get_user(_params: &PathParams<UserParams>) -> Response {
+    Response::ok()
+}
+
+/// Retrieve the dashboard of a tenant.
+pub fn dashboard() -> Response {
+    Response::ok()
+}
+
+/// Update the admin dashboard.
+pub fn update_admin_dashboard() -> Response {
+    Response::ok()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    ApiKit::new().register(&mut bp);
+    bp.route(GET, "/users", f!(crate::list_users))
+        .name("users.list");
+    bp.route(POST, "/users", f!(crate::create_user))
+        .error_handler(f!(crate::create_user_error));
+    bp.route(GET, "/users/:id<u64>", f!(crate::get_user))
+        .name("users.show");
+    bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY);
+    bp.nest_at_domain(":tenant.example.com", {
+        let mut bp = Blueprint::new();
+        bp.route(GET, "/dashboard", f!(crate::dashboard));
+        bp
+    });
+    bp.nest_at_domain("admin.example.com", {
+        let mut bp = Blueprint::new();
+        bp.route(POST, "/dashboard", f!(crate::update_admin_dashboard));
+        bp
+    });
+    bp
+}
@@ -0,0 +1,26 @@
+use pavex::blueprint::{constructor::Lifecycle, router::GET, Blueprint};
+use pavex::f;
+use pavex::{request::path::PathParams, response::Response};
+
+#[PathParams]
+pub struct UserPathParams {
+    // The route constrains `id` to be a `u64`
+    pub id: u32,
+}
+
+pub fn get_user(params: PathParams<UserPathParams>) -> Response {
+    Response::ok().set_typed_body(format!("user {}", params.0.id))
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(
+        f!(pavex::request::path::PathParams::extract),
+        Lifecycle::RequestScoped,
+    )
+    .error_handler(f!(
+        pavex::request::path::errors::ExtractPathParamsError::into_response
+    ));
+    bp.route(GET, "/users/:id<u64>", f!(crate::get_user));
+    bp
+}
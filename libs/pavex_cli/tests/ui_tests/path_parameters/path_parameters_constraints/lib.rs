@@ -0,0 +1,35 @@
+use pavex::blueprint::{constructor::Lifecycle, router::GET, Blueprint};
+use pavex::f;
+use pavex::{request::path::PathParams, response::Response};
+
+#[PathParams]
+pub struct UserPathParams {
+    pub id: u64,
+}
+
+pub fn get_user(params: PathParams<UserPathParams>) -> Response {
+    Response::ok().set_typed_body(format!("user {}", params.0.id))
+}
+
+#[PathParams]
+pub struct PostPathParams {
+    pub slug: String,
+}
+
+pub fn get_post(params: PathParams<PostPathParams>) -> Response {
+    Response::ok().set_typed_body(format!("post {}", params.0.slug))
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(
+        f!(pavex::request::path::PathParams::extract),
+        Lifecycle::RequestScoped,
+    )
+    .error_handler(f!(
+        pavex::request::path::errors::ExtractPathParamsError::into_response
+    ));
+    bp.route(GET, "/users/:id<u64>", f!(crate::get_user));
+    bp.route(GET, "/posts/:slug<[a-z0-9-]+>", f!(crate::get_post));
+    bp
+}
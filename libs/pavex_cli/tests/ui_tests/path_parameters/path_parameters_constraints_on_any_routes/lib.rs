@@ -0,0 +1,36 @@
+use pavex::blueprint::{constructor::Lifecycle, router::ANY_WITH_EXTENSIONS, Blueprint};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::{request::path::PathParams, response::Response};
+
+#[PathParams]
+pub struct UserPathParams {
+    pub id: u64,
+}
+
+pub fn user(params: PathParams<UserPathParams>) -> Response {
+    Response::ok().set_typed_body(format!("user {}", params.0.id))
+}
+
+pub fn fallback() -> Response {
+    Response::not_found().set_typed_body("fallback")
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    bp.constructor(
+        f!(pavex::request::path::PathParams::extract),
+        Lifecycle::RequestScoped,
+    )
+    .error_handler(f!(
+        pavex::request::path::errors::ExtractPathParamsError::into_response
+    ));
+    bp.route(ANY_WITH_EXTENSIONS, "/users/:id<u64>", f!(crate::user));
+    bp.redirect(
+        "/profiles/:id<u64>",
+        "/users/:id",
+        StatusCode::MOVED_PERMANENTLY,
+    );
+    bp.fallback(f!(crate::fallback));
+    bp
+}
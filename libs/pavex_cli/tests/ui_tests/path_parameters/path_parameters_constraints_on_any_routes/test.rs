@@ -0,0 +1,85 @@
+use std::future::IntoFuture;
+use std::net::TcpListener;
+
+use application::{build_application_state, run};
+use pavex::http::StatusCode;
+
+async fn spawn_test_server() -> u16 {
+    static TELEMETRY: std::sync::Once = std::sync::Once::new();
+    TELEMETRY.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::FULL)
+            .init();
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to listen on a random port");
+    let port = listener
+        .local_addr()
+        .expect("Failed to get local address")
+        .port();
+    let incoming_stream: pavex::server::IncomingStream =
+        listener.try_into().expect("Failed to convert listener");
+    let server = pavex::server::Server::new().listen(incoming_stream);
+    let application_state = build_application_state().await;
+    tokio::task::spawn(run(server, application_state).into_future());
+    port
+}
+
+fn client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn values_that_satisfy_the_constraint_are_routed_to_the_handler() {
+    let port = spawn_test_server().await;
+    for method in [reqwest::Method::GET, reqwest::Method::DELETE] {
+        let response = client()
+            .request(method, &format!("http://localhost:{}/users/42", port))
+            .send()
+            .await
+            .expect("Failed to make request");
+        assert_eq!(StatusCode::OK.as_u16(), response.status().as_u16());
+        assert_eq!(response.text().await.unwrap(), "user 42");
+    }
+}
+
+#[tokio::test]
+async fn values_that_violate_the_constraint_are_routed_to_the_fallback() {
+    let port = spawn_test_server().await;
+    for method in [reqwest::Method::GET, reqwest::Method::DELETE] {
+        let response = client()
+            .request(method, &format!("http://localhost:{}/users/me", port))
+            .send()
+            .await
+            .expect("Failed to make request");
+        assert_eq!(StatusCode::NOT_FOUND.as_u16(), response.status().as_u16());
+        assert_eq!(response.text().await.unwrap(), "fallback");
+    }
+}
+
+#[tokio::test]
+async fn redirects_enforce_constraints() {
+    let port = spawn_test_server().await;
+    let response = client()
+        .get(&format!("http://localhost:{}/profiles/42", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(
+        StatusCode::MOVED_PERMANENTLY.as_u16(),
+        response.status().as_u16()
+    );
+    assert_eq!(response.headers()["location"], "/users/42");
+
+    let response = client()
+        .get(&format!("http://localhost:{}/profiles/me", port))
+        .send()
+        .await
+        .expect("Failed to make request");
+    assert_eq!(StatusCode::NOT_FOUND.as_u16(), response.status().as_u16());
+    assert_eq!(response.text().await.unwrap(), "fallback");
+}
@@ -0,0 +1,41 @@
+use pavex::blueprint::{router::GET, router::POST, Blueprint};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::kit::ApiKit;
+use pavex::request::body::JsonBody;
+use pavex::request::query::QueryParams;
+use serde::{Deserialize, Deserializer};
+
+pub struct Search {
+    pub q: String,
+}
+
+impl<'de> Deserialize<'de> for Search {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            q: String,
+        }
+
+        Raw::deserialize(deserializer).map(|raw| Search { q: raw.q })
+    }
+}
+
+pub fn search(params: QueryParams<Search>) -> StatusCode {
+    todo!()
+}
+
+pub fn create(body: JsonBody<Search>) -> StatusCode {
+    todo!()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    ApiKit::new().register(&mut bp);
+    bp.route(GET, "/search", f!(crate::search));
+    bp.route(POST, "/search", f!(crate::create));
+    bp
+}
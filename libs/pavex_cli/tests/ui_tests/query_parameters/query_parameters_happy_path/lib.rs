@@ -0,0 +1,105 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::request::query::QueryParams;
+use pavex::response::Response;
+use serde::{Deserialize, Deserializer};
+
+/// A value with a hand-written `Deserialize` implementation:
+/// Pavex can't verify it, so it trusts it.
+pub struct Tag(pub String);
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Tag)
+    }
+}
+
+pub type Ids = Vec<u32>;
+
+#[derive(Deserialize)]
+pub struct Filter {
+    pub status: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SearchParams {
+    pub q: String,
+    pub page: Option<u32>,
+    #[serde(default, rename = "id[]")]
+    pub ids: Ids,
+    #[serde(default)]
+    pub filter: Option<Filter>,
+    pub tag: Option<Tag>,
+    // A tuple can't be represented in a query string, but it's deserialized
+    // via a custom function here.
+    #[serde(default, deserialize_with = "parse_range")]
+    pub range: (u32, u32),
+}
+
+fn parse_range<'de, D>(deserializer: D) -> Result<(u32, u32), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    let (start, end) = s
+        .split_once("..")
+        .ok_or_else(|| serde::de::Error::custom("expected a range, e.g. `1..5`"))?;
+    let start = start.parse().map_err(serde::de::Error::custom)?;
+    let end = end.parse().map_err(serde::de::Error::custom)?;
+    Ok((start, end))
+}
+
+pub fn search(params: QueryParams<SearchParams>) -> Response {
+    let SearchParams {
+        q,
+        page,
+        ids,
+        filter,
+        tag,
+        range,
+    } = params.0;
+    Response::ok().set_typed_body(format!(
+        "q={q} page={page:?} ids={ids:?} status={:?} tag={:?} range={range:?}",
+        filter.and_then(|f| f.status),
+        tag.map(|t| t.0),
+    ))
+}
+
+/// A struct deserialized via another type: its own fields are irrelevant.
+#[derive(Deserialize)]
+#[serde(from = "RawPagination")]
+pub struct Pagination {
+    pub bounds: (u32, u32),
+}
+
+#[derive(Deserialize)]
+pub struct RawPagination {
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl From<RawPagination> for Pagination {
+    fn from(raw: RawPagination) -> Self {
+        Self {
+            bounds: (raw.offset, raw.offset + raw.limit),
+        }
+    }
+}
+
+pub type PaginationAlias = Pagination;
+
+pub fn paginate(params: QueryParams<PaginationAlias>) -> Response {
+    let (start, end) = params.0.bounds;
+    Response::ok().set_typed_body(format!("{start}..{end}"))
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    QueryParams::register(&mut bp);
+    bp.route(GET, "/search", f!(crate::search));
+    bp.route(GET, "/paginate", f!(crate::paginate));
+    bp
+}
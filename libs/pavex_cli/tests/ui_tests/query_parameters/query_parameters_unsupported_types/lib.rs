@@ -0,0 +1,88 @@
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::request::query::QueryParams;
+
+pub fn primitive(params: QueryParams<u32>) -> StatusCode {
+    todo!()
+}
+
+pub fn tuple(params: QueryParams<(u32, u32)>) -> StatusCode {
+    todo!()
+}
+
+#[derive(serde::Deserialize)]
+pub enum MyEnum {
+    A,
+    B,
+}
+
+pub fn enum_(params: QueryParams<MyEnum>) -> StatusCode {
+    todo!()
+}
+
+#[derive(serde::Deserialize)]
+pub struct TupleField {
+    pub range: (u32, u32),
+}
+
+pub fn tuple_field(params: QueryParams<TupleField>) -> StatusCode {
+    todo!()
+}
+
+#[derive(serde::Deserialize)]
+pub struct NestedSequence {
+    pub matrix: Vec<Vec<u32>>,
+}
+
+pub fn nested_sequence(params: QueryParams<NestedSequence>) -> StatusCode {
+    todo!()
+}
+
+#[derive(serde::Deserialize)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(serde::Deserialize)]
+pub struct SequenceOfStructs {
+    pub points: Vec<Point>,
+}
+
+pub fn sequence_of_structs(params: QueryParams<SequenceOfStructs>) -> StatusCode {
+    todo!()
+}
+
+#[derive(serde::Deserialize)]
+pub enum Shape {
+    Circle { radius: u32 },
+    Square(u32),
+}
+
+#[derive(serde::Deserialize)]
+pub struct Filter {
+    pub shape: Shape,
+}
+
+#[derive(serde::Deserialize)]
+pub struct NestedDataCarryingEnum {
+    pub filter: Filter,
+}
+
+pub fn nested_data_carrying_enum(params: QueryParams<NestedDataCarryingEnum>) -> StatusCode {
+    todo!()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    QueryParams::register(&mut bp);
+    bp.route(GET, "/a", f!(crate::primitive));
+    bp.route(GET, "/b", f!(crate::tuple));
+    bp.route(GET, "/c", f!(crate::enum_));
+    bp.route(GET, "/d", f!(crate::tuple_field));
+    bp.route(GET, "/e", f!(crate::nested_sequence));
+    bp.route(GET, "/f", f!(crate::sequence_of_structs));
+    bp.route(GET, "/g", f!(crate::nested_data_carrying_enum));
+    bp
+}
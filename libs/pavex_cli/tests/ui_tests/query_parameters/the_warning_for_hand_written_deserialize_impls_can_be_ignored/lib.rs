@@ -0,0 +1,42 @@
+use pavex::blueprint::linter::Lint;
+use pavex::blueprint::{router::GET, Blueprint};
+use pavex::f;
+use pavex::http::StatusCode;
+use pavex::kit::ApiKit;
+use pavex::request::query::QueryParams;
+use serde::{Deserialize, Deserializer};
+
+pub struct Search {
+    pub q: String,
+}
+
+impl<'de> Deserialize<'de> for Search {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            q: String,
+        }
+
+        Raw::deserialize(deserializer).map(|raw| Search { q: raw.q })
+    }
+}
+
+pub fn search(params: QueryParams<Search>) -> StatusCode {
+    todo!()
+}
+
+pub fn blueprint() -> Blueprint {
+    let mut bp = Blueprint::new();
+    let mut kit = ApiKit::new();
+    kit.query_params = Some(
+        QueryParams::default_constructor()
+            .ignore(Lint::Unused)
+            .ignore(Lint::CustomDeserialize),
+    );
+    kit.register(&mut bp);
+    bp.route(GET, "/search", f!(crate::search));
+    bp
+}
@@ -11,6 +11,7 @@ use pavex::blueprint::Blueprint;
 pub struct GenerateBuilder {
     cmd: Command,
     diagnostics_path: Option<PathBuf>,
+    openapi_path: Option<PathBuf>,
     blueprint: Blueprint,
     output_directory: PathBuf,
 }
@@ -22,6 +23,7 @@ impl GenerateBuilder {
     pub(crate) fn new(cmd: Command, blueprint: Blueprint, output_directory: PathBuf) -> Self {
         Self {
             diagnostics_path: None,
+            openapi_path: None,
             blueprint,
             cmd,
             output_directory,
@@ -85,6 +87,9 @@ impl GenerateBuilder {
         if let Some(path) = self.diagnostics_path {
             self.cmd.arg("--diagnostics").arg(path);
         }
+        if let Some(path) = self.openapi_path {
+            self.cmd.arg("--openapi").arg(path);
+        }
         Ok(self.cmd)
     }
 
@@ -98,6 +103,17 @@ impl GenerateBuilder {
         self.diagnostics_path = Some(path);
         self
     }
+
+    /// Set the path to the file that Pavex will use to save an OpenAPI document
+    /// describing the routes of the application.
+    ///
+    /// The document is serialized as JSON.
+    ///
+    /// If this is not set, Pavex will not generate an OpenAPI document.
+    pub fn openapi_path(mut self, path: PathBuf) -> Self {
+        self.openapi_path = Some(path);
+        self
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -149,6 +149,14 @@ struct TestExpectations {
     codegen: ExpectedOutcome,
     #[serde(default = "ExpectedOutcome::pass")]
     lints: ExpectedOutcome,
+    /// If set to `true`, Pavex will also be asked to generate an OpenAPI document for the
+    /// application and the test runner will compare it with the `openapi.json` snapshot.
+    #[serde(default)]
+    openapi: bool,
+    /// If set to `true`, the test runner will list the routes of the application via
+    /// `pavexc routes` and compare the output with the `routes.txt` snapshot.
+    #[serde(default)]
+    routes: bool,
 }
 
 impl Default for TestExpectations {
@@ -156,6 +164,8 @@ impl Default for TestExpectations {
         Self {
             codegen: ExpectedOutcome::Pass,
             lints: ExpectedOutcome::Pass,
+            openapi: false,
+            routes: false,
         }
     }
 }
@@ -409,6 +419,11 @@ impl TestData {
             toml::to_string(&cargo_config)?.as_bytes(),
         )?;
 
+        let openapi_path = if test_config.expectations.openapi {
+            r#".openapi_path("openapi.json".into())"#
+        } else {
+            ""
+        };
         let main_rs = format!(
             r##"use app::blueprint;
 use pavex_cli_client::{{Client, config::Color}};
@@ -420,6 +435,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {{
         .pavex_cli_path(r#"{}"#.into())
         .generate(blueprint(), "generated_app".into())
         .diagnostics_path("diagnostics.dot".into())
+        {}
         .execute();
     match outcome {{
         Ok(_) => {{}},
@@ -432,7 +448,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {{
     Ok(())
 }}
 "##,
-            cli.to_str().unwrap()
+            cli.to_str().unwrap(),
+            openapi_path
         );
         persist_if_changed(&source_directory.join("main.rs"), main_rs.as_bytes())?;
         Ok(if has_tests {
@@ -618,6 +635,29 @@ fn _run_test(
     .unwrap();
     let codegen_outcome = app_code_snapshot.verify(&actual_app_code);
 
+    let openapi_outcome = if test_config.expectations.openapi {
+        let openapi_snapshot = SnapshotTest::new(expectations_directory.join("openapi.json"));
+        let actual_openapi =
+            fs_err::read_to_string(test.test_runtime_directory().join("openapi.json"))?;
+        openapi_snapshot.verify(&actual_openapi)
+    } else {
+        Ok(())
+    };
+
+    let routes_outcome = if test_config.expectations.routes {
+        let output = std::process::Command::new(pavexc_cli)
+            .arg("routes")
+            .arg("-b")
+            .arg("generated_app/blueprint.ron")
+            .current_dir(test.test_runtime_directory())
+            .output()
+            .context("Failed to list the routes of the application")?;
+        let routes_snapshot = SnapshotTest::new(expectations_directory.join("routes.txt"));
+        routes_snapshot.verify(&String::from_utf8_lossy(&output.stdout))
+    } else {
+        Ok(())
+    };
+
     // Check that the generated code compiles
     let output = std::process::Command::new("cargo")
         .env("RUSTFLAGS", "-Awarnings")
@@ -652,6 +692,24 @@ fn _run_test(
         });
     }
 
+    if openapi_outcome.is_err() {
+        return Ok(TestOutcome {
+            outcome: Err("The generated OpenAPI document doesn't match what we expected".into()),
+            codegen_output,
+            compilation_output: None,
+            test_output: None,
+        });
+    }
+
+    if routes_outcome.is_err() {
+        return Ok(TestOutcome {
+            outcome: Err("The routes of the application don't match what we expected".into()),
+            codegen_output,
+            compilation_output: None,
+            test_output: None,
+        });
+    }
+
     let compilation_output = compilation_output?;
     if !output.status.success() {
         return Ok(TestOutcome {
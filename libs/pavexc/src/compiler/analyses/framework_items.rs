@@ -30,7 +30,7 @@ impl FrameworkItemDb {
     /// (e.g. if we want to reuse the DI machinery for more than a single web framework).
     #[tracing::instrument("Build framework items database", skip_all)]
     pub fn new(package_graph: &PackageGraph, krate_collection: &CrateCollection) -> Self {
-        let capacity = 2;
+        let capacity = 7;
         let mut items = BiHashMap::with_capacity(capacity);
         let mut id2metadata = HashMap::with_capacity(capacity);
 
@@ -105,6 +105,45 @@ impl FrameworkItemDb {
                 binding: format_ident!("allowed_methods"),
             },
         );
+        let request_context = process_framework_path(
+            "pavex::request::RequestContext",
+            package_graph,
+            krate_collection,
+        );
+        items.insert(request_context, Self::request_context_id());
+        id2metadata.insert(
+            Self::request_context_id(),
+            FrameworkItemMetadata {
+                lifecycle: Lifecycle::RequestScoped,
+                cloning_strategy: CloningStrategy::CloneIfNecessary,
+                binding: format_ident!("request_context"),
+            },
+        );
+        let connection_info = process_framework_path(
+            "pavex::connection::ConnectionInfo",
+            package_graph,
+            krate_collection,
+        );
+        items.insert(connection_info, Self::connection_info_id());
+        id2metadata.insert(
+            Self::connection_info_id(),
+            FrameworkItemMetadata {
+                lifecycle: Lifecycle::RequestScoped,
+                cloning_strategy: CloningStrategy::CloneIfNecessary,
+                binding: format_ident!("connection_info"),
+            },
+        );
+        let redirect =
+            process_framework_path("pavex::router::Redirect", package_graph, krate_collection);
+        items.insert(redirect, Self::redirect_id());
+        id2metadata.insert(
+            Self::redirect_id(),
+            FrameworkItemMetadata {
+                lifecycle: Lifecycle::RequestScoped,
+                cloning_strategy: CloningStrategy::CloneIfNecessary,
+                binding: format_ident!("redirect_target"),
+            },
+        );
         Self { items, id2metadata }
     }
 
@@ -113,11 +152,26 @@ impl FrameworkItemDb {
         3
     }
 
-    /// Return the id for the `MatchedPathPattern` type.
+    /// Return the id for the `AllowedMethods` type.
     pub(crate) fn allowed_methods_id() -> FrameworkItemId {
         4
     }
 
+    /// Return the id for the `RequestContext` type.
+    pub(crate) fn request_context_id() -> FrameworkItemId {
+        5
+    }
+
+    /// Return the id for the `ConnectionInfo` type.
+    pub(crate) fn connection_info_id() -> FrameworkItemId {
+        6
+    }
+
+    /// Return the id for the `Redirect` type.
+    pub(crate) fn redirect_id() -> FrameworkItemId {
+        7
+    }
+
     /// Return the [`Lifecycle`] associated with a framework item.
     pub(crate) fn lifecycle(&self, item_id: FrameworkItemId) -> Lifecycle {
         self.id2metadata[&item_id].lifecycle
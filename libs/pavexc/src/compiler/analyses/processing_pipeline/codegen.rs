@@ -277,6 +277,27 @@ impl CodegenedRequestHandlerPipeline {
         self.needs_input_type(allowed_methods_type)
     }
 
+    pub(crate) fn needs_request_context(&self, framework_item_db: &FrameworkItemDb) -> bool {
+        let request_context_type = framework_item_db
+            .get_type(FrameworkItemDb::request_context_id())
+            .unwrap();
+        self.needs_input_type(request_context_type)
+    }
+
+    pub(crate) fn needs_connection_info(&self, framework_item_db: &FrameworkItemDb) -> bool {
+        let connection_info_type = framework_item_db
+            .get_type(FrameworkItemDb::connection_info_id())
+            .unwrap();
+        self.needs_input_type(connection_info_type)
+    }
+
+    pub(crate) fn needs_redirect(&self, framework_item_db: &FrameworkItemDb) -> bool {
+        let redirect_type = framework_item_db
+            .get_type(FrameworkItemDb::redirect_id())
+            .unwrap();
+        self.needs_input_type(redirect_type)
+    }
+
     pub(crate) fn needs_matched_route(&self, framework_item_db: &FrameworkItemDb) -> bool {
         let matched_route_type = framework_item_db
             .get_type(FrameworkItemDb::matched_route_template_id())
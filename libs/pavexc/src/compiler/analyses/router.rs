@@ -1,8 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use ahash::HashMap;
+use pavex_bp_schema::{Redirect, RouterSettings, VersionSelector};
 
 use crate::compiler::analyses::components::ComponentId;
+use crate::compiler::analyses::user_components::param_constraints::ParamConstraint;
 use crate::compiler::analyses::user_components::UserComponentId;
 
 #[derive(Debug)]
@@ -10,6 +12,16 @@ pub(crate) struct Router {
     pub(crate) route_path2sub_router: BTreeMap<String, LeafRouter>,
     /// The fallback to use if no route matches the incoming request.
     pub(crate) root_fallback_id: ComponentId,
+    /// The settings specified on the root blueprint.
+    pub(crate) settings: RouterSettings,
+    /// Additional paths that must be routed to a registered path, according to the
+    /// trailing slash policy—e.g. `/users/` => `/users`.
+    pub(crate) trailing_slash_aliases: BTreeMap<String, String>,
+    /// The request handler of each named route, keyed by route name.
+    pub(crate) route_name2handler_id: BTreeMap<String, ComponentId>,
+    /// The rules used to route requests to a version of the API regardless of their path,
+    /// paired with the path prefix of the version—e.g. `/v2`.
+    pub(crate) version_selectors: Vec<(String, VersionSelector)>,
 }
 
 /// A router to dispatch a request to a handler based on its method, after having matched its path.
@@ -19,14 +31,25 @@ pub(crate) struct LeafRouter {
     //     the set of methods that a handler can handle.
     pub(crate) handler_id2methods: BTreeMap<ComponentId, BTreeSet<String>>,
     pub(crate) fallback_id: ComponentId,
+    /// The fallback to use if the values of the route parameters don't satisfy their
+    /// constraints.
+    pub(crate) unmatched_fallback_id: ComponentId,
+    /// The constraints on the values of the route parameters.
+    pub(crate) param_constraints: BTreeMap<String, ParamConstraint>,
+    /// The target of the redirect, if the route was registered via `Blueprint::redirect`.
+    pub(crate) redirect: Option<Redirect>,
 }
 
 impl LeafRouter {
-    /// Return the set of [`ComponentId`]s that can handle the given route, including the fallback.
+    /// Return the set of [`ComponentId`]s that can handle the given route, including the fallbacks.
     pub(crate) fn handler_ids(&self) -> impl Iterator<Item = &ComponentId> {
         self.handler_id2methods
             .keys()
             .chain(std::iter::once(&self.fallback_id))
+            .chain(
+                (self.unmatched_fallback_id != self.fallback_id)
+                    .then_some(&self.unmatched_fallback_id),
+            )
     }
 }
 
@@ -51,19 +74,37 @@ impl Router {
                     })
                     .collect();
                 let fallback_id = user_component_id2component_id[&leaf_router.fallback_id];
+                let unmatched_fallback_id =
+                    user_component_id2component_id[&leaf_router.unmatched_fallback_id];
                 (
                     route_path,
                     LeafRouter {
                         handler_id2methods,
                         fallback_id,
+                        unmatched_fallback_id,
+                        param_constraints: leaf_router.param_constraints,
+                        redirect: leaf_router.redirect,
                     },
                 )
             })
             .collect();
         let root_fallback_id = user_component_id2component_id[&router.root_fallback_id];
+        let route_name2handler_id = router
+            .route_name2handler_id
+            .into_iter()
+            .filter_map(|(name, user_component_id)| {
+                user_component_id2component_id
+                    .get(&user_component_id)
+                    .map(|&component_id| (name, component_id))
+            })
+            .collect();
         Self {
             route_path2sub_router,
             root_fallback_id,
+            settings: router.settings,
+            trailing_slash_aliases: router.trailing_slash_aliases,
+            route_name2handler_id,
+            version_selectors: router.version_selectors,
         }
     }
 }
@@ -3,10 +3,12 @@ use crate::compiler::analyses::components::HydratedComponent;
 use crate::compiler::analyses::components::{ComponentDb, ComponentId};
 use crate::compiler::analyses::computations::ComputationDb;
 use crate::compiler::analyses::processing_pipeline::RequestHandlerPipeline;
+use crate::compiler::analyses::user_components::{UserComponent, UserComponentId};
 use crate::compiler::computation::Computation;
 use crate::compiler::utils::get_ok_variant;
 use crate::diagnostic;
 use crate::diagnostic::{CompilerDiagnostic, LocationExt, SourceSpanExt};
+use crate::language::ResolvedType;
 use guppy::graph::PackageGraph;
 use indexmap::IndexSet;
 use miette::Severity;
@@ -14,6 +16,9 @@ use pavex_bp_schema::{Lint, LintSetting};
 
 /// Emit a warning for each user-registered constructor that hasn't
 /// been used in the code-generated pipelines.
+///
+/// Unused per-route constructors are reported as errors: they are registered
+/// to affect a specific route, therefore it's a mistake if they don't.
 pub(crate) fn detect_unused<'a, I>(
     pipelines: I,
     application_state_call_graph: &ApplicationStateCallGraph,
@@ -61,6 +66,23 @@ pub(crate) fn detect_unused<'a, I>(
             continue;
         }
 
+        let overridden_route_id = component_db.user_component_id(id).and_then(|user_id| {
+            component_db
+                .user_component_db()
+                .get_overridden_route_id(user_id)
+        });
+        if let Some(route_id) = overridden_route_id {
+            emit_unused_override_error(
+                id,
+                route_id,
+                component_db,
+                computation_db,
+                diagnostics,
+                package_graph,
+            );
+            continue;
+        }
+
         if let Some(overrides) = component_db.lints(id) {
             if overrides.get(&Lint::Unused) == Some(&LintSetting::Ignore) {
                 // No warning!
@@ -72,6 +94,75 @@ pub(crate) fn detect_unused<'a, I>(
     }
 }
 
+fn emit_unused_override_error(
+    constructor_id: ComponentId,
+    route_id: UserComponentId,
+    component_db: &ComponentDb,
+    computation_db: &ComputationDb,
+    diagnostics: &mut Vec<miette::Error>,
+    package_graph: &PackageGraph,
+) {
+    let Some(user_component_id) = component_db.user_component_id(constructor_id) else {
+        return;
+    };
+    let location = component_db
+        .user_component_db()
+        .get_location(user_component_id);
+    let source = match location.source_file(package_graph) {
+        Ok(s) => {
+            let span = diagnostic::get_f_macro_invocation_span(&s, location)
+                .map(|s| s.labeled("The per-route constructor was registered here".into()));
+            Some((s, span))
+        }
+        Err(e) => {
+            diagnostics.push(e.into());
+            None
+        }
+    };
+    let output_type = constructed_type(constructor_id, component_db, computation_db);
+    let UserComponent::RequestHandler { router_key, .. } =
+        &component_db.user_component_db()[route_id]
+    else {
+        unreachable!()
+    };
+    let error = anyhow::anyhow!(
+        "You registered a constructor for `{output_type:?}` as an override for `{}`, \
+        but no component in the request processing pipeline for that route asks for \
+        `{output_type:?}` to be injected as one of its inputs.",
+        router_key.diagnostic_repr()
+    );
+    let builder = match source {
+        None => CompilerDiagnostic::builder_without_source(error),
+        Some((source, labeled_span)) => {
+            CompilerDiagnostic::builder(source, error).optional_label(labeled_span)
+        }
+    }
+    .help(
+        "Remove the constructor or check that it builds the type you meant to override."
+            .to_string(),
+    );
+    diagnostics.push(builder.build().into())
+}
+
+/// The type built by a constructor, with the `Result` unwrapped if the constructor is fallible.
+fn constructed_type(
+    constructor_id: ComponentId,
+    component_db: &ComponentDb,
+    computation_db: &ComputationDb,
+) -> ResolvedType {
+    let HydratedComponent::Constructor(constructor) =
+        component_db.hydrated_component(constructor_id, computation_db)
+    else {
+        unreachable!()
+    };
+    let output_type = constructor.output_type();
+    if output_type.is_result() {
+        get_ok_variant(output_type).to_owned()
+    } else {
+        output_type.to_owned()
+    }
+}
+
 fn emit_unused_warning(
     constructor_id: ComponentId,
     component_db: &ComponentDb,
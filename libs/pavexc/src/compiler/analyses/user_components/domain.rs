@@ -0,0 +1,161 @@
+//! Routing keys for blueprints nested under a domain via `Blueprint::nest_at_domain`.
+//!
+//! Pavex routes on a single `matchit` router. Routes that are only available on a specific
+//! domain are registered using a **routing key** that encodes both the domain and the path:
+//! the labels of the domain, in reverse order, followed by an `@` segment and the route path.
+//!
+//! E.g. `GET /users` nested under `:tenant.example.com` is registered as
+//! `~com/example/:host.tenant/@/users`.
+//!
+//! The leading `~` guarantees that routing keys never conflict with plain route paths, since
+//! the latter must start with a `/`.
+//! Domain captures are prefixed with `host.` to keep them apart from path parameters.
+//!
+//! The encoding must be kept in sync with `pavex::router::host_routing_key`, which computes
+//! the routing key for incoming requests at runtime.
+use std::borrow::Cow;
+
+/// The prefix of all routing keys for domain-specific routes.
+const DOMAIN_MARKER: &str = "~";
+/// The segment that separates the domain from the route path in a routing key.
+const PATH_MARKER: &str = "/@";
+/// The prefix added to the name of domain captures.
+pub(crate) const HOST_PARAMETER_PREFIX: &str = "host.";
+
+/// Check that `domain` is a valid domain pattern for `Blueprint::nest_at_domain`.
+///
+/// A domain pattern is a sequence of dot-separated labels.
+/// Each label is either a literal (lowercase ASCII letters, digits and hyphens) or a
+/// named capture (e.g. `:tenant`) that matches exactly one label.
+pub(crate) fn validate_domain(domain: &str) -> Result<(), String> {
+    if domain.is_empty() {
+        return Err("The domain cannot be empty.".into());
+    }
+    for label in domain.split('.') {
+        if label.is_empty() {
+            return Err(format!(
+                "`{domain}` contains an empty label. Labels must be separated by a single `.`."
+            ));
+        }
+        if let Some(name) = label.strip_prefix(':') {
+            if name.is_empty() {
+                return Err(format!(
+                    "`{domain}` contains an anonymous capture. All domain captures must be named, e.g. `:tenant`."
+                ));
+            }
+            if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!(
+                    "`{name}` is not a valid name for a domain capture. \
+                    Use ASCII letters, digits and underscores."
+                ));
+            }
+            continue;
+        }
+        if !label
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(format!(
+                "`{label}` is not a valid domain label. \
+                Use lowercase ASCII letters, digits and hyphens, or a named capture (e.g. `:tenant`)."
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The prefix shared by the routing keys of all routes nested under `domain`.
+///
+/// `domain` is assumed to be valid—see [`validate_domain`].
+pub(crate) fn domain_routing_prefix(domain: &str) -> String {
+    let labels = domain
+        .split('.')
+        .rev()
+        .map(|label| match label.strip_prefix(':') {
+            Some(name) => Cow::Owned(format!(":{HOST_PARAMETER_PREFIX}{name}")),
+            None => Cow::Borrowed(label),
+        })
+        .collect::<Vec<_>>();
+    format!("{DOMAIN_MARKER}{}{PATH_MARKER}", labels.join("/"))
+}
+
+/// Split a routing key into its domain pattern (if any) and its path template.
+pub(crate) fn split_routing_key(key: &str) -> (Option<String>, &str) {
+    let Some(rest) = key.strip_prefix(DOMAIN_MARKER) else {
+        return (None, key);
+    };
+    let Some((labels, path)) = rest.split_once(PATH_MARKER) else {
+        return (None, key);
+    };
+    let domain = labels
+        .split('/')
+        .rev()
+        .map(|label| match label.strip_prefix(':') {
+            Some(name) => {
+                let name = name.strip_prefix(HOST_PARAMETER_PREFIX).unwrap_or(name);
+                Cow::Owned(format!(":{name}"))
+            }
+            None => Cow::Borrowed(label),
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+    (Some(domain), path)
+}
+
+/// The path template of a routing key—i.e. the routing key without its domain, if any.
+pub(crate) fn path_template(key: &str) -> &str {
+    split_routing_key(key).1
+}
+
+/// Whether `key` is a domain-specific routing key that ends with a catch-all—e.g. the
+/// fallback key `~com/example/admin/@*catch_all`.
+///
+/// `matchit` rejects a catch-all if the route doesn't start with a `/` and it shares no
+/// prefix with the routes already in the router.
+/// Keys of this kind must therefore be inserted after all other routing keys.
+pub(crate) fn is_domain_catch_all(key: &str) -> bool {
+    key.starts_with(DOMAIN_MARKER) && key.contains('*')
+}
+
+/// A human-readable representation of a routing key, suitable for diagnostics.
+///
+/// E.g. `~com/example/:host.tenant/@/users` becomes `:tenant.example.com/users`.
+pub(crate) fn display_routing_key(key: &str) -> Cow<'_, str> {
+    match split_routing_key(key) {
+        (Some(domain), path) => Cow::Owned(format!("{domain}{path}")),
+        (None, path) => Cow::Borrowed(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routing_keys_roundtrip() {
+        let prefix = domain_routing_prefix(":tenant.example.com");
+        assert_eq!(prefix, "~com/example/:host.tenant/@");
+        let key = format!("{prefix}/users/:id");
+        assert_eq!(
+            split_routing_key(&key),
+            (Some(":tenant.example.com".to_string()), "/users/:id")
+        );
+        assert_eq!(display_routing_key(&key), ":tenant.example.com/users/:id");
+        assert_eq!(path_template("/users/:id"), "/users/:id");
+        assert!(is_domain_catch_all(&format!("{prefix}*catch_all")));
+        assert!(!is_domain_catch_all(&key));
+        assert!(!is_domain_catch_all("/files/*path"));
+    }
+
+    #[test]
+    fn invalid_domains_are_rejected() {
+        assert!(validate_domain("admin.example.com").is_ok());
+        assert!(validate_domain(":tenant.example.com").is_ok());
+        assert!(validate_domain("").is_err());
+        assert!(validate_domain("admin..example.com").is_err());
+        assert!(validate_domain("Admin.example.com").is_err());
+        assert!(validate_domain("admin.example.com:8080").is_err());
+        assert!(validate_domain(":.example.com").is_err());
+        assert!(validate_domain("example.com/users").is_err());
+    }
+}
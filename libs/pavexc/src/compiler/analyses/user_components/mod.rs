@@ -3,9 +3,12 @@ pub use raw_db::{UserComponent, UserComponentId};
 pub(crate) use router::Router;
 pub use scope_graph::{ScopeGraph, ScopeId};
 
+pub(crate) mod domain;
+pub(crate) mod param_constraints;
 mod processed_db;
 mod raw_db;
 mod resolved_paths;
 mod router;
 mod router_key;
 mod scope_graph;
+mod versions;
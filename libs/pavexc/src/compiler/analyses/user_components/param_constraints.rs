@@ -0,0 +1,166 @@
+//! Constraints on the values of route parameters, e.g. `/users/:id<u64>` or `/posts/:slug<[a-z-]+>`.
+//!
+//! Constraints are not understood by `matchit`: they are stripped from the route path before
+//! it's added to the router and they are checked, instead, by the generated code after a
+//! route has been matched.
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The types that can be used as a constraint on the value of a route parameter.
+pub(crate) const SUPPORTED_TYPES: [&str; 16] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+    "f64", "bool", "char",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum ParamConstraint {
+    /// The (percent-decoded) value must parse as the specified primitive type—e.g. `u64`.
+    Type(String),
+    /// The (percent-decoded) value must match the specified regular expression, in its entirety.
+    Pattern(String),
+}
+
+impl fmt::Display for ParamConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamConstraint::Type(ty) => write!(f, "<{ty}>"),
+            ParamConstraint::Pattern(pattern) => write!(f, "<{pattern}>"),
+        }
+    }
+}
+
+/// Extract the parameter constraints from `path`.
+///
+/// It returns the path without constraints—i.e. the path that can be added to
+/// a `matchit` router—alongside the constraints for each parameter.
+pub(crate) fn strip_constraints(
+    path: &str,
+) -> Result<(String, BTreeMap<String, ParamConstraint>), String> {
+    let mut stripped = String::with_capacity(path.len());
+    let mut constraints = BTreeMap::new();
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        stripped.push(c);
+        if c != ':' && c != '*' {
+            continue;
+        }
+        let mut name = String::new();
+        while let Some(&c) = chars.peek() {
+            if c == '/' || c == '<' {
+                break;
+            }
+            name.push(c);
+            stripped.push(c);
+            chars.next();
+        }
+        if chars.peek() != Some(&'<') {
+            continue;
+        }
+        chars.next();
+        // Regular expressions may contain `<` and `>` (e.g. named groups), so we must keep
+        // track of the nesting level to find the end of the constraint.
+        let mut depth = 1;
+        let mut constraint = String::new();
+        for c in chars.by_ref() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+            constraint.push(c);
+        }
+        if depth != 0 {
+            return Err(format!(
+                "The constraint on `{name}` is never closed: add a `>` at the end of it."
+            ));
+        }
+        if constraint.is_empty() {
+            return Err(format!("The constraint on `{name}` is empty."));
+        }
+        if let Some(next) = chars.peek() {
+            if *next != '/' {
+                return Err(format!(
+                    "The constraint on `{name}` must be followed by a `/` or by the end of the path."
+                ));
+            }
+        }
+        let constraint = if SUPPORTED_TYPES.contains(&constraint.as_str()) {
+            ParamConstraint::Type(constraint)
+        } else {
+            if let Err(e) = regex::Regex::new(&format!("^(?:{constraint})$")) {
+                return Err(format!(
+                    "`{constraint}`, the constraint on `{name}`, is neither a supported type \
+                    nor a valid regular expression.\n{e}"
+                ));
+            }
+            ParamConstraint::Pattern(constraint)
+        };
+        if constraints.insert(name.clone(), constraint).is_some() {
+            return Err(format!("`{name}` is constrained more than once."));
+        }
+    }
+    Ok((stripped, constraints))
+}
+
+/// The inverse of [`strip_constraints`]: add the constraints back to a stripped path,
+/// to show it to the user as it was registered.
+pub(crate) fn restore_constraints(
+    path: &str,
+    constraints: &BTreeMap<String, ParamConstraint>,
+) -> String {
+    path.split('/')
+        .map(|segment| {
+            let constraint = segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*'))
+                .and_then(|name| constraints.get(name));
+            match constraint {
+                Some(constraint) => format!("{segment}{constraint}"),
+                None => segment.to_owned(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constraints_are_stripped() {
+        let (path, constraints) =
+            strip_constraints("/users/:id<u64>/posts/:slug<[a-z-]{2,}>/*rest").unwrap();
+        assert_eq!(path, "/users/:id/posts/:slug/*rest");
+        assert_eq!(
+            constraints,
+            BTreeMap::from([
+                ("id".to_string(), ParamConstraint::Type("u64".into())),
+                (
+                    "slug".to_string(),
+                    ParamConstraint::Pattern("[a-z-]{2,}".into())
+                ),
+            ])
+        );
+
+        assert_eq!(
+            restore_constraints(&path, &constraints),
+            "/users/:id<u64>/posts/:slug<[a-z-]{2,}>/*rest"
+        );
+
+        let (path, constraints) = strip_constraints("/users/:id").unwrap();
+        assert_eq!(path, "/users/:id");
+        assert!(constraints.is_empty());
+    }
+
+    #[test]
+    fn invalid_constraints_are_rejected() {
+        assert!(strip_constraints("/users/:id<u64").is_err());
+        assert!(strip_constraints("/users/:id<>").is_err());
+        assert!(strip_constraints("/users/:id<[a-z>").is_err());
+        assert!(strip_constraints("/users/:id<u64>.json").is_err());
+    }
+}
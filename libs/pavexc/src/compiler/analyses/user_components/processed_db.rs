@@ -64,6 +64,11 @@ pub struct UserComponentDb {
     ///
     /// Invariants: there is an entry for every single request handler.
     handler_id2error_observer_ids: HashMap<UserComponentId, Vec<UserComponentId>>,
+    /// Associate each constructor registered as a per-route override with the request
+    /// handler of the route it applies to.
+    ///
+    /// Invariants: there is an entry for every single per-route constructor.
+    constructor_id2route_id: HashMap<UserComponentId, UserComponentId>,
     scope_graph: ScopeGraph,
 }
 
@@ -92,7 +97,13 @@ impl UserComponentDb {
 
         let (raw_db, scope_graph) = RawUserComponentDb::build(bp, package_graph, diagnostics);
         let resolved_path_db = ResolvedPathDb::build(&raw_db, package_graph, diagnostics);
-        let router = Router::new(&raw_db, &scope_graph, package_graph, diagnostics)?;
+        let router = Router::new(
+            &raw_db,
+            &scope_graph,
+            bp.router_settings.clone(),
+            package_graph,
+            diagnostics,
+        )?;
         exit_on_errors!(diagnostics);
 
         precompute_crate_docs(krate_collection, &resolved_path_db, diagnostics);
@@ -118,6 +129,14 @@ impl UserComponentDb {
             handler_id2middleware_ids,
             handler_id2error_observer_ids,
             fallback_id2path_prefix: _,
+            auto_options_paths: _,
+            handler_id2param_constraints: _,
+            route_name2handler_id: _,
+            constructor_id2route_id,
+            handler_id2version: _,
+            inherited_route_id2scope_id: _,
+            version_selectors: _,
+            handler_id2redirect: _,
         } = raw_db;
 
         Ok((
@@ -130,6 +149,7 @@ impl UserComponentDb {
                 id2lifecycle,
                 handler_id2middleware_ids,
                 handler_id2error_observer_ids,
+                constructor_id2route_id,
                 scope_graph,
                 id2lints,
             },
@@ -234,6 +254,12 @@ impl UserComponentDb {
         self.id2lints.get(&id)
     }
 
+    /// Return the id of the request handler of the route that this constructor applies to,
+    /// if it was registered as a per-route override via `RegisteredRoute::constructor`.
+    pub fn get_overridden_route_id(&self, id: UserComponentId) -> Option<UserComponentId> {
+        self.constructor_id2route_id.get(&id).copied()
+    }
+
     /// Return the ids of the error observers that must be invoked when something goes wrong
     /// in the request processing pipeline for this handler.
     ///
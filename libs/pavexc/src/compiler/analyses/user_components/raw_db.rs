@@ -1,20 +1,27 @@
 use ahash::{HashMap, HashMapExt};
 use anyhow::anyhow;
 use guppy::graph::PackageGraph;
-use std::collections::BTreeMap;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
 
 use pavex_bp_schema::{
-    Blueprint, Callable, CloningStrategy, Component, Constructor, ErrorObserver, Fallback,
-    Lifecycle, Lint, LintSetting, Location, NestedBlueprint, RawCallableIdentifiers, Route,
-    WrappingMiddleware,
+    AutoOptionsOverride, Blueprint, Callable, CloningStrategy, Component, Constructor,
+    ErrorObserver, Fallback, Lifecycle, Lint, LintSetting, Location, MethodGuard, NestedBlueprint,
+    RawCallableIdentifiers, Redirect, Route, RouterSettings, VersionSelector, WrappingMiddleware,
 };
 
+use crate::compiler::analyses::user_components::domain;
+use crate::compiler::analyses::user_components::param_constraints::{self, ParamConstraint};
 use crate::compiler::analyses::user_components::router_key::RouterKey;
 use crate::compiler::analyses::user_components::scope_graph::ScopeGraphBuilder;
+use crate::compiler::analyses::user_components::versions::{self, Heir, InheritanceError};
 use crate::compiler::analyses::user_components::{ScopeGraph, ScopeId};
 use crate::compiler::interner::Interner;
 use crate::diagnostic;
-use crate::diagnostic::{CallableType, CompilerDiagnostic, LocationExt, SourceSpanExt};
+use crate::diagnostic::{
+    AnnotatedSnippet, CallableType, CompilerDiagnostic, LocationExt, SourceSpanExt,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// A component registered by a framework user against the `Blueprint` for their application.
@@ -174,6 +181,49 @@ pub(super) struct RawUserComponentDb {
     ///
     /// Invariants: there is an entry for every single fallback.
     pub(super) fallback_id2path_prefix: HashMap<UserComponentId, Option<String>>,
+    /// The route paths that still need a framework-provided `OPTIONS` handler,
+    /// according to the router settings of the root `Blueprint`.
+    ///
+    /// Paths are removed from the set as soon as their `OPTIONS` handler has been registered.
+    pub(super) auto_options_paths: BTreeSet<String>,
+    /// Associate each request handler with the constraints on the values of its route
+    /// parameters—e.g. `u64` for `:id` in `/users/:id<u64>`.
+    ///
+    /// Invariants: there is an entry for every single request handler.
+    pub(super) handler_id2param_constraints:
+        HashMap<UserComponentId, BTreeMap<String, ParamConstraint>>,
+    /// Associate the name of each named route with its request handler—e.g.
+    /// `user.show` for a route registered via `.name("user.show")`.
+    pub(super) route_name2handler_id: BTreeMap<String, UserComponentId>,
+    /// Associate each constructor registered as a per-route override with the request
+    /// handler of the route it applies to.
+    ///
+    /// Invariants: there is an entry for every single per-route constructor.
+    pub(super) constructor_id2route_id: HashMap<UserComponentId, UserComponentId>,
+    /// Associate each request handler registered against a version of the API with
+    /// that version.
+    /// If there is no entry for a request handler, it doesn't belong to a version.
+    pub(super) handler_id2version: HashMap<UserComponentId, RouteVersion>,
+    /// Associate each route inherited from another version of the API with the scope
+    /// of the version that inherits it.
+    /// Inherited routes are registered in the scope of the version they come from,
+    /// but they must use the fallback of the version that inherits them.
+    pub(super) inherited_route_id2scope_id: HashMap<UserComponentId, ScopeId>,
+    /// The rules used to route requests to a version of the API regardless of their path,
+    /// paired with the path prefix of the version—e.g. `/v2`.
+    pub(super) version_selectors: Vec<(String, VersionSelector)>,
+    /// Associate each route registered via `Blueprint::redirect` with the target of the redirect.
+    /// If there is no entry for a request handler, it isn't a redirect.
+    pub(super) handler_id2redirect: HashMap<UserComponentId, Redirect>,
+}
+
+/// The version of the API that a route belongs to.
+#[derive(Debug, Clone)]
+pub(super) struct RouteVersion {
+    /// The name of the version—e.g. `v2`.
+    pub(super) name: String,
+    /// The name of the version the route was inherited from, if it was inherited.
+    pub(super) inherited_from: Option<String>,
 }
 
 /// Used in [`RawUserComponentDb::build`] to keep track of the nested blueprints that we still
@@ -184,6 +234,50 @@ struct QueueItem<'a> {
     nested_bp: &'a NestedBlueprint,
     current_middleware_chain: Vec<UserComponentId>,
     current_observer_chain: Vec<UserComponentId>,
+    /// The fallback in effect for the parent scope.
+    parent_fallback: Option<Fallback>,
+    /// The version of the API that the nested blueprint belongs to, if any.
+    version: Option<VersionScope>,
+}
+
+/// Used in [`RawUserComponentDb::build`] to keep track of the version of the API that
+/// the routes of a blueprint belong to.
+#[derive(Clone)]
+struct VersionScope {
+    name: String,
+    /// The scope of the blueprint that the version was nested under.
+    parent_scope_id: ScopeId,
+    /// The path prefix of the blueprint that the version was nested under.
+    parent_path_prefix: Option<String>,
+    /// The length of the middleware chain of the parent blueprint when the version was nested.
+    parent_middleware_chain_len: usize,
+    /// The path prefix of the current blueprint, relative to the version prefix.
+    relative_prefix: String,
+    /// The versions that inherit routes from this version.
+    heirs: Rc<Vec<Heir>>,
+}
+
+/// Used in [`RawUserComponentDb::build`] to keep track of the routes that must be registered
+/// again for the versions that inherit them, once the middleware chains of all versions
+/// are known.
+struct InheritedRoute<'a> {
+    route: &'a Route,
+    /// The scope the route was registered against, in the version it comes from.
+    scope_id: ScopeId,
+    middleware_chain: Vec<UserComponentId>,
+    observer_chain: Vec<UserComponentId>,
+    version: VersionScope,
+    heir: Heir,
+}
+
+/// Used in [`RawUserComponentDb::build`] to keep track of the versions of the API that
+/// have already been processed.
+struct ProcessedVersion {
+    scope_id: ScopeId,
+    /// The length of the middleware chain of the parent blueprint when the version was nested.
+    parent_middleware_chain_len: usize,
+    /// The middleware chain in effect at the end of the version's blueprint.
+    middleware_chain: Vec<UserComponentId>,
 }
 
 // The public `build` method alongside its private supporting routines.
@@ -205,7 +299,16 @@ impl RawUserComponentDb {
             handler_id2middleware_ids: HashMap::new(),
             handler_id2error_observer_ids: HashMap::new(),
             fallback_id2path_prefix: HashMap::new(),
+            auto_options_paths: BTreeSet::new(),
+            handler_id2param_constraints: HashMap::new(),
+            route_name2handler_id: BTreeMap::new(),
+            constructor_id2route_id: HashMap::new(),
+            handler_id2version: HashMap::new(),
+            inherited_route_id2scope_id: HashMap::new(),
+            version_selectors: Vec::new(),
+            handler_id2redirect: HashMap::new(),
         };
+        self_.auto_options_paths = self_.auto_options_paths(bp, package_graph, diagnostics);
         let mut scope_graph_builder = ScopeGraph::builder(bp.creation_location.clone());
         let root_scope_id = scope_graph_builder.root_scope_id();
         // The middleware chain that will wrap around all the request handlers in the current scope.
@@ -221,6 +324,10 @@ impl RawUserComponentDb {
         let mut current_observer_chain = Vec::new();
 
         let mut processing_queue = Vec::new();
+        // Inherited routes are registered after all blueprints have been processed.
+        let mut inherited_routes = Vec::new();
+        // Keyed by the scope of the parent blueprint and the name of the version.
+        let mut processed_versions = HashMap::new();
 
         Self::process_blueprint(
             &mut self_,
@@ -231,7 +338,11 @@ impl RawUserComponentDb {
             &mut current_middleware_chain,
             &mut current_observer_chain,
             true,
+            false,
+            None,
+            None,
             &mut processing_queue,
+            &mut inherited_routes,
             package_graph,
             diagnostics,
         );
@@ -243,18 +354,34 @@ impl RawUserComponentDb {
                 parent_path_prefix,
                 mut current_middleware_chain,
                 mut current_observer_chain,
+                parent_fallback,
+                version,
             } = item;
             let nested_scope_id = scope_graph_builder
                 .add_scope(parent_scope_id, Some(nested_bp.nesting_location.clone()));
-            self_.validate_nested_bp(nested_bp, package_graph, diagnostics);
+            self_.validate_nested_bp(
+                nested_bp,
+                parent_path_prefix.as_deref(),
+                package_graph,
+                diagnostics,
+            );
 
-            let path_prefix = match parent_path_prefix {
-                Some(prefix) => Some(format!(
+            // Invalid domains have already been reported: we process the nested blueprint
+            // as if it had been nested without a domain to avoid cascading errors.
+            let domain_prefix = nested_bp
+                .domain
+                .as_deref()
+                .filter(|domain| domain::validate_domain(domain).is_ok())
+                .map(domain::domain_routing_prefix);
+            let is_domain_root = domain_prefix.is_some();
+            let path_prefix = match (parent_path_prefix, domain_prefix) {
+                (_, Some(domain_prefix)) => Some(domain_prefix),
+                (Some(prefix), None) => Some(format!(
                     "{}{}",
                     prefix,
                     nested_bp.path_prefix.as_deref().unwrap_or("")
                 )),
-                None => nested_bp.path_prefix.clone(),
+                (None, None) => nested_bp.path_prefix.clone(),
             };
 
             Self::process_blueprint(
@@ -266,7 +393,32 @@ impl RawUserComponentDb {
                 &mut current_middleware_chain,
                 &mut current_observer_chain,
                 false,
+                is_domain_root,
+                parent_fallback.as_ref(),
+                version.as_ref(),
                 &mut processing_queue,
+                &mut inherited_routes,
+                package_graph,
+                diagnostics,
+            );
+
+            if let (Some(api_version), Some(version)) = (&nested_bp.version, version) {
+                processed_versions.insert(
+                    (parent_scope_id, api_version.name.clone()),
+                    ProcessedVersion {
+                        scope_id: nested_scope_id,
+                        parent_middleware_chain_len: version.parent_middleware_chain_len,
+                        middleware_chain: current_middleware_chain,
+                    },
+                );
+            }
+        }
+
+        for inherited_route in inherited_routes {
+            self_.process_inherited_route(
+                inherited_route,
+                &processed_versions,
+                &mut scope_graph_builder,
                 package_graph,
                 diagnostics,
             );
@@ -285,6 +437,13 @@ impl RawUserComponentDb {
     ///
     /// If `path_prefix` is `Some`, then it is prepended to the path of each route
     /// in `Blueprint`.
+    ///
+    /// If `is_domain_root` is `true` and the `Blueprint` doesn't register a fallback,
+    /// `parent_fallback` is registered against the current scope: requests for a domain
+    /// must never be routed outside of it.
+    ///
+    /// If `version` is `Some`, the routes of the `Blueprint` belong to that version of the API:
+    /// they're queued in `inherited_routes` for each version that inherits them.
     fn process_blueprint<'a>(
         &mut self,
         bp: &'a Blueprint,
@@ -294,10 +453,19 @@ impl RawUserComponentDb {
         mut current_middleware_chain: &mut Vec<UserComponentId>,
         mut current_observer_chain: &mut Vec<UserComponentId>,
         is_root: bool,
+        is_domain_root: bool,
+        parent_fallback: Option<&Fallback>,
+        version: Option<&VersionScope>,
         bp_queue: &mut Vec<QueueItem<'a>>,
+        inherited_routes: &mut Vec<InheritedRoute<'a>>,
         package_graph: &PackageGraph,
         diagnostics: &mut Vec<miette::Error>,
     ) {
+        let first_nested_bp_index = bp_queue.len();
+        let (mut version2heirs, inheritance_errors) = versions::resolve_inheritance(bp);
+        for e in inheritance_errors {
+            self.invalid_inheritance(e, package_graph, diagnostics);
+        }
         let mut fallback: Option<&Fallback> = None;
         for component in &bp.components {
             match component {
@@ -307,26 +475,101 @@ impl RawUserComponentDb {
                 Component::WrappingMiddleware(w) => {
                     self.process_middleware(&w, current_scope_id, &mut current_middleware_chain);
                 }
-                Component::Route(r) => self.process_route(
-                    &r,
-                    &current_middleware_chain,
-                    &current_observer_chain,
-                    current_scope_id,
-                    path_prefix,
-                    scope_graph_builder,
-                    package_graph,
-                    diagnostics,
-                ),
+                Component::Route(r) => {
+                    let route_id = self.process_route(
+                        r,
+                        current_middleware_chain,
+                        current_observer_chain,
+                        current_scope_id,
+                        path_prefix,
+                        scope_graph_builder,
+                        package_graph,
+                        diagnostics,
+                    );
+                    let Some(version) = version else {
+                        continue;
+                    };
+                    self.handler_id2version.insert(
+                        route_id,
+                        RouteVersion {
+                            name: version.name.clone(),
+                            inherited_from: None,
+                        },
+                    );
+                    // Invalid routes have already been reported: we don't inherit them
+                    // to avoid reporting the same error twice.
+                    let relative_path = format!("{}{}", version.relative_prefix, r.path);
+                    let is_valid_route = (r.path.is_empty() || r.path.starts_with('/'))
+                        && param_constraints::strip_constraints(&relative_path).is_ok()
+                        && match &r.method_guard {
+                            MethodGuard::Any => true,
+                            MethodGuard::Some(methods) => {
+                                methods.iter().all(|m| validate_method(m).is_ok())
+                            }
+                        }
+                        && r.constructors
+                            .iter()
+                            .all(|c| c.lifecycle != Lifecycle::Singleton);
+                    if !is_valid_route {
+                        continue;
+                    }
+                    for heir in version.heirs.iter() {
+                        if heir.inherits(&relative_path, &r.method_guard) {
+                            inherited_routes.push(InheritedRoute {
+                                route: r,
+                                scope_id: current_scope_id,
+                                middleware_chain: current_middleware_chain.clone(),
+                                observer_chain: current_observer_chain.clone(),
+                                version: version.clone(),
+                                heir: heir.clone(),
+                            });
+                        }
+                    }
+                }
                 Component::FallbackRequestHandler(f) => {
                     fallback = Some(f);
                 }
                 Component::NestedBlueprint(b) => {
+                    let nested_version = match &b.version {
+                        Some(api_version) => {
+                            if let Some(selector) = &api_version.selector {
+                                let version_prefix = format!(
+                                    "{}{}",
+                                    path_prefix.map(domain::path_template).unwrap_or(""),
+                                    b.path_prefix.as_deref().unwrap_or("")
+                                );
+                                self.version_selectors
+                                    .push((version_prefix, selector.clone()));
+                            }
+                            Some(VersionScope {
+                                name: api_version.name.clone(),
+                                parent_scope_id: current_scope_id,
+                                parent_path_prefix: path_prefix.map(|s| s.to_owned()),
+                                parent_middleware_chain_len: current_middleware_chain.len(),
+                                relative_prefix: String::new(),
+                                heirs: Rc::new(
+                                    version2heirs.remove(&api_version.name).unwrap_or_default(),
+                                ),
+                            })
+                        }
+                        None => version.map(|version| VersionScope {
+                            relative_prefix: format!(
+                                "{}{}",
+                                version.relative_prefix,
+                                b.path_prefix.as_deref().unwrap_or("")
+                            ),
+                            ..version.clone()
+                        }),
+                    };
                     bp_queue.push(QueueItem {
                         parent_scope_id: current_scope_id,
                         nested_bp: &b,
                         parent_path_prefix: path_prefix.map(|s| s.to_owned()),
                         current_middleware_chain: current_middleware_chain.clone(),
                         current_observer_chain: current_observer_chain.clone(),
+                        // Populated below, once we know which fallback is in effect.
+                        parent_fallback: None,
+                        version: nested_version,
                     });
                 }
                 Component::ErrorObserver(eo) => {
@@ -334,6 +577,30 @@ impl RawUserComponentDb {
                 }
             }
         }
+        let fallback = match (fallback, parent_fallback) {
+            (Some(fallback), _) => Some(fallback.to_owned()),
+            (None, Some(parent_fallback)) if is_domain_root => Some(parent_fallback.to_owned()),
+            (None, _) if is_root => {
+                // We need to have a top-level fallback handler.
+                // If the user hasn't registered one against the top-level blueprint,
+                // we must provide a framework default.
+                let raw_callable_identifiers = RawCallableIdentifiers::from_raw_parts(
+                    "pavex::router::default_fallback".to_owned(),
+                    "pavex".to_owned(),
+                );
+                Some(Fallback {
+                    request_handler: Callable {
+                        callable: raw_callable_identifiers,
+                        // We don't have a location for the default fallback handler.
+                        // Nor do we have a way (yet) to identify this component as "framework provided".
+                        // Something to fix in the future.
+                        location: bp.creation_location.clone(),
+                    },
+                    error_handler: None,
+                })
+            }
+            (None, _) => None,
+        };
         if let Some(fallback) = &fallback {
             self.process_fallback(
                 fallback,
@@ -343,32 +610,11 @@ impl RawUserComponentDb {
                 current_scope_id,
                 scope_graph_builder,
             );
-        } else if is_root {
-            // We need to have a top-level fallback handler.
-            // If the user hasn't registered one against the top-level blueprint,
-            // we must provide a framework default.
-            let raw_callable_identifiers = RawCallableIdentifiers::from_raw_parts(
-                "pavex::router::default_fallback".to_owned(),
-                "pavex".to_owned(),
-            );
-            let registered_fallback = Fallback {
-                request_handler: Callable {
-                    callable: raw_callable_identifiers,
-                    // We don't have a location for the default fallback handler.
-                    // Nor do we have a way (yet) to identify this component as "framework provided".
-                    // Something to fix in the future.
-                    location: bp.creation_location.clone(),
-                },
-                error_handler: None,
-            };
-            self.process_fallback(
-                &registered_fallback,
-                path_prefix,
-                current_middleware_chain,
-                current_observer_chain,
-                current_scope_id,
-                scope_graph_builder,
-            )
+        }
+        // Nested blueprints inherit the fallback in effect for the current scope.
+        let fallback_in_effect = fallback.or_else(|| parent_fallback.cloned());
+        for item in &mut bp_queue[first_nested_bp_index..] {
+            item.parent_fallback = fallback_in_effect.clone();
         }
     }
 
@@ -385,22 +631,26 @@ impl RawUserComponentDb {
         scope_graph_builder: &mut ScopeGraphBuilder,
         package_graph: &PackageGraph,
         diagnostics: &mut Vec<miette::Error>,
-    ) {
+    ) -> UserComponentId {
         const ROUTE_LIFECYCLE: Lifecycle = Lifecycle::RequestScoped;
 
         let raw_callable_identifiers_id = self
             .identifiers_interner
             .get_or_intern(registered_route.request_handler.callable.clone());
         let route_scope_id = scope_graph_builder.add_scope(current_scope_id, None);
-        let router_key = {
-            let path = match path_prefix {
-                Some(prefix) => format!("{}{}", prefix, registered_route.path),
-                None => registered_route.path.to_owned(),
+        let full_path = match path_prefix {
+            Some(prefix) => format!("{}{}", prefix, registered_route.path),
+            None => registered_route.path.to_owned(),
+        };
+        // Constraints are not part of the routing key: they are checked after matching.
+        let (path, param_constraints, invalid_constraint) =
+            match param_constraints::strip_constraints(&full_path) {
+                Ok((path, constraints)) => (path, constraints, None),
+                Err(e) => (full_path, BTreeMap::new(), Some(e)),
             };
-            RouterKey {
-                path,
-                method_guard: registered_route.method_guard.clone(),
-            }
+        let router_key = RouterKey {
+            path: path.clone(),
+            method_guard: registered_route.method_guard.clone(),
         };
         let component = UserComponent::RequestHandler {
             raw_callable_identifiers_id,
@@ -417,13 +667,41 @@ impl RawUserComponentDb {
             .insert(request_handler_id, current_middleware_chain.to_owned());
         self.handler_id2error_observer_ids
             .insert(request_handler_id, current_observer_chain.to_owned());
+        self.handler_id2param_constraints
+            .insert(request_handler_id, param_constraints);
 
+        if let Some(e) = invalid_constraint {
+            self.invalid_param_constraint(request_handler_id, e, package_graph, diagnostics);
+        }
+        if let Some(name) = &registered_route.name {
+            self.process_route_name(request_handler_id, name, package_graph, diagnostics);
+        }
+        // Per-route constructors live in the scope of the route, therefore they shadow
+        // the constructors registered for the same types in the enclosing scopes.
+        for constructor in &registered_route.constructors {
+            if constructor.lifecycle == Lifecycle::Singleton {
+                self.singleton_route_constructor(constructor, package_graph, diagnostics);
+                continue;
+            }
+            let constructor_id = self.process_constructor(constructor, route_scope_id);
+            self.constructor_id2route_id
+                .insert(constructor_id, request_handler_id);
+        }
         self.validate_route(
             request_handler_id,
             registered_route,
             package_graph,
             diagnostics,
         );
+        if let Some(redirect) = &registered_route.redirect {
+            match validate_redirect(domain::path_template(&path), redirect) {
+                Ok(()) => {
+                    self.handler_id2redirect
+                        .insert(request_handler_id, redirect.to_owned());
+                }
+                Err(e) => self.invalid_redirect(request_handler_id, e, package_graph, diagnostics),
+            }
+        }
 
         self.process_error_handler(
             &registered_route.error_handler,
@@ -431,6 +709,210 @@ impl RawUserComponentDb {
             current_scope_id,
             request_handler_id,
         );
+
+        // The `OPTIONS` handler is registered right after the first route for the same path,
+        // so that it shares the same middlewares and error observers.
+        // We skip invalid paths to avoid reporting the same error twice.
+        let is_valid_path =
+            registered_route.path.is_empty() || registered_route.path.starts_with('/');
+        if is_valid_path && self.auto_options_paths.remove(&path) {
+            let raw_callable_identifiers = RawCallableIdentifiers::from_raw_parts(
+                "pavex::router::auto_options".to_owned(),
+                "pavex".to_owned(),
+            );
+            let options_route = Route {
+                path: registered_route.path.clone(),
+                method_guard: MethodGuard::Some(BTreeSet::from(["OPTIONS".to_owned()])),
+                request_handler: Callable {
+                    callable: raw_callable_identifiers,
+                    // There is no dedicated location for framework-provided handlers,
+                    // we use the location of the route that triggered its registration.
+                    location: registered_route.request_handler.location.clone(),
+                },
+                error_handler: None,
+                name: None,
+                constructors: Vec::new(),
+                redirect: None,
+            };
+            self.process_route(
+                &options_route,
+                current_middleware_chain,
+                current_observer_chain,
+                current_scope_id,
+                path_prefix,
+                scope_graph_builder,
+                package_graph,
+                diagnostics,
+            );
+        }
+        request_handler_id
+    }
+
+    /// Register with [`RawUserComponentDb`] a route inherited by another version of the API,
+    /// under the path prefix of the inheriting version.
+    ///
+    /// The route keeps the scope, error observers and middlewares it was registered with.
+    /// The middlewares of the inheriting version, as well as the ones of the versions
+    /// the route went through, wrap around them.
+    fn process_inherited_route(
+        &mut self,
+        inherited_route: InheritedRoute,
+        processed_versions: &HashMap<(ScopeId, String), ProcessedVersion>,
+        scope_graph_builder: &mut ScopeGraphBuilder,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let InheritedRoute {
+            route,
+            scope_id,
+            middleware_chain,
+            observer_chain,
+            version,
+            heir,
+        } = inherited_route;
+        let processed_version =
+            |name: &str| processed_versions.get(&(version.parent_scope_id, name.to_owned()));
+
+        let heir_version = processed_version(&heir.name);
+        let mut inherited_middleware_chain = heir_version
+            .map(|v| v.middleware_chain.clone())
+            .unwrap_or_default();
+        for name in &heir.intermediates {
+            if let Some(v) = processed_version(name) {
+                inherited_middleware_chain
+                    .extend_from_slice(&v.middleware_chain[v.parent_middleware_chain_len..]);
+            }
+        }
+        inherited_middleware_chain
+            .extend_from_slice(&middleware_chain[version.parent_middleware_chain_len..]);
+
+        let path_prefix = format!(
+            "{}{}{}",
+            version.parent_path_prefix.as_deref().unwrap_or(""),
+            heir.path_prefix,
+            version.relative_prefix
+        );
+        // Route names must be unique: the inherited route can't reuse the name of the original one.
+        let route = Route {
+            name: None,
+            ..route.clone()
+        };
+        let route_id = self.process_route(
+            &route,
+            &inherited_middleware_chain,
+            &observer_chain,
+            scope_id,
+            Some(&path_prefix),
+            scope_graph_builder,
+            package_graph,
+            diagnostics,
+        );
+        if let Some(heir_version) = heir_version {
+            self.inherited_route_id2scope_id
+                .insert(route_id, heir_version.scope_id);
+        }
+        self.handler_id2version.insert(
+            route_id,
+            RouteVersion {
+                name: heir.name,
+                inherited_from: Some(version.name),
+            },
+        );
+    }
+
+    /// Determine which route paths need a framework-provided `OPTIONS` handler—i.e.
+    /// automatic `OPTIONS` handling is enabled for them and there is no user-registered
+    /// handler that can process `OPTIONS` requests.
+    ///
+    /// Emit diagnostics for per-path overrides that don't match any registered route.
+    fn auto_options_paths(
+        &self,
+        bp: &Blueprint,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) -> BTreeSet<String> {
+        fn collect_paths(
+            bp: &Blueprint,
+            path_prefix: Option<&str>,
+            route_paths: &mut BTreeSet<String>,
+            explicit_options_paths: &mut BTreeSet<String>,
+        ) {
+            let mut routes = versions::inherited_routes(bp);
+            for component in &bp.components {
+                match component {
+                    Component::Route(r) => routes.push((r.path.clone(), r.method_guard.clone())),
+                    Component::NestedBlueprint(b) => {
+                        let domain = b
+                            .domain
+                            .as_deref()
+                            .filter(|domain| domain::validate_domain(domain).is_ok());
+                        let path_prefix = match domain {
+                            Some(domain) => domain::domain_routing_prefix(domain),
+                            None => format!(
+                                "{}{}",
+                                path_prefix.unwrap_or(""),
+                                b.path_prefix.as_deref().unwrap_or("")
+                            ),
+                        };
+                        collect_paths(
+                            &b.blueprint,
+                            Some(&path_prefix),
+                            route_paths,
+                            explicit_options_paths,
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            for (path, method_guard) in routes {
+                let path = format!("{}{}", path_prefix.unwrap_or(""), path);
+                let path = match param_constraints::strip_constraints(&path) {
+                    Ok((path, _)) => path,
+                    Err(_) => path,
+                };
+                let handles_options = match method_guard {
+                    MethodGuard::Any => true,
+                    MethodGuard::Some(methods) => methods.contains("OPTIONS"),
+                };
+                if handles_options {
+                    explicit_options_paths.insert(path.clone());
+                }
+                route_paths.insert(path);
+            }
+        }
+
+        let settings = &bp.router_settings;
+        if !settings.auto_options && settings.auto_options_overrides.is_empty() {
+            return BTreeSet::new();
+        }
+
+        let mut route_paths = BTreeSet::new();
+        let mut explicit_options_paths = BTreeSet::new();
+        collect_paths(bp, None, &mut route_paths, &mut explicit_options_paths);
+
+        let mut path2enabled: BTreeMap<_, _> = route_paths
+            .into_iter()
+            .map(|path| (path, settings.auto_options))
+            .collect();
+        for override_ in &settings.auto_options_overrides {
+            // Routes nested under a domain are identified by their domain and path,
+            // e.g. `admin.example.com/users`.
+            let mut matched = false;
+            for (path, enabled) in path2enabled.iter_mut() {
+                if domain::display_routing_key(path) == override_.path {
+                    *enabled = override_.enabled;
+                    matched = true;
+                }
+            }
+            if !matched {
+                self.auto_options_override_for_unknown_path(override_, package_graph, diagnostics);
+            }
+        }
+        path2enabled
+            .into_iter()
+            .filter(|(path, enabled)| *enabled && !explicit_options_paths.contains(path))
+            .map(|(path, _)| path)
+            .collect()
     }
 
     /// Register with [`RawUserComponentDb`] the fallback that has been
@@ -465,8 +947,14 @@ impl RawUserComponentDb {
             .insert(fallback_id, current_middleware_chain.to_owned());
         self.handler_id2error_observer_ids
             .insert(fallback_id, current_observer_chain.to_owned());
+        // Constraints on the parameters in the prefix only apply to routes.
+        let path_prefix = path_prefix.map(|prefix| {
+            param_constraints::strip_constraints(prefix)
+                .map(|(prefix, _)| prefix)
+                .unwrap_or_else(|_| prefix.to_owned())
+        });
         self.fallback_id2path_prefix
-            .insert(fallback_id, path_prefix.map(|s| s.to_owned()));
+            .insert(fallback_id, path_prefix);
 
         self.process_error_handler(
             &fallback.error_handler,
@@ -513,7 +1001,11 @@ impl RawUserComponentDb {
     /// registered against the provided `Blueprint`, including its error handler
     /// (if present).
     /// It is associated with or nested under the provided `current_scope_id`.
-    fn process_constructor(&mut self, constructor: &Constructor, current_scope_id: ScopeId) {
+    fn process_constructor(
+        &mut self,
+        constructor: &Constructor,
+        current_scope_id: ScopeId,
+    ) -> UserComponentId {
         let raw_callable_identifiers_id = self
             .identifiers_interner
             .get_or_intern(constructor.constructor.callable.clone());
@@ -544,6 +1036,7 @@ impl RawUserComponentDb {
             current_scope_id,
             constructor_id,
         );
+        constructor_id
     }
 
     /// Register with [`RawUserComponentDb`] an error observer that has been
@@ -606,8 +1099,9 @@ impl RawUserComponentDb {
         self.intern_component(component, lifecycle, error_handler.location.to_owned());
     }
 
-    /// Check the path of the registered route.
-    /// Emit diagnostics if the path is invalid—i.e. empty or missing a leading slash.
+    /// Check the path and the method guard of the registered route.
+    /// Emit diagnostics if the path is invalid—i.e. empty or missing a leading slash—or
+    /// if the method guard contains an invalid method.
     fn validate_route(
         &self,
         route_id: UserComponentId,
@@ -615,6 +1109,13 @@ impl RawUserComponentDb {
         package_graph: &PackageGraph,
         diagnostics: &mut Vec<miette::Error>,
     ) {
+        if let MethodGuard::Some(methods) = &route.method_guard {
+            for method in methods {
+                if let Err(e) = validate_method(method) {
+                    self.invalid_method(route_id, e, package_graph, diagnostics);
+                }
+            }
+        }
         // Empty paths are OK.
         if route.path.is_empty() {
             return;
@@ -626,12 +1127,25 @@ impl RawUserComponentDb {
 
     /// Check the path prefix of the nested blueprint.
     /// Emit diagnostics if the path prefix is invalid—i.e. empty or missing a leading slash.
+    /// Router settings can only be customized on the root blueprint: emit a diagnostic
+    /// if the nested blueprint customizes them.
     fn validate_nested_bp(
         &self,
         nested_bp: &NestedBlueprint,
+        parent_path_prefix: Option<&str>,
         package_graph: &PackageGraph,
         diagnostics: &mut Vec<miette::Error>,
     ) {
+        if let Some(domain) = nested_bp.domain.as_deref() {
+            if let Err(e) = domain::validate_domain(domain) {
+                self.invalid_domain(nested_bp, e, package_graph, diagnostics);
+            } else if parent_path_prefix.is_some() {
+                self.domain_cannot_be_nested(nested_bp, package_graph, diagnostics);
+            }
+        }
+        if nested_bp.blueprint.router_settings != RouterSettings::default() {
+            self.router_settings_cannot_be_nested(nested_bp, package_graph, diagnostics);
+        }
         if let Some(path_prefix) = nested_bp.path_prefix.as_deref() {
             if path_prefix.is_empty() {
                 self.path_prefix_cannot_be_empty(nested_bp, package_graph, diagnostics);
@@ -723,8 +1237,213 @@ impl std::ops::Index<&UserComponent> for RawUserComponentDb {
     }
 }
 
+/// Check that `method` can be used in a method guard.
+///
+/// Custom methods must be valid tokens, as defined in RFC 9110, and they can't be a
+/// differently-cased version of a well-known or WebDAV method—methods are case-sensitive,
+/// therefore `get` wouldn't match `GET` requests.
+fn validate_method(method: &str) -> Result<(), String> {
+    const KNOWN_METHODS: [&str; 16] = [
+        "GET",
+        "POST",
+        "PUT",
+        "DELETE",
+        "PATCH",
+        "HEAD",
+        "OPTIONS",
+        "CONNECT",
+        "TRACE",
+        "PROPFIND",
+        "PROPPATCH",
+        "MKCOL",
+        "COPY",
+        "MOVE",
+        "LOCK",
+        "UNLOCK",
+    ];
+
+    if method.is_empty() {
+        return Err("HTTP methods can't be empty.".into());
+    }
+    if let Some(c) = method
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !"!#$%&'*+-.^_`|~".contains(*c))
+    {
+        return Err(format!(
+            "`{method}` is not a valid HTTP method: it contains `{c}`.\n\
+            HTTP methods must be tokens, as defined in RFC 9110: ASCII letters, digits and \
+            any of `!#$%&'*+-.^_`|~`."
+        ));
+    }
+    if let Some(known) = KNOWN_METHODS
+        .iter()
+        .find(|m| **m != method && m.eq_ignore_ascii_case(method))
+    {
+        return Err(format!(
+            "`{method}` is not a well-known HTTP method: methods are case-sensitive, \
+            therefore it wouldn't match `{known}` requests."
+        ));
+    }
+    Ok(())
+}
+
+/// The reason why a redirect registered via `Blueprint::redirect` was rejected.
+#[derive(Debug, PartialEq, Eq)]
+enum InvalidRedirect {
+    Status(String),
+    Target(String),
+}
+
+/// Check the target and the status code of a redirect route.
+///
+/// The status code must belong to the `3xx` range and the target must be a valid
+/// `Location` header value.
+/// The path parameters referenced in the target—e.g. `:id` or `*path`—must be defined
+/// in `path`, the path template of the route.
+fn validate_redirect(path: &str, redirect: &Redirect) -> Result<(), InvalidRedirect> {
+    let Redirect { to, status } = redirect;
+    if !(300..400).contains(status) {
+        return Err(InvalidRedirect::Status(format!(
+            "`{status}` is not a valid status code for a redirect.\n\
+            Redirects must use a `3xx` status code—e.g. `301 Moved Permanently` or \
+            `308 Permanent Redirect`."
+        )));
+    }
+    if to.is_empty() {
+        return Err(InvalidRedirect::Target(
+            "The target of a redirect can't be empty.".into(),
+        ));
+    }
+    if let Some(c) = to.chars().find(|c| !c.is_ascii_graphic()) {
+        return Err(InvalidRedirect::Target(format!(
+            "`{to}` is not a valid redirect target: it contains {c:?}.\n\
+            The target is used as the value of the `Location` header: it can only contain \
+            visible ASCII characters. Percent-encode the others."
+        )));
+    }
+
+    let path_params: BTreeSet<&str> = path
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix([':', '*']))
+        .collect();
+    let mut rest = to.as_str();
+    while let Some(i) = rest.find([':', '*']) {
+        let after = &rest[i + 1..];
+        // Parameter names are made of ASCII letters, digits and underscores, and they can't
+        // start with a digit—e.g. the port in `https://example.com:8080` is not a parameter.
+        let name_len = if after.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len())
+        } else {
+            0
+        };
+        let name = &after[..name_len];
+        if !name.is_empty() && !path_params.contains(name) {
+            let token = &rest[i..i + 1 + name_len];
+            return Err(InvalidRedirect::Target(format!(
+                "The redirect target, `{to}`, references a path parameter that doesn't exist: \
+                `{token}`.\n\
+                The target can only reference the parameters of the redirected path, `{path}`."
+            )));
+        }
+        rest = &after[name_len..];
+    }
+    Ok(())
+}
+
 /// All diagnostic-related code.
 impl RawUserComponentDb {
+    fn invalid_redirect(
+        &self,
+        route_id: UserComponentId,
+        error: InvalidRedirect,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = self.get_location(route_id);
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let (error, label) = match error {
+            InvalidRedirect::Status(e) => (
+                e,
+                diagnostic::get_redirect_status_span(&source, location)
+                    .map(|s| s.labeled("The redirect status code".to_string())),
+            ),
+            InvalidRedirect::Target(e) => (
+                e,
+                diagnostic::get_redirect_target_span(&source, location)
+                    .map(|s| s.labeled("The redirect target".to_string())),
+            ),
+        };
+        let diagnostic = CompilerDiagnostic::builder(source, anyhow!(error)).optional_label(label);
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn invalid_method(
+        &self,
+        route_id: UserComponentId,
+        error: String,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = self.get_location(route_id);
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_route_method_guard_span(&source, location)
+            .map(|s| s.labeled("The method guard with the invalid method".to_string()));
+        let diagnostic = CompilerDiagnostic::builder(source, anyhow!(error))
+            .optional_label(label)
+            .help(
+                "Use the constants in `pavex::blueprint::router` for well-known and WebDAV methods, \
+                e.g. `GET` or `PROPFIND`."
+                    .into(),
+            );
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn singleton_route_constructor(
+        &self,
+        constructor: &Constructor,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = &constructor.constructor.location;
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_f_macro_invocation_span(&source, location)
+            .map(|s| s.labeled("The singleton constructor".to_string()));
+        let error = anyhow!(
+            "Constructors registered for a single route must be either request-scoped or transient.\n\
+            `{}` is registered via `RegisteredRoute::constructor`, but it's a singleton.",
+            constructor.constructor.callable.import_path
+        );
+        let diagnostic = CompilerDiagnostic::builder(source, error)
+            .optional_label(label)
+            .help(
+                "Singletons are built once, when the application starts, and shared by all routes: \
+                they can't be overridden for a single route. \
+                Use `Lifecycle::RequestScoped` or register the constructor against the `Blueprint`."
+                    .into(),
+            );
+        diagnostics.push(diagnostic.build().into());
+    }
+
     fn route_path_must_start_with_a_slash(
         &self,
         route: &Route,
@@ -751,6 +1470,326 @@ impl RawUserComponentDb {
         diagnostics.push(diagnostic.build().into());
     }
 
+    fn auto_options_override_for_unknown_path(
+        &self,
+        override_: &AutoOptionsOverride,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = &override_.location;
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_auto_options_at_path_span(&source, location)
+            .map(|s| s.labeled("The path without any route".to_string()));
+        let path = &override_.path;
+        let err = anyhow!(
+            "You tried to override automatic `OPTIONS` handling for `{path}`, but there is no route registered for that path."
+        );
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help("The path must match the path of a registered route verbatim, including the prefixes of the blueprints it's nested under.".into());
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    /// Check that the route name is valid and that it hasn't already been used
+    /// by another route.
+    fn process_route_name(
+        &mut self,
+        route_id: UserComponentId,
+        name: &str,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let invalid_segment = name.split('.').find(|segment| {
+            !segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                || syn::parse_str::<syn::Ident>(segment).is_err()
+        });
+        if let Some(segment) = invalid_segment {
+            self.invalid_route_name(route_id, name, segment, package_graph, diagnostics);
+            return;
+        }
+        match self.route_name2handler_id.get(name) {
+            Some(&other_id) => {
+                self.route_name_already_in_use(
+                    route_id,
+                    other_id,
+                    name,
+                    package_graph,
+                    diagnostics,
+                );
+            }
+            None => {
+                self.route_name2handler_id.insert(name.to_owned(), route_id);
+            }
+        }
+    }
+
+    fn invalid_route_name(
+        &self,
+        route_id: UserComponentId,
+        name: &str,
+        segment: &str,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = self.get_location(route_id);
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_route_path_span(&source, location)
+            .map(|s| s.labeled("The route with the invalid name".to_string()));
+        let err = anyhow!(
+            "`{name}` is not a valid route name: `{segment}` is not a valid segment.\n\
+            Pavex generates a module for each segment of a route name (and a function for the last one), \
+            therefore each segment must be a valid Rust identifier, in snake case."
+        );
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help("Use dot-separated snake case identifiers—e.g. `user.show`.".into());
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn route_name_already_in_use(
+        &self,
+        route_id: UserComponentId,
+        other_route_id: UserComponentId,
+        name: &str,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = self.get_location(route_id);
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_route_path_span(&source, location)
+            .map(|s| s.labeled("The second route".to_string()));
+        let other_location = self.get_location(other_route_id);
+        let other_source = match other_location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let other_label = diagnostic::get_route_path_span(&other_source, other_location)
+            .map(|s| s.labeled("The first route".to_string()));
+        let err = anyhow!("Route names must be unique, but two routes are named `{name}`.");
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .optional_additional_annotated_snippet(
+                other_label.map(|l| AnnotatedSnippet::new(other_source, l)),
+            )
+            .help("Rename one of the two routes.".into());
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn invalid_inheritance(
+        &self,
+        error: InheritanceError,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let (location, label, err, help) = match error {
+            InheritanceError::UnknownVersion { version, inherited } => (
+                &inherited.location,
+                "The unknown version",
+                anyhow!(
+                    "`{}` can't inherit routes from `{}`: there is no version named `{}` nested against the same blueprint.",
+                    version.name,
+                    inherited.name,
+                    inherited.name
+                ),
+                "Nest the inherited version, via `Blueprint::nest_version`, against the same blueprint as the version that inherits from it.",
+            ),
+            InheritanceError::Cycle { versions, inherited } => (
+                &inherited.location,
+                "The inheritance that closes the cycle",
+                anyhow!(
+                    "The versions of your API can't inherit from each other in a cycle: {}.",
+                    versions
+                        .iter()
+                        .chain(std::iter::once(&versions[0]))
+                        .tuple_windows()
+                        .map(|(v, parent)| format!("`{v}` inherits from `{parent}`"))
+                        .join(", ")
+                ),
+                "Remove one of the `inherit_from` calls to break the cycle.",
+            ),
+            InheritanceError::UnknownRemovedRoute { version, removed } => (
+                &removed.location,
+                "The route that isn't inherited",
+                anyhow!(
+                    "`{}` doesn't inherit a `{} {}` route, therefore it can't be removed.",
+                    version.name,
+                    match &removed.method_guard {
+                        MethodGuard::Any => "ANY".to_owned(),
+                        MethodGuard::Some(methods) => methods.iter().join(" | "),
+                    },
+                    removed.path
+                ),
+                "The path must be relative to the version prefix and, together with the method guard, \
+                it must match an inherited route verbatim. Did you forget to call `inherit_from`?",
+            ),
+        };
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_api_version_argument_span(&source, location)
+            .map(|s| s.labeled(label.to_string()));
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help(help.into());
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn invalid_param_constraint(
+        &self,
+        route_id: UserComponentId,
+        reason: String,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = self.get_location(route_id);
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_route_path_span(&source, location)
+            .map(|s| s.labeled("The path with the invalid constraint".to_string()));
+        let err = anyhow!("The route path contains an invalid parameter constraint.\n{reason}");
+        let supported_types = param_constraints::SUPPORTED_TYPES.join(", ");
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help(format!("Constraints are enclosed in angle brackets, right after the parameter name—e.g. `:id<u64>` or `:slug<[a-z-]+>`. \
+                They can either be one of the supported types ({supported_types}) or a regular expression."));
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn invalid_domain(
+        &self,
+        nested_bp: &NestedBlueprint,
+        reason: String,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = &nested_bp.nesting_location;
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_nest_at_prefix_span(&source, location)
+            .map(|s| s.labeled("The invalid domain".to_string()));
+        let err = anyhow!(
+            "`{}` is not a valid domain.\n{reason}",
+            nested_bp.domain.as_deref().unwrap_or_default()
+        );
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help("Domains are sequences of dot-separated labels, without a port—e.g. `admin.example.com` or `:tenant.example.com`.".into());
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn domain_cannot_be_nested(
+        &self,
+        nested_bp: &NestedBlueprint,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let location = &nested_bp.nesting_location;
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_nest_at_prefix_span(&source, location)
+            .map(|s| s.labeled("The nested domain".to_string()));
+        let err = anyhow!(
+            "You can't nest a blueprint under a domain if one of its parents was nested under a path prefix or a domain."
+        );
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help("Nest the blueprint under the domain in the root blueprint (or in a blueprint nested via `nest`), then add path prefixes inside it.".into());
+        diagnostics.push(diagnostic.build().into());
+    }
+
+    fn router_settings_cannot_be_nested(
+        &self,
+        nested_bp: &NestedBlueprint,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) {
+        let RouterSettings {
+            auto_head,
+            auto_options,
+            auto_options_overrides,
+            trailing_slash,
+        } = &nested_bp.blueprint.router_settings;
+        let mut customized = vec![];
+        if *auto_head {
+            customized.push("`auto_head`");
+        }
+        if *auto_options {
+            customized.push("`auto_options`");
+        }
+        if !auto_options_overrides.is_empty() {
+            customized.push("`auto_options_at`");
+        }
+        if *trailing_slash != Default::default() {
+            customized.push("`trailing_slash`");
+        }
+        let location = &nested_bp.blueprint.creation_location;
+        let source = match location.source_file(package_graph) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_bp_new_span(&source, location)
+            .map(|s| s.labeled("The nested blueprint".to_string()));
+        let err = anyhow!(
+            "Router settings can only be customized on the root blueprint, but a nested blueprint \
+            customizes them via {}.",
+            customized.join(", ")
+        );
+        let diagnostic = CompilerDiagnostic::builder(source, err)
+            .optional_label(label)
+            .help(
+                "Move the router settings to the root blueprint. \
+                Use `auto_options_at` if you want to enable or disable automatic `OPTIONS` \
+                handling for the routes of the nested blueprint."
+                    .into(),
+            );
+        diagnostics.push(diagnostic.build().into());
+    }
+
     fn path_prefix_cannot_be_empty(
         &self,
         nested_bp: &NestedBlueprint,
@@ -837,3 +1876,54 @@ impl RawUserComponentDb {
         diagnostics.push(diagnostic.build().into());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pavex_bp_schema::Redirect;
+
+    use super::{validate_method, validate_redirect, InvalidRedirect};
+
+    #[test]
+    fn custom_methods_are_validated() {
+        for valid in ["GET", "PROPFIND", "PURGE", "purge", "M-SEARCH"] {
+            assert!(validate_method(valid).is_ok(), "{valid}");
+        }
+        for invalid in ["", "GET POST", "PURGE\n", "get", "Propfind"] {
+            assert!(validate_method(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn redirects_can_only_reference_existing_parameters() {
+        let redirect = |to: &str, status| Redirect {
+            to: to.into(),
+            status,
+        };
+        let path = "/old/:id/files/*path";
+        for valid in [
+            "/new/:id/*path",
+            "https://example.com:8080/:id",
+            "/new?id=:id",
+            "/static",
+        ] {
+            assert_eq!(
+                validate_redirect(path, &redirect(valid, 301)),
+                Ok(()),
+                "{valid}"
+            );
+        }
+        for invalid in ["/new/:user_id", "/new/*rest", "", "/new page"] {
+            assert!(
+                matches!(
+                    validate_redirect(path, &redirect(invalid, 301)),
+                    Err(InvalidRedirect::Target(_))
+                ),
+                "{invalid}"
+            );
+        }
+        assert!(matches!(
+            validate_redirect(path, &redirect("/new", 200)),
+            Err(InvalidRedirect::Status(_))
+        ));
+    }
+}
@@ -6,9 +6,11 @@ use guppy::graph::PackageGraph;
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use matchit::InsertError;
-use pavex_bp_schema::MethodGuard;
+use pavex_bp_schema::{MethodGuard, Redirect, RouterSettings, TrailingSlash, VersionSelector};
 
-use crate::compiler::analyses::user_components::raw_db::RawUserComponentDb;
+use crate::compiler::analyses::user_components::domain::{display_routing_key, split_routing_key};
+use crate::compiler::analyses::user_components::param_constraints::ParamConstraint;
+use crate::compiler::analyses::user_components::raw_db::{RawUserComponentDb, RouteVersion};
 use crate::compiler::analyses::user_components::{
     ScopeGraph, ScopeId, UserComponent, UserComponentId,
 };
@@ -24,6 +26,18 @@ pub(crate) struct Router {
     pub(crate) route_path2sub_router: BTreeMap<String, LeafRouter>,
     /// The fallback to use if no route matches the incoming request.
     pub(crate) root_fallback_id: UserComponentId,
+    /// The settings specified on the root blueprint.
+    pub(crate) settings: RouterSettings,
+    /// The paths that must be added to the router, on top of the registered ones,
+    /// to honor the trailing slash policy, mapped to the registered path they stand for—e.g.
+    /// `/users/` => `/users`.
+    /// It's empty if the policy is [`TrailingSlash::Strict`].
+    pub(crate) trailing_slash_aliases: BTreeMap<String, String>,
+    /// The request handler of each named route, keyed by route name.
+    pub(crate) route_name2handler_id: BTreeMap<String, UserComponentId>,
+    /// The rules used to route requests to a version of the API regardless of their path,
+    /// paired with the path prefix of the version—e.g. `/v2`.
+    pub(crate) version_selectors: Vec<(String, VersionSelector)>,
 }
 
 /// A router to dispatch a request to a handler based on its method, after having matched its path.
@@ -36,6 +50,15 @@ pub(crate) struct LeafRouter {
     /// methods registered for the route.
     /// We always need a fallback, since you might receive requests with "non-standard" methods.
     pub(crate) fallback_id: UserComponentId,
+    /// The fallback to use if the values of the route parameters don't satisfy their
+    /// constraints, as if the path didn't match.
+    /// It's the same as `fallback_id`, unless the route matches all methods: `fallback_id`
+    /// is then the request handler itself.
+    pub(crate) unmatched_fallback_id: UserComponentId,
+    /// The constraints on the values of the route parameters.
+    pub(crate) param_constraints: BTreeMap<String, ParamConstraint>,
+    /// The target of the redirect, if the route was registered via `Blueprint::redirect`.
+    pub(crate) redirect: Option<Redirect>,
 }
 
 impl LeafRouter {
@@ -43,6 +66,9 @@ impl LeafRouter {
         Self {
             handler_id2methods: Default::default(),
             fallback_id,
+            unmatched_fallback_id: fallback_id,
+            param_constraints: Default::default(),
+            redirect: None,
         }
     }
 }
@@ -51,6 +77,7 @@ impl Router {
     pub(super) fn new(
         raw_user_component_db: &RawUserComponentDb,
         scope_graph: &ScopeGraph,
+        settings: RouterSettings,
         package_graph: &PackageGraph,
         diagnostics: &mut Vec<miette::Error>,
     ) -> Result<Self, ()> {
@@ -69,8 +96,12 @@ impl Router {
             })
             .expect("No fallback registered for the root scope.");
         Self::detect_method_conflicts(raw_user_component_db, package_graph, diagnostics)?;
-        let runtime_router =
-            Self::detect_path_conflicts(raw_user_component_db, package_graph, diagnostics)?;
+        let (runtime_router, trailing_slash_aliases) = Self::detect_path_conflicts(
+            raw_user_component_db,
+            settings.trailing_slash,
+            package_graph,
+            diagnostics,
+        )?;
         let (route_id2fallback_id, path_catchall2fallback_id) = Self::assign_fallbacks(
             runtime_router.clone(),
             raw_user_component_db,
@@ -84,6 +115,7 @@ impl Router {
             package_graph,
             diagnostics,
         )?;
+        Self::check_param_constraints(raw_user_component_db, package_graph, diagnostics)?;
 
         let mut route_path2sub_router = BTreeMap::new();
         for (id, component) in raw_user_component_db.iter() {
@@ -94,13 +126,24 @@ impl Router {
                 MethodGuard::Any => {
                     // We don't need to register a fallback for this route, since it matches
                     // all methods.
-                    route_path2sub_router.insert(router_key.path.clone(), LeafRouter::new(id));
+                    let mut sub_router = LeafRouter::new(id);
+                    sub_router.unmatched_fallback_id = route_id2fallback_id[&id];
+                    sub_router.param_constraints =
+                        raw_user_component_db.handler_id2param_constraints[&id].clone();
+                    // Redirect routes match all methods.
+                    sub_router.redirect =
+                        raw_user_component_db.handler_id2redirect.get(&id).cloned();
+                    route_path2sub_router.insert(router_key.path.clone(), sub_router);
                 }
                 MethodGuard::Some(methods) => {
                     let sub_router: &mut LeafRouter = route_path2sub_router
                         .entry(router_key.path.clone())
                         .or_insert_with(|| LeafRouter::new(route_id2fallback_id[&id]));
                     sub_router.handler_id2methods.insert(id, methods.clone());
+                    // We checked in `check_param_constraints` that all routes for the
+                    // same path share the same constraints.
+                    sub_router.param_constraints =
+                        raw_user_component_db.handler_id2param_constraints[&id].clone();
                 }
             }
         }
@@ -113,6 +156,10 @@ impl Router {
         Ok(Self {
             route_path2sub_router,
             root_fallback_id,
+            settings,
+            trailing_slash_aliases,
+            route_name2handler_id: raw_user_component_db.route_name2handler_id.clone(),
+            version_selectors: raw_user_component_db.version_selectors.clone(),
         })
     }
 
@@ -136,7 +183,21 @@ impl Router {
         }
 
         for (path, routes) in path2method2component_id.into_iter() {
-            for method in METHODS {
+            // Custom methods can conflict too—e.g. two `PROPFIND` handlers for the same path.
+            let custom_methods: BTreeSet<&str> = routes
+                .iter()
+                .filter_map(|(guard, _)| match guard {
+                    MethodGuard::Any => None,
+                    MethodGuard::Some(methods) => Some(methods),
+                })
+                .flatten()
+                .map(String::as_str)
+                .filter(|method| !METHODS.contains(method))
+                .collect();
+            // The same set of handlers often conflicts on several methods (e.g. two `ANY`
+            // routes): we report each set once, listing all the affected methods.
+            let mut handlers2methods = IndexMap::<Vec<UserComponentId>, Vec<&str>>::new();
+            for method in METHODS.into_iter().chain(custom_methods) {
                 let mut relevant_handler_ids = IndexSet::new();
                 for (guard, id) in &routes {
                     match guard {
@@ -153,19 +214,73 @@ impl Router {
                 }
                 // We don't want to return an error if the _same_ callable is being registered
                 // as a request handler for the same path+method multiple times.
+                // Redirects share the same callable: they are only the same if their targets match.
                 let unique_handlers = relevant_handler_ids
                     .iter()
-                    .unique_by(|id| raw_user_component_db[**id].raw_callable_identifiers_id())
+                    .unique_by(|id| {
+                        (
+                            raw_user_component_db[**id].raw_callable_identifiers_id(),
+                            raw_user_component_db.handler_id2redirect.get(*id),
+                        )
+                    })
+                    .copied()
                     .collect::<Vec<_>>();
                 if unique_handlers.len() > 1 {
-                    push_router_conflict_diagnostic(
+                    handlers2methods
+                        .entry(unique_handlers)
+                        .or_default()
+                        .push(method);
+                }
+            }
+            for (handler_ids, methods) in handlers2methods {
+                push_router_conflict_diagnostic(
+                    path,
+                    &methods,
+                    &handler_ids,
+                    raw_user_component_db,
+                    package_graph,
+                    diagnostics,
+                );
+            }
+        }
+
+        if n_diagnostics == diagnostics.len() {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// All routes registered for the same path must specify the same parameter constraints:
+    /// we can only check constraints after the path has been matched, therefore we can't
+    /// use them to choose between routes registered for the same path.
+    fn check_param_constraints(
+        raw_user_component_db: &RawUserComponentDb,
+        package_graph: &PackageGraph,
+        diagnostics: &mut Vec<miette::Error>,
+    ) -> Result<(), ()> {
+        let n_diagnostics = diagnostics.len();
+
+        let mut path2route_ids = IndexMap::<_, Vec<_>>::new();
+        for (id, component) in raw_user_component_db.iter() {
+            if let UserComponent::RequestHandler { router_key, .. } = component {
+                path2route_ids.entry(&router_key.path).or_default().push(id);
+            }
+        }
+        for (path, route_ids) in path2route_ids {
+            let first_id = route_ids[0];
+            let first_constraints = &raw_user_component_db.handler_id2param_constraints[&first_id];
+            for &id in &route_ids[1..] {
+                if &raw_user_component_db.handler_id2param_constraints[&id] != first_constraints {
+                    push_param_constraints_mismatch_diagnostic(
                         path,
-                        method,
-                        &unique_handlers,
+                        first_id,
+                        id,
                         raw_user_component_db,
                         package_graph,
                         diagnostics,
                     );
+                    break;
                 }
             }
         }
@@ -184,11 +299,16 @@ impl Router {
     ///
     /// By trying to create the router in the compiler itself!
     /// If it works now, it'll work at runtime too.
+    ///
+    /// Unless the trailing slash policy is [`TrailingSlash::Strict`], the router must
+    /// also accommodate the other form of each registered path—e.g. `/users/` for `/users`.
+    /// We return those additional paths alongside the router.
     fn detect_path_conflicts(
         raw_user_component_db: &RawUserComponentDb,
+        trailing_slash: TrailingSlash,
         package_graph: &PackageGraph,
         diagnostics: &mut Vec<miette::Error>,
-    ) -> Result<matchit::Router<()>, ()> {
+    ) -> Result<(matchit::Router<()>, BTreeMap<String, String>), ()> {
         let mut path_router = matchit::Router::new();
         let mut errored = false;
         for (id, component) in raw_user_component_db.iter() {
@@ -217,10 +337,64 @@ impl Router {
                 }
             }
         }
+        if errored {
+            return Err(());
+        }
+
+        let mut trailing_slash_aliases = BTreeMap::new();
+        if trailing_slash == TrailingSlash::Strict {
+            return Ok((path_router, trailing_slash_aliases));
+        }
+        let mut path2route_id = BTreeMap::new();
+        for (id, component) in raw_user_component_db.iter() {
+            if let UserComponent::RequestHandler { router_key, .. } = component {
+                path2route_id.entry(router_key.path.as_str()).or_insert(id);
+            }
+        }
+        for (&path, &id) in &path2route_id {
+            let Some(alternate) = trailing_slash_alternate(path) else {
+                continue;
+            };
+            if let Some(&alternate_id) = path2route_id.get(alternate.as_str()) {
+                // Both forms have been registered. That's fine if we're redirecting—each
+                // form is handled by its own route—but it's ambiguous if they must be
+                // handled by the same route.
+                // We only report the conflict once, when looking at the form with the trailing slash.
+                if trailing_slash == TrailingSlash::MatchBoth && path.ends_with('/') {
+                    errored = true;
+                    push_trailing_slash_conflict_diagnostic(
+                        &alternate,
+                        alternate_id,
+                        path,
+                        id,
+                        raw_user_component_db,
+                        package_graph,
+                        diagnostics,
+                    );
+                }
+                continue;
+            }
+            if let Err(e) = path_router.insert(alternate.clone(), ()) {
+                errored = true;
+                push_trailing_slash_alias_diagnostic(
+                    path,
+                    &alternate,
+                    id,
+                    e,
+                    trailing_slash,
+                    raw_user_component_db,
+                    package_graph,
+                    diagnostics,
+                );
+                continue;
+            }
+            trailing_slash_aliases.insert(alternate, path.to_owned());
+        }
+
         if errored {
             Err(())
         } else {
-            Ok(path_router)
+            Ok((path_router, trailing_slash_aliases))
         }
     }
 
@@ -267,6 +441,8 @@ impl Router {
 
         let scope_based_fallback_router = FallbackTree::new(&scope_id2fallback_id, scope_graph);
 
+        // It only holds catch-alls: we prepend a `/` to all keys since `matchit` would otherwise
+        // reject domain-specific catch-alls (see `domain::is_domain_catch_all`).
         let mut path_based_fallback_router = matchit::Router::new();
         let mut path_catchall2fallback_id = BTreeMap::new();
         for (fallback_id, component) in raw_user_component_db.iter() {
@@ -305,7 +481,7 @@ impl Router {
 
             path_catchall2fallback_id.insert(fallback_path.clone(), fallback_id);
             path_based_fallback_router
-                .insert(fallback_path, fallback_id)
+                .insert(format!("/{fallback_path}"), fallback_id)
                 .unwrap();
         }
 
@@ -325,10 +501,16 @@ impl Router {
             };
 
             let path_fallback = path_based_fallback_router
-                .at(router_key.path.as_str())
+                .at(&format!("/{}", router_key.path))
                 .ok()
                 .map(|m| m.value)
                 .copied();
+            // Routes inherited from another version of the API use the fallback of the version
+            // that inherits them.
+            let scope_id = raw_user_component_db
+                .inherited_route_id2scope_id
+                .get(&handler_id)
+                .unwrap_or(scope_id);
             let scope_fallback_id =
                 scope_based_fallback_router.find_fallback_id(*scope_id, scope_graph);
             match path_fallback {
@@ -412,13 +594,13 @@ impl Router {
     ) -> Result<(), ()> {
         let n_diagnostics = diagnostics.len();
 
-        let mut method_aware_router = matchit::Router::<u32>::new();
-        // Route id <> (fallback_id <> (handler_id <> method guards))
+        // Path <> (fallback_id <> (handler_id <> method guards))
+        // Paths are compared verbatim: a route is only affected by the method guards of the
+        // other routes registered against the very same path.
         let mut map: BTreeMap<
-            u32,
+            &str,
             BTreeMap<UserComponentId, BTreeMap<UserComponentId, BTreeSet<String>>>,
         > = BTreeMap::default();
-        let mut next_route_id = 0;
         for (handler_id, component) in raw_user_component_db.iter() {
             let UserComponent::RequestHandler { router_key, .. } = component else {
                 continue;
@@ -433,19 +615,8 @@ impl Router {
                 }
                 MethodGuard::Some(g) => g,
             };
-            let route_id = match method_aware_router.at_mut(router_key.path.as_str()) {
-                Ok(match_) => *match_.value,
-                Err(_) => {
-                    let route_id = next_route_id;
-                    next_route_id += 1;
-                    method_aware_router
-                        .insert(router_key.path.clone(), route_id)
-                        .unwrap();
-                    route_id
-                }
-            };
             let fallback_id = route_id2fallback_id[&handler_id];
-            map.entry(route_id)
+            map.entry(router_key.path.as_str())
                 .or_default()
                 .entry(fallback_id)
                 .or_default()
@@ -486,6 +657,23 @@ impl Router {
     }
 }
 
+/// The other form of `path` with respect to trailing slashes—e.g. `/users/` for `/users`
+/// and vice versa.
+///
+/// It returns `None` for the root path and for paths that end with a catch-all parameter,
+/// since they already match both forms.
+fn trailing_slash_alternate(path: &str) -> Option<String> {
+    let (_, path_template) = split_routing_key(path);
+    if path_template == "/" || path_template.rsplit('/').next()?.contains('*') {
+        return None;
+    }
+    let alternate = match path.strip_suffix('/') {
+        Some(stripped) => stripped.to_owned(),
+        None => format!("{path}/"),
+    };
+    Some(alternate)
+}
+
 fn prefix_ends_with_capture(path: &str) -> Option<Capture> {
     // Prefixes, if not empty, **cannot** end with a `/`.
     // Therefore we will always get a `Some` from `split_last` and it won't be empty.
@@ -653,9 +841,11 @@ fn push_fallback_ambiguity_diagnostic(
             .fully_qualified_path()
             .join("::")
     };
-    let path_prefix = raw_user_component_db.fallback_id2path_prefix[&path_fallback_id]
-        .as_ref()
-        .unwrap();
+    let path_prefix = display_routing_key(
+        raw_user_component_db.fallback_id2path_prefix[&path_fallback_id]
+            .as_ref()
+            .unwrap(),
+    );
     let error = anyhow::anyhow!(
         "Routing logic can't be ambiguous.\n\
         You registered `{path_fallback}` as the fallback handler for all unmatched incoming requests \
@@ -664,7 +854,7 @@ fn push_fallback_ambiguity_diagnostic(
         It was registered under a different blueprint, with a different fallback handler: {scope_fallback}.\n\
         I can't determine which fallback is the most appropriate one for incoming `{}` requests \
         with a method that doesn't match the ones you registered a handler for.",
-        router_key.path
+        display_routing_key(&router_key.path)
     );
     let diagnostic = CompilerDiagnostic::builder(route_source, error)
         .optional_label(label)
@@ -697,7 +887,7 @@ fn push_fallback_method_ambiguity_diagnostic(
     else {
         unreachable!()
     };
-    let route_path = router_key.path.as_str();
+    let route_path = display_routing_key(&router_key.path);
     let mut err_msg = "Routing logic can't be ambiguous.\n\
         You registered:\n"
         .to_string();
@@ -803,7 +993,7 @@ fn push_matchit_diagnostic(
     // diagnostics we emit.
     let error = match error {
         InsertError::Conflict { with } => {
-            anyhow!("This route path, `{}`, conflicts with the path of another route you already registered, `{}`.", path, with)
+            anyhow!("This route path, `{}`, conflicts with the path of another route you already registered, `{}`.", display_routing_key(path), display_routing_key(&with))
         }
         InsertError::TooManyParams => {
             anyhow!("You can only register one path parameter per each path segment.")
@@ -817,6 +1007,103 @@ fn push_matchit_diagnostic(
         _ => error.into(),
     };
 
+    let location = raw_user_component_db.get_location(raw_user_component_id);
+    let source = match location.source_file(package_graph) {
+        Ok(s) => s,
+        Err(e) => {
+            diagnostics.push(e.into());
+            return;
+        }
+    };
+    let label = diagnostic::get_route_path_span(&source, location).labeled(format!(
+        "The problematic path{}",
+        version_suffix(raw_user_component_db, raw_user_component_id)
+    ));
+    let diagnostic = CompilerDiagnostic::builder(source, error).optional_label(label);
+    diagnostics.push(diagnostic.build().into());
+}
+
+/// Describe the version of the API that a request handler belongs to, if any,
+/// to be appended to the label of a conflicting route—e.g. `, inherited by `v2` from `v1``.
+fn version_suffix(raw_user_component_db: &RawUserComponentDb, id: UserComponentId) -> String {
+    match raw_user_component_db.handler_id2version.get(&id) {
+        Some(RouteVersion {
+            name,
+            inherited_from: Some(inherited_from),
+        }) => format!(", inherited by `{name}` from `{inherited_from}`"),
+        Some(RouteVersion {
+            name,
+            inherited_from: None,
+        }) => format!(", registered for `{name}`"),
+        None => String::new(),
+    }
+}
+
+fn push_trailing_slash_conflict_diagnostic(
+    path: &str,
+    id: UserComponentId,
+    other_path: &str,
+    other_id: UserComponentId,
+    raw_user_component_db: &RawUserComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<miette::Error>,
+) {
+    let path = display_routing_key(path);
+    let other_path = display_routing_key(other_path);
+    let mut annotated_snippets = Vec::with_capacity(2);
+    for (i, id) in [id, other_id].into_iter().enumerate() {
+        let location = raw_user_component_db.get_location(id);
+        let source = match location.source_file(package_graph) {
+            Ok(s) => s,
+            Err(e) => {
+                diagnostics.push(e.into());
+                continue;
+            }
+        };
+        if let Some(s) = diagnostic::get_route_path_span(&source, location) {
+            let label = s.labeled(format!("The {} route", ZeroBasedOrdinal(i)));
+            annotated_snippets.push(AnnotatedSnippet::new(source, label));
+        }
+    }
+    let mut annotated_snippets = annotated_snippets.into_iter();
+    let Some(first) = annotated_snippets.next() else {
+        return;
+    };
+    let overall = CompilerDiagnostic::builder(first.source_code, anyhow!(
+            "You registered routes for both `{path}` and `{other_path}`, but they are the same path \
+            according to your trailing slash policy, `TrailingSlash::MatchBoth`."
+        ))
+        .labels(first.labels.into_iter())
+        .additional_annotated_snippets(annotated_snippets)
+        .help(
+            "Remove one of the two routes, or switch to a different trailing slash policy \
+            via `Blueprint::trailing_slash`.".into()
+        );
+    diagnostics.push(overall.build().into());
+}
+
+fn push_trailing_slash_alias_diagnostic(
+    path: &str,
+    alternate: &str,
+    raw_user_component_id: UserComponentId,
+    error: matchit::InsertError,
+    trailing_slash: TrailingSlash,
+    raw_user_component_db: &RawUserComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<miette::Error>,
+) {
+    let path = display_routing_key(path);
+    let alternate = display_routing_key(alternate);
+    let conflict = match error {
+        InsertError::Conflict { with } => {
+            format!(" conflicts with `{}`", display_routing_key(&with))
+        }
+        _ => " can't be added to the router".to_string(),
+    };
+    let error = anyhow!(
+        "Your trailing slash policy, `TrailingSlash::{trailing_slash:?}`, requires the router to \
+        match `{alternate}` on top of `{path}`, but `{alternate}`{conflict}."
+    );
     let location = raw_user_component_db.get_location(raw_user_component_id);
     let source = match location.source_file(package_graph) {
         Ok(s) => s,
@@ -827,22 +1114,70 @@ fn push_matchit_diagnostic(
     };
     let label = diagnostic::get_route_path_span(&source, location)
         .labeled("The problematic path".to_string());
-    let diagnostic = CompilerDiagnostic::builder(source, error).optional_label(label);
+    let diagnostic = CompilerDiagnostic::builder(source, error)
+        .optional_label(label)
+        .help(
+            "Change the path of one of the conflicting routes, or switch to `TrailingSlash::Strict` \
+            via `Blueprint::trailing_slash`."
+                .into(),
+        );
     diagnostics.push(diagnostic.build().into());
 }
 
+fn push_param_constraints_mismatch_diagnostic(
+    path: &str,
+    first_id: UserComponentId,
+    second_id: UserComponentId,
+    raw_user_component_db: &RawUserComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<miette::Error>,
+) {
+    let path = display_routing_key(path);
+    let mut annotated_snippets = Vec::with_capacity(2);
+    for (i, id) in [first_id, second_id].into_iter().enumerate() {
+        let location = raw_user_component_db.get_location(id);
+        let source = match location.source_file(package_graph) {
+            Ok(s) => s,
+            Err(e) => {
+                diagnostics.push(e.into());
+                continue;
+            }
+        };
+        if let Some(s) = diagnostic::get_route_path_span(&source, location) {
+            let label = s.labeled(format!("The {} route", ZeroBasedOrdinal(i)));
+            annotated_snippets.push(AnnotatedSnippet::new(source, label));
+        }
+    }
+    let mut annotated_snippets = annotated_snippets.into_iter();
+    let Some(first) = annotated_snippets.next() else {
+        return;
+    };
+    let overall = CompilerDiagnostic::builder(first.source_code, anyhow!(
+            "All routes registered for `{path}` must use the same constraints for their path parameters, \
+            but I found two routes with different constraints."
+        ))
+        .labels(first.labels.into_iter())
+        .additional_annotated_snippets(annotated_snippets)
+        .help(
+            "Constraints are checked after the path has been matched, therefore they can't be used \
+            to choose between routes registered for the same path. Use the same constraints for all of them.".into()
+        );
+    diagnostics.push(overall.build().into());
+}
+
 fn push_router_conflict_diagnostic(
     path: &str,
-    method: &str,
-    raw_user_component_ids: &[&UserComponentId],
+    methods: &[&str],
+    raw_user_component_ids: &[UserComponentId],
     raw_user_component_db: &RawUserComponentDb,
     package_graph: &PackageGraph,
     diagnostics: &mut Vec<miette::Error>,
 ) {
+    let path = display_routing_key(path);
     let n_unique_handlers = raw_user_component_ids.len();
     let mut annotated_snippets: Vec<AnnotatedSnippet> = Vec::with_capacity(n_unique_handlers);
     for (i, raw_user_component_id) in raw_user_component_ids.iter().enumerate() {
-        let location = raw_user_component_db.get_location(**raw_user_component_id);
+        let location = raw_user_component_db.get_location(*raw_user_component_id);
         let source = match location.source_file(package_graph) {
             Ok(s) => s,
             Err(e) => {
@@ -851,21 +1186,67 @@ fn push_router_conflict_diagnostic(
             }
         };
         if let Some(s) = diagnostic::get_f_macro_invocation_span(&source, location) {
-            let label = s.labeled(format!("The {} conflicting handler", ZeroBasedOrdinal(i)));
+            let label = s.labeled(format!(
+                "The {} conflicting handler{}",
+                ZeroBasedOrdinal(i),
+                version_suffix(raw_user_component_db, *raw_user_component_id)
+            ));
             annotated_snippets.push(AnnotatedSnippet::new(source, label));
         }
     }
+    let has_inherited_routes = raw_user_component_ids.iter().any(|id| {
+        raw_user_component_db
+            .handler_id2version
+            .get(id)
+            .is_some_and(|v| v.inherited_from.is_some())
+    });
+    let help = if has_inherited_routes {
+        "You can only register one request handler for each path+method combination. \
+        A version of your API overrides an inherited route only if it registers a route with the \
+        same path and method guard: adjust the method guard of the overriding route or \
+        remove the inherited route via `RegisteredApiVersion::remove_route`."
+    } else {
+        "You can only register one request handler for each path+method combination. \
+        Remove all but one of the conflicting request handlers."
+    };
     let mut annotated_snippets = annotated_snippets.into_iter();
     let first = annotated_snippets.next().unwrap();
-    let overall = CompilerDiagnostic::builder(first.source_code, anyhow!(
+    let error = match methods {
+        [method] => anyhow!(
             "I don't know how to route incoming `{method} {path}` requests: you have registered {n_unique_handlers} \
             different request handlers for this path+method combination."
-        ))
+        ),
+        _ => anyhow!(
+            "I don't know how to route incoming `{path}` requests for the following methods: {}.\n\
+            You have registered {n_unique_handlers} different request handlers for each of these \
+            path+method combinations.",
+            methods.iter().map(|m| format!("`{m}`")).join(", ")
+        ),
+    };
+    let overall = CompilerDiagnostic::builder(first.source_code, error)
         .labels(first.labels.into_iter())
         .additional_annotated_snippets(annotated_snippets)
-        .help(
-            "You can only register one request handler for each path+method combination. \
-            Remove all but one of the conflicting request handlers.".into()
-        );
+        .help(help.into());
     diagnostics.push(overall.build().into());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::trailing_slash_alternate;
+
+    #[test]
+    fn trailing_slash_alternates() {
+        assert_eq!(trailing_slash_alternate("/users").unwrap(), "/users/");
+        assert_eq!(
+            trailing_slash_alternate("/users/:id/").unwrap(),
+            "/users/:id"
+        );
+        assert_eq!(
+            trailing_slash_alternate("~com/example/@/users").unwrap(),
+            "~com/example/@/users/"
+        );
+        assert_eq!(trailing_slash_alternate("/"), None);
+        assert_eq!(trailing_slash_alternate("~com/example/@/"), None);
+        assert_eq!(trailing_slash_alternate("/files/*path"), None);
+    }
+}
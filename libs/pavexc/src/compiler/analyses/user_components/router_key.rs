@@ -1,10 +1,15 @@
 use itertools::Itertools;
 use pavex_bp_schema::MethodGuard;
 
+use crate::compiler::analyses::user_components::domain::display_routing_key;
+
 /// A `RouterKey` uniquely identifies a subset of incoming requests for routing purposes.
 /// Each request handler is associated with a `RouterKey`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct RouterKey {
+    /// The routing key for the route—i.e. its path template, prefixed with its domain
+    /// if it was nested under one.
+    /// See [`domain`](super::domain) for more details.
     pub path: String,
     pub method_guard: MethodGuard,
 }
@@ -17,6 +22,6 @@ impl RouterKey {
             MethodGuard::Any => String::from("*"),
             MethodGuard::Some(method_set) => method_set.clone().iter().join("|").to_string(),
         };
-        format!("{} {}", method_guard, self.path)
+        format!("{} {}", method_guard, display_routing_key(&self.path))
     }
 }
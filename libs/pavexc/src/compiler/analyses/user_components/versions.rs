@@ -0,0 +1,417 @@
+//! Versions of an API, nested via `Blueprint::nest_version`.
+//!
+//! A version can inherit the routes of another version nested against the same parent
+//! blueprint, via `RegisteredApiVersion::inherit_from`.
+//! Inherited routes are registered a second time, under the path prefix of the inheriting
+//! version, unless the inheriting version overrides them (i.e. it registers a route for the
+//! same path and method guard) or removes them explicitly.
+//!
+//! Inheritance is transitive: if `v3` inherits from `v2` and `v2` inherits from `v1`,
+//! `v3` exposes the routes of `v1` that haven't been overridden or removed by either `v2` or `v3`.
+use std::collections::{BTreeMap, BTreeSet};
+
+use pavex_bp_schema::{
+    ApiVersion, Blueprint, Component, InheritedVersion, MethodGuard, NestedBlueprint, RemovedRoute,
+};
+
+use crate::compiler::analyses::user_components::param_constraints;
+
+/// A version that inherits, directly or transitively, the routes of another version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Heir {
+    /// The name of the inheriting version—e.g. `v2`.
+    pub(super) name: String,
+    /// The path prefix of the inheriting version, relative to its parent blueprint—e.g. `/v2`.
+    pub(super) path_prefix: String,
+    /// The versions that routes go through before reaching the heir, starting from the one
+    /// closest to the heir.
+    /// It's empty if the heir inherits directly from the version.
+    pub(super) intermediates: Vec<String>,
+    /// The routes that don't reach the heir, because a version along the way overrides
+    /// or removes them.
+    excluded: BTreeSet<RouteSignature>,
+}
+
+impl Heir {
+    /// Returns `true` if the heir exposes the route of the inherited version with the given
+    /// path (relative to the version prefix) and method guard.
+    pub(super) fn inherits(&self, path: &str, method_guard: &MethodGuard) -> bool {
+        !self
+            .excluded
+            .contains(&RouteSignature::new(path, method_guard))
+    }
+}
+
+/// An invalid inheritance declaration.
+#[derive(Debug)]
+pub(super) enum InheritanceError<'a> {
+    /// The inherited version is not nested against the same parent blueprint.
+    UnknownVersion {
+        version: &'a ApiVersion,
+        inherited: &'a InheritedVersion,
+    },
+    /// The inherited version inherits, directly or transitively, from the inheriting version.
+    Cycle {
+        /// The versions involved in the cycle, starting from the one that closes it.
+        versions: Vec<&'a str>,
+        inherited: &'a InheritedVersion,
+    },
+    /// The removed route doesn't match any of the routes that the version inherits.
+    UnknownRemovedRoute {
+        version: &'a ApiVersion,
+        removed: &'a RemovedRoute,
+    },
+}
+
+/// Resolve the inheritance relationships between the versions nested against `bp`.
+///
+/// It returns the heirs of each version, keyed by version name, alongside the invalid
+/// inheritance declarations.
+/// Invalid declarations are ignored when computing the heirs.
+pub(super) fn resolve_inheritance(
+    bp: &Blueprint,
+) -> (BTreeMap<String, Vec<Heir>>, Vec<InheritanceError<'_>>) {
+    let versions: Vec<(&ApiVersion, &NestedBlueprint)> = bp
+        .components
+        .iter()
+        .filter_map(|c| match c {
+            Component::NestedBlueprint(b) => b.version.as_ref().map(|v| (v, b)),
+            _ => None,
+        })
+        .collect();
+    let mut heirs = BTreeMap::new();
+    if versions.is_empty() {
+        return (heirs, Vec::new());
+    }
+
+    let mut errors = Vec::new();
+    let mut name2index = BTreeMap::new();
+    for (i, (version, _)) in versions.iter().enumerate() {
+        // Duplicate names are reported as path conflicts, we keep the first one.
+        name2index.entry(version.name.as_str()).or_insert(i);
+    }
+    let mut parents: Vec<Option<usize>> = versions
+        .iter()
+        .map(|(version, _)| {
+            let inherited = version.inherits_from.as_ref()?;
+            let parent = name2index.get(inherited.name.as_str()).copied();
+            if parent.is_none() {
+                errors.push(InheritanceError::UnknownVersion { version, inherited });
+            }
+            parent
+        })
+        .collect();
+    // We break each cycle at the first version that closes it, to report it only once.
+    for i in 0..versions.len() {
+        let mut chain = vec![i];
+        let mut current = parents[i];
+        while let Some(j) = current {
+            if j == i {
+                let inherited = versions[i].0.inherits_from.as_ref().unwrap();
+                errors.push(InheritanceError::Cycle {
+                    versions: chain.iter().map(|k| versions[*k].0.name.as_str()).collect(),
+                    inherited,
+                });
+                parents[i] = None;
+                break;
+            }
+            if chain.contains(&j) {
+                // A cycle that doesn't involve `i`: it'll be reported when we get to it.
+                break;
+            }
+            chain.push(j);
+            current = parents[j];
+        }
+    }
+
+    let excluded: Vec<BTreeSet<RouteSignature>> = versions
+        .iter()
+        .map(|(version, b)| {
+            let mut excluded = BTreeSet::new();
+            collect_routes(&b.blueprint, "", &mut excluded);
+            excluded.extend(
+                version
+                    .removed_routes
+                    .iter()
+                    .map(|r| RouteSignature::new(&r.path, &r.method_guard)),
+            );
+            excluded
+        })
+        .collect();
+
+    // The routes exposed by each version, including the inherited ones.
+    let mut exposed: Vec<Option<BTreeSet<RouteSignature>>> = vec![None; versions.len()];
+    for i in 0..versions.len() {
+        exposed_routes(i, &versions, &parents, &mut exposed);
+    }
+    for (i, (version, _)) in versions.iter().enumerate() {
+        for removed in &version.removed_routes {
+            let is_inherited = parents[i].is_some_and(|parent| {
+                exposed[parent]
+                    .as_ref()
+                    .unwrap()
+                    .contains(&RouteSignature::new(&removed.path, &removed.method_guard))
+            });
+            if !is_inherited {
+                errors.push(InheritanceError::UnknownRemovedRoute { version, removed });
+            }
+        }
+    }
+
+    for (i, (version, b)) in versions.iter().enumerate() {
+        let mut intermediates = Vec::new();
+        let mut accumulated = excluded[i].clone();
+        let mut current = parents[i];
+        while let Some(j) = current {
+            let heir = Heir {
+                name: version.name.clone(),
+                path_prefix: b.path_prefix.clone().unwrap_or_default(),
+                intermediates: intermediates.clone(),
+                excluded: accumulated.clone(),
+            };
+            heirs
+                .entry(versions[j].0.name.clone())
+                .or_insert_with(Vec::new)
+                .push(heir);
+            intermediates.push(versions[j].0.name.clone());
+            accumulated.extend(excluded[j].iter().cloned());
+            current = parents[j];
+        }
+    }
+    (heirs, errors)
+}
+
+/// The routes that the versions nested against `bp` inherit, with their paths relative to
+/// `bp`—e.g. `/v2/users` for a `/users` route inherited by `v2` from `v1`.
+pub(super) fn inherited_routes(bp: &Blueprint) -> Vec<(String, MethodGuard)> {
+    let (heirs, _) = resolve_inheritance(bp);
+    let mut inherited = Vec::new();
+    for component in &bp.components {
+        let Component::NestedBlueprint(b) = component else {
+            continue;
+        };
+        let Some(heirs) = b.version.as_ref().and_then(|v| heirs.get(&v.name)) else {
+            continue;
+        };
+        let mut routes = BTreeSet::new();
+        collect_routes(&b.blueprint, "", &mut routes);
+        for route in &routes {
+            for heir in heirs.iter().filter(|heir| !heir.excluded.contains(route)) {
+                inherited.push((
+                    format!("{}{}", heir.path_prefix, route.path),
+                    route.method_guard.clone(),
+                ));
+            }
+        }
+    }
+    inherited
+}
+
+/// Compute (and memoize) the routes exposed by the `i`-th version, relative to its prefix.
+///
+/// `parents` must not contain cycles.
+fn exposed_routes(
+    i: usize,
+    versions: &[(&ApiVersion, &NestedBlueprint)],
+    parents: &[Option<usize>],
+    exposed: &mut Vec<Option<BTreeSet<RouteSignature>>>,
+) {
+    if exposed[i].is_some() {
+        return;
+    }
+    let (version, b) = versions[i];
+    let mut routes = BTreeSet::new();
+    collect_routes(&b.blueprint, "", &mut routes);
+    if let Some(parent) = parents[i] {
+        exposed_routes(parent, versions, parents, exposed);
+        let removed: BTreeSet<_> = version
+            .removed_routes
+            .iter()
+            .map(|r| RouteSignature::new(&r.path, &r.method_guard))
+            .collect();
+        let inherited = exposed[parent]
+            .as_ref()
+            .unwrap()
+            .iter()
+            .filter(|r| !removed.contains(r))
+            .cloned()
+            .collect::<Vec<_>>();
+        routes.extend(inherited);
+    }
+    exposed[i] = Some(routes);
+}
+
+/// Collect the routes registered against `bp` and the blueprints nested under it,
+/// with their paths relative to `bp`.
+fn collect_routes(bp: &Blueprint, path_prefix: &str, routes: &mut BTreeSet<RouteSignature>) {
+    for component in &bp.components {
+        match component {
+            Component::Route(r) => {
+                routes.insert(RouteSignature::new(
+                    &format!("{path_prefix}{}", r.path),
+                    &r.method_guard,
+                ));
+            }
+            Component::NestedBlueprint(b) if b.domain.is_none() => {
+                let path_prefix =
+                    format!("{path_prefix}{}", b.path_prefix.as_deref().unwrap_or(""));
+                collect_routes(&b.blueprint, &path_prefix, routes);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The path and method guard of a route.
+///
+/// Parameter constraints are ignored: they're not part of the routing key, therefore
+/// `/users/:id<u64>` and `/users/:id` identify the same route.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct RouteSignature {
+    path: String,
+    method_guard: MethodGuard,
+}
+
+impl RouteSignature {
+    fn new(path: &str, method_guard: &MethodGuard) -> Self {
+        let path = match param_constraints::strip_constraints(path) {
+            Ok((path, _)) => path,
+            Err(_) => path.to_owned(),
+        };
+        Self {
+            path,
+            method_guard: method_guard.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use pavex_bp_schema::{
+        ApiVersion, Blueprint, Callable, Component, InheritedVersion, Location, MethodGuard,
+        NestedBlueprint, RawCallableIdentifiers, RemovedRoute, Route,
+    };
+
+    use super::{resolve_inheritance, InheritanceError};
+
+    fn location() -> Location {
+        Location {
+            line: 1,
+            column: 1,
+            file: "src/lib.rs".into(),
+        }
+    }
+
+    fn get() -> MethodGuard {
+        MethodGuard::Some(BTreeSet::from(["GET".to_owned()]))
+    }
+
+    fn route(path: &str) -> Component {
+        Component::Route(Route {
+            path: path.into(),
+            method_guard: get(),
+            request_handler: Callable {
+                callable: RawCallableIdentifiers::from_raw_parts(
+                    "crate::handler".into(),
+                    "app".into(),
+                ),
+                location: location(),
+            },
+            error_handler: None,
+            name: None,
+            constructors: vec![],
+            redirect: None,
+        })
+    }
+
+    fn version(name: &str, inherits_from: Option<&str>, routes: &[&str]) -> Component {
+        Component::NestedBlueprint(NestedBlueprint {
+            blueprint: Blueprint {
+                creation_location: location(),
+                components: routes.iter().map(|p| route(p)).collect(),
+                router_settings: Default::default(),
+            },
+            path_prefix: Some(format!("/{name}")),
+            domain: None,
+            version: Some(ApiVersion {
+                name: name.into(),
+                inherits_from: inherits_from.map(|name| InheritedVersion {
+                    name: name.into(),
+                    location: location(),
+                }),
+                removed_routes: vec![],
+                selector: None,
+            }),
+            nesting_location: location(),
+        })
+    }
+
+    fn blueprint(components: Vec<Component>) -> Blueprint {
+        Blueprint {
+            creation_location: location(),
+            components,
+            router_settings: Default::default(),
+        }
+    }
+
+    #[test]
+    fn inheritance_is_transitive() {
+        let bp = blueprint(vec![
+            version("v1", None, &["/users", "/orders"]),
+            version("v2", Some("v1"), &["/users/:id<u64>"]),
+            version("v3", Some("v2"), &["/orders", "/users/:id"]),
+        ]);
+        let (heirs, errors) = resolve_inheritance(&bp);
+        assert!(errors.is_empty());
+
+        let v1_heirs = &heirs["v1"];
+        assert_eq!(v1_heirs.len(), 2);
+        let (v2, v3) = (&v1_heirs[0], &v1_heirs[1]);
+        assert_eq!((v2.name.as_str(), v2.path_prefix.as_str()), ("v2", "/v2"));
+        assert!(v2.intermediates.is_empty());
+        assert!(v2.inherits("/orders", &get()));
+        assert_eq!(v3.intermediates, vec!["v2".to_owned()]);
+        assert!(v3.inherits("/users", &get()));
+        // Overridden by `v3`.
+        assert!(!v3.inherits("/orders", &get()));
+
+        // Constraints are not part of the route signature.
+        assert!(!heirs["v2"][0].inherits("/users/:id<u64>", &get()));
+        assert!(!heirs.contains_key("v3"));
+    }
+
+    #[test]
+    fn invalid_declarations_are_reported() {
+        let mut v2 = version("v2", Some("v1"), &[]);
+        if let Component::NestedBlueprint(b) = &mut v2 {
+            b.version
+                .as_mut()
+                .unwrap()
+                .removed_routes
+                .push(RemovedRoute {
+                    path: "/unknown".into(),
+                    method_guard: get(),
+                    location: location(),
+                });
+        }
+        let bp = blueprint(vec![
+            version("v1", Some("v2"), &["/users"]),
+            v2,
+            version("v3", Some("v0"), &[]),
+        ]);
+        let (heirs, errors) = resolve_inheritance(&bp);
+        assert!(matches!(
+            &errors[..],
+            [
+                InheritanceError::UnknownVersion { version, .. },
+                InheritanceError::Cycle { versions, .. },
+                InheritanceError::UnknownRemovedRoute { removed, .. },
+            ] if version.name == "v3" && versions == &["v1", "v2"] && removed.path == "/unknown"
+        ));
+        // The cycle is broken at `v1`.
+        assert_eq!(heirs["v1"][0].name, "v2");
+        assert!(!heirs.contains_key("v2"));
+    }
+}
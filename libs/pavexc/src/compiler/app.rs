@@ -29,10 +29,15 @@ use crate::compiler::analyses::unused::detect_unused;
 use crate::compiler::analyses::user_components::UserComponentDb;
 use crate::compiler::computation::Computation;
 use crate::compiler::generated_app::GeneratedApp;
+use crate::compiler::openapi::OpenApiDocument;
 use crate::compiler::resolvers::CallableResolutionError;
 use crate::compiler::traits::{assert_trait_is_implemented, MissingTraitImplementationError};
+use crate::compiler::url_builders::UrlBuilder;
 use crate::compiler::utils::process_framework_path;
-use crate::compiler::{codegen, path_parameter_validation};
+use crate::compiler::{
+    codegen, extractor_validation, openapi, path_parameter_validation, redirect_validation,
+    url_builders,
+};
 use crate::diagnostic;
 use crate::diagnostic::{CompilerDiagnostic, LocationExt, SourceSpanExt};
 use crate::language::ResolvedType;
@@ -45,6 +50,8 @@ pub(crate) const GENERATED_APP_PACKAGE_ID: &str = "crate";
 pub struct App {
     package_graph: PackageGraph,
     router: Router,
+    url_builders: Vec<UrlBuilder>,
+    openapi_document: OpenApiDocument,
     handler_id2pipeline: IndexMap<ComponentId, RequestHandlerPipeline>,
     application_state_call_graph: ApplicationStateCallGraph,
     framework_item_db: FrameworkItemDb,
@@ -148,7 +155,40 @@ impl App {
             &krate_collection,
             &mut diagnostics,
         );
+        extractor_validation::verify_query_parameters(
+            &handler_id2pipeline,
+            &computation_db,
+            &component_db,
+            &package_graph,
+            &krate_collection,
+            &mut diagnostics,
+        );
+        redirect_validation::verify_redirect_injection(
+            &router,
+            &handler_id2pipeline,
+            &framework_item_db,
+            &component_db,
+            &package_graph,
+            &mut diagnostics,
+        );
         exit_on_errors!(diagnostics);
+        let url_builders = url_builders::url_builders(
+            &router,
+            &handler_id2pipeline,
+            &computation_db,
+            &component_db,
+            &package_graph,
+            &krate_collection,
+        );
+        let openapi_document = openapi::openapi_document(
+            &bp.creation_location,
+            &router,
+            &handler_id2pipeline,
+            &computation_db,
+            &component_db,
+            &package_graph,
+            &krate_collection,
+        );
 
         let runtime_singletons: IndexSet<(ResolvedType, ComponentId)> =
             get_required_singleton_types(
@@ -197,6 +237,8 @@ impl App {
             Self {
                 package_graph,
                 router,
+                url_builders,
+                openapi_document,
                 handler_id2pipeline,
                 component_db,
                 computation_db,
@@ -226,6 +268,7 @@ impl App {
         );
         let lib_rs = codegen::codegen_app(
             &self.router,
+            &self.url_builders,
             &self.handler_id2pipeline,
             &self.application_state_call_graph,
             &framework_bindings,
@@ -243,6 +286,11 @@ impl App {
         })
     }
 
+    /// An OpenAPI document describing the routes of the analysed application.
+    pub fn openapi_document(&self) -> &OpenApiDocument {
+        &self.openapi_document
+    }
+
     /// A representation of an `App` geared towards debugging and testing.
     pub fn diagnostic_representation(&self) -> AppDiagnostics {
         let (_, package_ids2deps) = codegen::codegen_manifest(
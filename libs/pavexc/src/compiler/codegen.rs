@@ -7,6 +7,7 @@ use guppy::graph::{ExternalSource, PackageSource};
 use guppy::PackageId;
 use indexmap::{IndexMap, IndexSet};
 use once_cell::sync::Lazy;
+use pavex_bp_schema::{Redirect, TrailingSlash, VersionSelector};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::{ItemEnum, ItemFn, ItemStruct};
@@ -21,8 +22,11 @@ use crate::compiler::analyses::processing_pipeline::{
     CodegenedRequestHandlerPipeline, RequestHandlerPipeline,
 };
 use crate::compiler::analyses::router::Router;
+use crate::compiler::analyses::user_components::domain;
+use crate::compiler::analyses::user_components::param_constraints::ParamConstraint;
 use crate::compiler::app::GENERATED_APP_PACKAGE_ID;
 use crate::compiler::computation::Computation;
+use crate::compiler::url_builders::{PathSegment, UrlBuilder};
 use crate::language::{Callable, GenericArgument, ResolvedType};
 use crate::rustdoc::{ALLOC_PACKAGE_ID_REPR, TOOLCHAIN_CRATES};
 
@@ -32,29 +36,47 @@ use super::generated_app::GeneratedManifest;
 pub(super) struct CodegenMethodRouter {
     pub(super) methods_and_pipelines: Vec<(BTreeSet<String>, CodegenedRequestHandlerPipeline)>,
     pub(super) catch_all_pipeline: CodegenedRequestHandlerPipeline,
+    /// The pipeline for requests whose path parameters don't satisfy their constraints.
+    pub(super) unmatched_pipeline: CodegenedRequestHandlerPipeline,
+    /// If `true`, `HEAD` requests are dispatched to the pipeline that handles `GET` requests.
+    ///
+    /// It's only set when automatic `HEAD` handling is enabled and there is
+    /// no handler registered explicitly for `HEAD` requests.
+    pub(super) route_head_to_get: bool,
+    /// The constraints on the values of the route parameters.
+    pub(super) param_constraints: BTreeMap<String, ParamConstraint>,
+    /// The target of the redirect, if the route was registered via `Blueprint::redirect`.
+    pub(super) redirect: Option<Redirect>,
 }
 
 impl CodegenMethodRouter {
     pub fn pipelines(&self) -> impl Iterator<Item = &CodegenedRequestHandlerPipeline> {
+        self.matched_pipelines()
+            .chain(std::iter::once(&self.unmatched_pipeline))
+    }
+
+    /// The pipelines that may handle a request whose path parameters satisfy their constraints.
+    fn matched_pipelines(&self) -> impl Iterator<Item = &CodegenedRequestHandlerPipeline> {
         self.methods_and_pipelines
             .iter()
             .map(|(_, p)| p)
             .chain(std::iter::once(&self.catch_all_pipeline))
     }
 
-    /// Returns `true` if any of the pipelines in this router needs `MatchedPathPattern`
-    /// as input type.
+    /// Returns `true` if any of the pipelines in this router, for requests whose path parameters
+    /// satisfy their constraints, needs `MatchedPathPattern` as input type.
     pub fn needs_matched_route(&self, framework_items_db: &FrameworkItemDb) -> bool {
         let matched_route_type = framework_items_db
             .get_type(FrameworkItemDb::matched_route_template_id())
             .unwrap();
-        self.pipelines()
+        self.matched_pipelines()
             .any(|pipeline| pipeline.needs_input_type(matched_route_type))
     }
 }
 
 pub(crate) fn codegen_app(
     router: &Router,
+    url_builders: &[UrlBuilder],
     handler_id2pipeline: &IndexMap<ComponentId, RequestHandlerPipeline>,
     application_state_call_graph: &ApplicationStateCallGraph,
     request_scoped_framework_bindings: &BiHashMap<Ident, ResolvedType>,
@@ -114,11 +136,26 @@ pub(crate) fn codegen_app(
             }
             let catch_all_pipeline =
                 handler_id2codegened_pipeline[&method_router.fallback_id].clone();
+            let unmatched_pipeline =
+                handler_id2codegened_pipeline[&method_router.unmatched_fallback_id].clone();
+            let route_head_to_get = router.settings.auto_head
+                && method_router
+                    .handler_id2methods
+                    .values()
+                    .any(|methods| methods.contains("GET"))
+                && !method_router
+                    .handler_id2methods
+                    .values()
+                    .any(|methods| methods.contains("HEAD"));
             map.insert(
                 path.to_owned(),
                 CodegenMethodRouter {
                     methods_and_pipelines,
                     catch_all_pipeline,
+                    unmatched_pipeline,
+                    route_head_to_get,
+                    param_constraints: method_router.param_constraints.clone(),
+                    redirect: method_router.redirect.clone(),
                 },
             );
         }
@@ -132,11 +169,28 @@ pub(crate) fn codegen_app(
         route_id2router_entry.insert(route_id as u32, router_entry.to_owned());
     }
 
-    let router_init = get_router_init(&route_id2path, &matchit_import_name);
+    // Additional paths added to the router to honor the trailing slash policy.
+    // They either share the route id of the registered path they stand for, or they
+    // point to a dedicated route id that redirects to the registered path.
+    let trailing_slash_redirect_id = (router.settings.trailing_slash == TrailingSlash::Redirect
+        && !router.trailing_slash_aliases.is_empty())
+    .then(|| route_id2path.len() as u32);
+    let aliases = router
+        .trailing_slash_aliases
+        .iter()
+        .map(|(alias, path)| {
+            let route_id = trailing_slash_redirect_id
+                .unwrap_or_else(|| *route_id2path.get_by_right(path).unwrap());
+            (alias.to_owned(), route_id)
+        })
+        .collect::<Vec<_>>();
+    let router_init = get_router_init(&route_id2path, &aliases, &matchit_import_name);
     let fallback_codegened_pipeline = &handler_id2codegened_pipeline[&router.root_fallback_id];
     let route_request = get_request_dispatcher(
         &route_id2router_entry,
         &route_id2path,
+        trailing_slash_redirect_id,
+        &router.version_selectors,
         fallback_codegened_pipeline,
         runtime_singleton_bindings,
         request_scoped_framework_bindings,
@@ -145,6 +199,7 @@ pub(crate) fn codegen_app(
         &http_import_name,
         &hyper_import_name,
     );
+    let urls = get_url_builders(url_builders, package_id2name, &pavex_import_name);
     let entrypoint = server_startup(&pavex_import_name);
     let alloc_extern_import = if package_id2name.contains_right(ALLOC_PACKAGE_ID_REPR) {
         // The fact that an item from `alloc` is used in the generated code does not imply
@@ -171,6 +226,7 @@ pub(crate) fn codegen_app(
         #entrypoint
         #router_init
         #route_request
+        #urls
         #(#handler_modules)*
     };
     Ok(code)
@@ -288,11 +344,21 @@ fn get_application_state_init(
     Ok(function)
 }
 
-fn get_router_init(route_id2path: &BiBTreeMap<u32, String>, matchit_import_name: &Ident) -> ItemFn {
+fn get_router_init(
+    route_id2path: &BiBTreeMap<u32, String>,
+    aliases: &[(String, u32)],
+    matchit_import_name: &Ident,
+) -> ItemFn {
     let mut router_init = quote! {
         let mut router = #matchit_import_name::Router::new();
     };
-    for (route_id, path) in route_id2path {
+    let aliases = aliases.iter().map(|(path, route_id)| (route_id, path));
+    // Domain-specific catch-alls must be inserted last, see `domain::is_domain_catch_all`.
+    let (domain_catch_alls, others): (Vec<_>, Vec<_>) = route_id2path
+        .iter()
+        .chain(aliases)
+        .partition(|(_, path)| domain::is_domain_catch_all(path));
+    for (route_id, path) in others.into_iter().chain(domain_catch_alls) {
         router_init = quote! {
             #router_init
             router.insert(#path, #route_id).unwrap();
@@ -310,9 +376,173 @@ fn get_router_init(route_id2path: &BiBTreeMap<u32, String>, matchit_import_name:
     .unwrap()
 }
 
+/// Generate the `urls` module, with a function to build the path of each named route.
+///
+/// Each segment of a route name becomes a module, apart from the last one which
+/// becomes the name of the function—e.g. `user.show` becomes `urls::user::show`.
+/// Nothing is generated if there are no named routes.
+fn get_url_builders(
+    url_builders: &[UrlBuilder],
+    package_id2name: &BiHashMap<PackageId, String>,
+    pavex: &Ident,
+) -> TokenStream {
+    #[derive(Default)]
+    struct UrlModule {
+        functions: Vec<TokenStream>,
+        children: BTreeMap<String, UrlModule>,
+    }
+
+    impl UrlModule {
+        fn render(&self) -> TokenStream {
+            let functions = &self.functions;
+            let children = self.children.iter().map(|(name, child)| {
+                let name = format_ident!("{}", name);
+                let child = child.render();
+                quote! {
+                    pub mod #name {
+                        #child
+                    }
+                }
+            });
+            quote! {
+                #(#functions)*
+                #(#children)*
+            }
+        }
+    }
+
+    if url_builders.is_empty() {
+        return quote! {};
+    }
+    let mut root = UrlModule::default();
+    for builder in url_builders {
+        let mut segments = builder.name.split('.').collect::<Vec<_>>();
+        let function_name = format_ident!("{}", segments.pop().unwrap());
+        let mut module = &mut root;
+        for segment in segments {
+            module = module.children.entry(segment.to_owned()).or_default();
+        }
+
+        let parameter_names = builder.parameter_names();
+        // The buffer must not shadow any of the inputs of the generated function.
+        let buffer = {
+            let mut buffer = "path".to_owned();
+            while parameter_names.iter().any(|name| name == &buffer) {
+                buffer.push('_');
+            }
+            format_ident!("{}", buffer)
+        };
+        let (inputs, values) = match &builder.path_params_type {
+            Some(ty) => {
+                // The generated function doesn't care about lifetimes: we elide them.
+                let mut ty = ty.to_owned();
+                let elided = ty
+                    .named_lifetime_parameters()
+                    .into_iter()
+                    .map(|l| (l, "_".to_owned()))
+                    .collect();
+                ty.rename_lifetime_parameters(&elided);
+                let ty = ty.syn_type(package_id2name);
+                let values = parameter_names
+                    .iter()
+                    .map(|name| {
+                        let field = format_ident!("{}", name);
+                        quote! { &params.#field }
+                    })
+                    .collect::<Vec<_>>();
+                (vec![quote! { params: &#ty }], values)
+            }
+            None => parameter_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let ident = syn::parse_str::<Ident>(name)
+                        .unwrap_or_else(|_| format_ident!("param{}", i));
+                    (quote! { #ident: impl std::fmt::Display }, quote! { #ident })
+                })
+                .unzip(),
+        };
+        let mut values = values.into_iter();
+        let statements = builder.segments().into_iter().map(|segment| match segment {
+            PathSegment::Literal(literal) => quote! {
+                #buffer.push_str(#literal);
+            },
+            PathSegment::Parameter { catch_all, .. } => {
+                let value = values.next().unwrap();
+                quote! {
+                    #pavex::router::push_path_param(&mut #buffer, #value, #catch_all);
+                }
+            }
+        });
+        let doc = format!(
+            " Build the path of the `{}` route—`{}`.",
+            builder.name, builder.path
+        );
+        module.functions.push(quote! {
+            #[doc = #doc]
+            pub fn #function_name(#(#inputs),*) -> String {
+                let mut #buffer = String::new();
+                #(#statements)*
+                #buffer
+            }
+        });
+    }
+    let urls = root.render();
+    quote! {
+        /// Build the paths of the named routes registered in your `Blueprint`.
+        pub mod urls {
+            #urls
+        }
+    }
+}
+
+/// Invoke the entrypoint of a request handler pipeline, creating a fresh
+/// `RequestContext` beforehand if the pipeline needs it.
+/// If the pipeline needs `ConnectionInfo` and it's not available, a `500 Internal Server Error`
+/// is returned instead.
+fn entrypoint_invocation(
+    pipeline: &CodegenedRequestHandlerPipeline,
+    singleton_bindings: &BiHashMap<Ident, ResolvedType>,
+    request_scoped_bindings: &BiHashMap<Ident, ResolvedType>,
+    server_state_ident: &Ident,
+    framework_items_db: &FrameworkItemDb,
+    pavex: &Ident,
+) -> TokenStream {
+    let invocation = pipeline.entrypoint_invocation(
+        singleton_bindings,
+        request_scoped_bindings,
+        server_state_ident,
+    );
+    let invocation = if pipeline.needs_request_context(framework_items_db) {
+        quote! {
+            {
+                let request_context = #pavex::request::RequestContext::new();
+                #invocation
+            }
+        }
+    } else {
+        invocation
+    };
+    if pipeline.needs_connection_info(framework_items_db) {
+        // The server couldn't determine the peer address of the underlying connection.
+        quote! {
+            {
+                let Some(connection_info) = connection_info else {
+                    return #pavex::response::Response::internal_server_error();
+                };
+                #invocation
+            }
+        }
+    } else {
+        invocation
+    }
+}
+
 fn get_request_dispatcher(
     route_id2router_entry: &BTreeMap<u32, CodegenMethodRouter>,
     route_id2path: &BiBTreeMap<u32, String>,
+    trailing_slash_redirect_id: Option<u32>,
+    version_selectors: &[(String, VersionSelector)],
     fallback_codegened_pipeline: &CodegenedRequestHandlerPipeline,
     singleton_bindings: &BiHashMap<Ident, ResolvedType>,
     request_scoped_bindings: &BiHashMap<Ident, ResolvedType>,
@@ -336,25 +566,44 @@ fn get_request_dispatcher(
     for (route_id, sub_router) in route_id2router_entry {
         let match_arm = if sub_router.methods_and_pipelines.is_empty() {
             // We just have the catch-all handler, we can skip the `match`.
-            sub_router.catch_all_pipeline.entrypoint_invocation(
+            let invocation = entrypoint_invocation(
+                &sub_router.catch_all_pipeline,
                 singleton_bindings,
                 request_scoped_bindings,
                 &server_state_ident,
-            )
+                framework_items_db,
+                pavex,
+            );
+            if sub_router
+                .catch_all_pipeline
+                .needs_allowed_methods(framework_items_db)
+            {
+                quote! {
+                    {
+                        let allowed_methods: #pavex::router::AllowedMethods = #pavex::router::MethodAllowList::from_iter(vec![]).into();
+                        #invocation
+                    }
+                }
+            } else {
+                invocation
+            }
         } else {
             let mut sub_router_dispatch_table = quote! {};
             let allowed_methods_init = {
+                let implicit_methods = sub_router.route_head_to_get.then_some("HEAD");
                 let allowed_methods = sub_router
                     .methods_and_pipelines
                     .iter()
-                    .flat_map(|(methods, _)| methods)
+                    .flat_map(|(methods, _)| methods.iter().map(String::as_str))
+                    .chain(implicit_methods)
                     .map(|m| {
-                        if WELL_KNOWN_METHODS.contains(m.as_str()) {
+                        if WELL_KNOWN_METHODS.contains(m) {
                             let i = format_ident!("{}", m);
                             quote! {
                                 #pavex::http::Method::#i
                             }
                         } else {
+                            // Custom methods have been validated when processing the blueprint.
                             let expect_msg = format!("{} is not a valid (custom) HTTP method", m);
                             quote! {
                                 #pavex::http::Method::try_from(#m).expect(#expect_msg)
@@ -369,10 +618,13 @@ fn get_request_dispatcher(
             };
 
             for (methods, request_pipeline) in &sub_router.methods_and_pipelines {
-                let invocation = request_pipeline.entrypoint_invocation(
+                let invocation = entrypoint_invocation(
+                    request_pipeline,
                     singleton_bindings,
                     request_scoped_bindings,
                     &server_state_ident,
+                    framework_items_db,
+                    pavex,
                 );
                 let invocation = if request_pipeline.needs_allowed_methods(framework_items_db) {
                     quote! {
@@ -402,6 +654,13 @@ fn get_request_dispatcher(
                     };
                 };
 
+                if sub_router.route_head_to_get && methods.contains("GET") {
+                    sub_router_dispatch_table = quote! {
+                        #sub_router_dispatch_table
+                        &#pavex::http::Method::HEAD => #pavex::router::into_head_response(#invocation),
+                    };
+                }
+
                 if !custom_methods.is_empty() {
                     let custom_methods = custom_methods.into_iter().map(|m| {
                         quote! {
@@ -415,7 +674,7 @@ fn get_request_dispatcher(
                 };
             }
             let matched_route_template = if sub_router.needs_matched_route(framework_items_db) {
-                let path = route_id2path.get_by_left(route_id).unwrap();
+                let path = domain::path_template(route_id2path.get_by_left(route_id).unwrap());
                 quote! {
                     let matched_route_template = #pavex::request::path::MatchedPathPattern::new(
                         #path
@@ -424,10 +683,13 @@ fn get_request_dispatcher(
             } else {
                 quote! {}
             };
-            let mut fallback_invocation = sub_router.catch_all_pipeline.entrypoint_invocation(
+            let mut fallback_invocation = entrypoint_invocation(
+                &sub_router.catch_all_pipeline,
                 singleton_bindings,
                 request_scoped_bindings,
                 &server_state_ident,
+                framework_items_db,
+                pavex,
             );
             if sub_router
                 .catch_all_pipeline
@@ -450,16 +712,104 @@ fn get_request_dispatcher(
                 }
             }
         };
+        let match_arm = if sub_router.param_constraints.is_empty() {
+            match_arm
+        } else {
+            // If the constraints are not satisfied, we behave as if the path didn't match.
+            let checks = sub_router
+                .param_constraints
+                .iter()
+                .map(|(name, constraint)| match constraint {
+                    ParamConstraint::Type(ty) => {
+                        let ty = format_ident!("{}", ty);
+                        quote! {
+                            #pavex::router::param_parses_as::<#ty>(&url_params, #name)
+                        }
+                    }
+                    ParamConstraint::Pattern(pattern) => quote! {
+                        {
+                            static PATTERN: std::sync::OnceLock<#pavex::router::ParamPattern> = std::sync::OnceLock::new();
+                            PATTERN
+                                .get_or_init(|| #pavex::router::ParamPattern::new(#pattern))
+                                .is_match(&url_params, #name)
+                        }
+                    },
+                });
+            let unmatched_invocation = entrypoint_invocation(
+                &sub_router.unmatched_pipeline,
+                singleton_bindings,
+                request_scoped_bindings,
+                &server_state_ident,
+                framework_items_db,
+                pavex,
+            );
+            let allowed_methods = if sub_router
+                .unmatched_pipeline
+                .needs_allowed_methods(framework_items_db)
+            {
+                quote! {
+                    let allowed_methods: #pavex::router::AllowedMethods = #pavex::router::MethodAllowList::from_iter(vec![]).into();
+                }
+            } else {
+                quote! {}
+            };
+            let unmatched_route = if sub_router
+                .unmatched_pipeline
+                .needs_matched_route(framework_items_db)
+            {
+                quote! {
+                    let matched_route_template = #pavex::request::path::MatchedPathPattern::new("*");
+                }
+            } else {
+                quote! {}
+            };
+            quote! {
+                if #(#checks)&&* {
+                    #match_arm
+                } else {
+                    #allowed_methods
+                    #unmatched_route
+                    #unmatched_invocation
+                }
+            }
+        };
+        // The redirect target is bound outside of the parameter constraints check, it's only
+        // used by the pipeline of the redirect route.
+        let match_arm = match &sub_router.redirect {
+            Some(Redirect { to, status })
+                if sub_router
+                    .pipelines()
+                    .any(|pipeline| pipeline.needs_redirect(framework_items_db)) =>
+            {
+                quote! {
+                    {
+                        let redirect_target = #pavex::router::Redirect::new(#to, #status);
+                        #match_arm
+                    }
+                }
+            }
+            _ => match_arm,
+        };
         route_dispatch_table = quote! {
             #route_dispatch_table
             #route_id => #match_arm,
         };
     }
 
-    let root_fallback_invocation = fallback_codegened_pipeline.entrypoint_invocation(
+    if let Some(route_id) = trailing_slash_redirect_id {
+        route_dispatch_table = quote! {
+            #route_dispatch_table
+            #route_id => #pavex::router::redirect_trailing_slash(&request_head),
+        };
+    }
+
+    let root_fallback_invocation = entrypoint_invocation(
+        fallback_codegened_pipeline,
         singleton_bindings,
         request_scoped_bindings,
         &server_state_ident,
+        framework_items_db,
+        pavex,
     );
     let unmatched_route = if fallback_codegened_pipeline.needs_matched_route(framework_items_db) {
         quote! {
@@ -475,16 +825,82 @@ fn get_request_dispatcher(
     } else {
         quote! {}
     };
+    // Requests are matched against domain-specific routes first, if there are any.
+    let has_domains = route_id2path
+        .right_values()
+        .any(|path| domain::split_routing_key(path).0.is_some());
+    let (host_routing_key, route_lookup) = if !version_selectors.is_empty() {
+        // Requests that match a version selector are matched against the routes of that
+        // version first, as if their path included the version prefix.
+        let versions = version_selectors.iter().map(|(prefix, selector)| {
+            let selector = match selector {
+                VersionSelector::Header { name, value } => {
+                    let name = name.to_ascii_lowercase();
+                    quote! { #pavex::router::VersionSelector::Header { name: #name, value: #value } }
+                }
+                VersionSelector::MediaType(media_type) => {
+                    quote! { #pavex::router::VersionSelector::MediaType(#media_type) }
+                }
+            };
+            quote! { (#prefix, #selector) }
+        });
+        (
+            quote! {
+                let routing_keys = #pavex::router::routing_keys(&request_head, #has_domains, &[#(#versions),*]);
+            },
+            quote! {
+                routing_keys
+                    .iter()
+                    .find_map(|key| server_state.router.at(key).ok())
+                    .ok_or(())
+            },
+        )
+    } else if has_domains {
+        (
+            quote! {
+                let host_routing_key = #pavex::router::host_routing_key(&request_head);
+            },
+            quote! {
+                host_routing_key
+                    .as_deref()
+                    .and_then(|key| server_state.router.at(key).ok())
+                    .map(Ok)
+                    .unwrap_or_else(|| server_state.router.at(&request_head.target.path()))
+            },
+        )
+    } else {
+        (
+            quote! {},
+            quote! { server_state.router.at(&request_head.target.path()) },
+        )
+    };
+    let needs_connection_info = route_id2router_entry
+        .values()
+        .flat_map(|sub_router| sub_router.pipelines())
+        .chain(std::iter::once(fallback_codegened_pipeline))
+        .any(|pipeline| pipeline.needs_connection_info(framework_items_db));
+    let connection_info = if needs_connection_info {
+        quote! {
+            let connection_info = request
+                .extensions()
+                .get::<#pavex::connection::ConnectionInfo>()
+                .copied();
+        }
+    } else {
+        quote! {}
+    };
     syn::parse2(quote! {
         async fn route_request(
             request: #http::Request<#hyper::body::Incoming>,
             #server_state_ident: std::sync::Arc<ServerState>
         ) -> #pavex::response::Response {
+            #connection_info
             let (request_head, request_body) = request.into_parts();
             #[allow(unused)]
             let request_body = #pavex::request::body::RawIncomingBody::from(request_body);
             let request_head: #pavex::request::RequestHead = request_head.into();
-            let matched_route = match server_state.router.at(&request_head.target.path()) {
+            #host_routing_key
+            let matched_route = match #route_lookup {
                 Ok(m) => m,
                 Err(_) => {
                     #allowed_methods
@@ -0,0 +1,690 @@
+use ahash::{HashMap, HashMapExt};
+use anyhow::anyhow;
+use guppy::graph::PackageGraph;
+use indexmap::{IndexMap, IndexSet};
+use miette::{Report, Severity};
+use pavex_bp_schema::{Lint, LintSetting};
+use petgraph::graph::NodeIndex;
+use petgraph::Direction;
+use rustdoc_types::{GenericParamDefKind, ItemEnum, StructKind, VariantKind};
+
+use crate::compiler::analyses::call_graph::{CallGraphNode, RawCallGraph};
+use crate::compiler::analyses::components::HydratedComponent;
+use crate::compiler::analyses::components::{ComponentDb, ComponentId};
+use crate::compiler::analyses::computations::ComputationDb;
+use crate::compiler::analyses::processing_pipeline::RequestHandlerPipeline;
+use crate::compiler::analyses::user_components::UserComponentId;
+use crate::compiler::component::Constructor;
+use crate::compiler::computation::{Computation, MatchResultVariant};
+use crate::compiler::resolvers::resolve_type;
+use crate::diagnostic;
+use crate::diagnostic::{CompilerDiagnostic, LocationExt, OptionalSourceSpanExt};
+use crate::language::{GenericArgument, PathType, ResolvedType};
+use crate::rustdoc::{CrateCollection, GlobalItemId};
+
+/// The maximum nesting depth we are willing to explore when checking if a type
+/// can be deserialized from a query string.
+/// It protects us from infinite recursion on recursive types.
+const MAX_NESTING_DEPTH: usize = 16;
+
+/// For each handler, check if query parameters are extracted from the incoming request.
+/// If so, check that the target type can be deserialized from a query string:
+///
+/// - it must be a plain struct with named fields;
+/// - each field must be a value, a sequence of values or a nested structure
+///   (encoded using the bracket syntax, e.g. `filter[status]=open`).
+///
+/// The field-level checks are only performed if the target type derives `serde::Deserialize`—we
+/// can't reason about custom implementations.
+/// For the same reason, we skip fields with a custom deserializer (e.g. `#[serde(deserialize_with = "...")]`)
+/// and types that are deserialized via another type (e.g. `#[serde(from = "...")]`).
+/// If the target type is defined in the current workspace and doesn't derive `serde::Deserialize`,
+/// a warning is emitted, unless [`Lint::CustomDeserialize`] is ignored on the constructor
+/// of `QueryParams`.
+#[tracing::instrument(name = "Verify query parameters", skip_all)]
+pub(crate) fn verify_query_parameters(
+    handler_id2pipeline: &IndexMap<ComponentId, RequestHandlerPipeline>,
+    computation_db: &ComputationDb,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    krate_collection: &CrateCollection,
+    diagnostics: &mut Vec<miette::Error>,
+) {
+    const QUERY_PARAMS: [&str; 4] = ["pavex", "request", "query", "QueryParams"];
+    for pipeline in handler_id2pipeline.values() {
+        let Some((graph, node_id, ty_)) =
+            find_extractor(pipeline, component_db, computation_db, &QUERY_PARAMS)
+        else {
+            continue;
+        };
+        let GenericArgument::TypeParameter(extracted_type) = &ty_.generic_arguments[0] else {
+            unreachable!()
+        };
+        let extractor = Extractor {
+            name: "QueryParams",
+            extracted_type,
+            call_graph: graph,
+            node_id,
+        };
+
+        let ResolvedType::ResolvedPath(path_type) = extracted_type else {
+            let reason = not_a_struct_reason(extracted_type, krate_collection);
+            report_unsupported_query_type(
+                &extractor,
+                &format!("{reason}, not a plain struct type"),
+                component_db,
+                package_graph,
+                diagnostics,
+            );
+            continue;
+        };
+        // We don't try to second-guess types defined outside of the current workspace
+        // (e.g. `HashMap`): they are expected to provide a meaningful `Deserialize` implementation.
+        if !is_defined_in_workspace(path_type, package_graph) {
+            continue;
+        }
+        let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+            rustdoc_item_id: path_type.rustdoc_id.clone().unwrap(),
+            package_id: path_type.package_id.clone(),
+        });
+        if !matches!(
+            &item.inner,
+            ItemEnum::Struct(s) if matches!(s.kind, StructKind::Plain { .. })
+        ) {
+            let reason = not_a_struct_reason(extracted_type, krate_collection);
+            report_unsupported_query_type(
+                &extractor,
+                &format!("{reason}, not a plain struct type"),
+                component_db,
+                package_graph,
+                diagnostics,
+            );
+            continue;
+        }
+        let deserialize_impl = deserialize_impl(path_type, krate_collection);
+        if deserialize_impl != DeserializeImpl::Derived {
+            if is_lint_ignored(&extractor, Lint::CustomDeserialize, component_db) {
+                continue;
+            }
+            report_missing_deserialize_derive(
+                &extractor,
+                deserialize_impl,
+                component_db,
+                package_graph,
+                diagnostics,
+            );
+            continue;
+        }
+        if let Err(e) = check_query_fields(path_type, krate_collection, 0) {
+            let reason = format!("`{}` {}", e.field_path.join("."), e.reason);
+            report_unsupported_query_type(
+                &extractor,
+                &reason,
+                component_db,
+                package_graph,
+                diagnostics,
+            );
+        }
+    }
+}
+
+/// A node in a call graph that extracts data from the incoming request (e.g. `QueryParams<T>`).
+struct Extractor<'a> {
+    /// The name of the extractor type, e.g. `QueryParams`.
+    name: &'static str,
+    /// The type that the extractor is trying to deserialize, e.g. `T` in `QueryParams<T>`.
+    extracted_type: &'a ResolvedType,
+    call_graph: &'a RawCallGraph,
+    /// The node for the `Ok` variant of the extractor's output.
+    node_id: NodeIndex,
+}
+
+/// Find the `Ok` node for an extractor with the given path (e.g. `pavex::request::query::QueryParams`)
+/// in the processing pipeline for a handler.
+///
+/// Since extractors are `RequestScoped` components, they appear as a `Compute` node in *at most*
+/// one of the call graphs in the processing pipeline.
+pub(super) fn find_extractor<'a>(
+    pipeline: &'a RequestHandlerPipeline,
+    component_db: &ComponentDb,
+    computation_db: &ComputationDb,
+    extractor_path: &[&str],
+) -> Option<(&'a RawCallGraph, NodeIndex, PathType)> {
+    pipeline.graph_iter().find_map(|graph| {
+        let graph = &graph.call_graph;
+        graph
+            .node_indices()
+            .find_map(|node_id| {
+                let node = &graph[node_id];
+                let CallGraphNode::Compute { component_id, .. } = node else {
+                    return None;
+                };
+                let hydrated_component =
+                    component_db.hydrated_component(*component_id, computation_db);
+                let HydratedComponent::Constructor(Constructor(Computation::MatchResult(m))) =
+                    hydrated_component
+                else {
+                    return None;
+                };
+                if m.variant != MatchResultVariant::Ok {
+                    return None;
+                }
+                let ResolvedType::ResolvedPath(ty_) = &m.output else {
+                    return None;
+                };
+                if ty_.base_type == extractor_path {
+                    Some((node_id, ty_.clone()))
+                } else {
+                    None
+                }
+            })
+            .map(|(node_id, ty_)| (graph, node_id, ty_))
+    })
+}
+
+/// Returns `true` if the user-registered constructor of the extractor ignores the given lint.
+fn is_lint_ignored(extractor: &Extractor, lint: Lint, component_db: &ComponentDb) -> bool {
+    let CallGraphNode::Compute { component_id, .. } = &extractor.call_graph[extractor.node_id]
+    else {
+        return false;
+    };
+    let constructor_id = component_db.fallible_id(*component_id);
+    let constructor_id = component_db
+        .derived_from(&constructor_id)
+        .unwrap_or(constructor_id);
+    component_db
+        .lints(constructor_id)
+        .is_some_and(|lints| lints.get(&lint) == Some(&LintSetting::Ignore))
+}
+
+/// Return the set of user component ids that consume a certain instance of an extractor
+/// as input parameter.
+pub(super) fn extractor_consumer_ids(
+    component_db: &ComponentDb,
+    call_graph: &RawCallGraph,
+    ok_extractor_node_id: NodeIndex,
+) -> IndexSet<UserComponentId> {
+    let mut consumer_ids = IndexSet::new();
+    let mut descendant_ids = call_graph
+        .neighbors_directed(ok_extractor_node_id, Direction::Outgoing)
+        .collect::<IndexSet<_>>();
+    while let Some(descendant_id) = descendant_ids.pop() {
+        let descendant_node = &call_graph[descendant_id];
+        if let CallGraphNode::Compute { component_id, .. } = descendant_node {
+            if let Some(user_component_id) = component_db.user_component_id(*component_id) {
+                consumer_ids.insert(user_component_id);
+            }
+        }
+    }
+    consumer_ids
+}
+
+/// Explain why a type is not a plain struct with named fields.
+fn not_a_struct_reason(type_: &ResolvedType, krate_collection: &CrateCollection) -> String {
+    match type_ {
+        ResolvedType::ResolvedPath(t) => {
+            let Some(item_id) = t.rustdoc_id.clone() else {
+                return format!("`{t:?}` is not a struct");
+            };
+            let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+                rustdoc_item_id: item_id,
+                package_id: t.package_id.clone(),
+            });
+            match item.inner {
+                ItemEnum::Union(_) => format!("`{t:?}` is an union"),
+                ItemEnum::Enum(_) => format!("`{t:?}` is an enum"),
+                ItemEnum::Struct(ref s) => match &s.kind {
+                    StructKind::Unit => {
+                        format!("`{t:?}` is a struct with no fields (a.k.a. unit struct)")
+                    }
+                    StructKind::Tuple(_) => format!("`{t:?}` is a tuple struct"),
+                    StructKind::Plain { .. } => format!("`{t:?}` is a plain struct"),
+                },
+                _ => format!("`{t:?}` is not a struct"),
+            }
+        }
+        ResolvedType::Reference(r) => format!("`{r:?}` is a reference"),
+        ResolvedType::Tuple(t) => format!("`{t:?}` is a tuple"),
+        ResolvedType::ScalarPrimitive(s) => format!("`{s:?}` is a primitive"),
+        ResolvedType::Slice(s) => format!("`{s:?}` is a slice"),
+        ResolvedType::Generic(g) => format!("`{g:?}` is a generic parameter"),
+    }
+}
+
+/// Returns `true` if the type is defined in one of the packages of the current workspace.
+fn is_defined_in_workspace(type_: &PathType, package_graph: &PackageGraph) -> bool {
+    type_.rustdoc_id.is_some()
+        && package_graph
+            .metadata(&type_.package_id)
+            .map(|m| m.in_workspace())
+            .unwrap_or(false)
+}
+
+/// How a type implements `serde::Deserialize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeserializeImpl {
+    /// Via `#[derive(serde::Deserialize)]`.
+    Derived,
+    /// Via a hand-written `impl<'de> serde::Deserialize<'de> for ...` block.
+    Manual,
+    /// We couldn't find an implementation of `serde::Deserialize` for the type.
+    Missing,
+}
+
+/// Determine how the type implements `serde::Deserialize`.
+///
+/// Type aliases don't need to be handled here: they have already been resolved to the
+/// type they point at when building the [`PathType`].
+fn deserialize_impl(type_: &PathType, krate_collection: &CrateCollection) -> DeserializeImpl {
+    // The canonical path of an item is its shortest public import path:
+    // `serde::Deserialize` rather than `serde::de::Deserialize`, where the trait is defined.
+    const DESERIALIZE_TRAIT_PATH: [&str; 2] = ["serde", "Deserialize"];
+
+    let Some(item_id) = type_.rustdoc_id.clone() else {
+        return DeserializeImpl::Missing;
+    };
+    let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+        rustdoc_item_id: item_id,
+        package_id: type_.package_id.clone(),
+    });
+    let impls = match &item.inner {
+        ItemEnum::Struct(s) => &s.impls,
+        ItemEnum::Enum(e) => &e.impls,
+        _ => return DeserializeImpl::Missing,
+    };
+    let mut found = DeserializeImpl::Missing;
+    for impl_id in impls {
+        let impl_item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+            rustdoc_item_id: impl_id.clone(),
+            package_id: type_.package_id.clone(),
+        });
+        let ItemEnum::Impl(impl_) = &impl_item.inner else {
+            continue;
+        };
+        let Some(trait_) = &impl_.trait_ else {
+            continue;
+        };
+        if impl_.negative {
+            continue;
+        }
+        let is_deserialize = match krate_collection.get_canonical_path_by_local_type_id(
+            &type_.package_id,
+            &trait_.id,
+            None,
+        ) {
+            Ok((_, trait_path)) => trait_path == DESERIALIZE_TRAIT_PATH,
+            // If we can't resolve the trait path, we fall back to its name.
+            // The code generated by `serde_derive` refers to the trait
+            // as `_serde::Deserialize`.
+            Err(_) => trait_.name.rsplit("::").next() == Some("Deserialize"),
+        };
+        if !is_deserialize {
+            continue;
+        }
+        if impl_item
+            .attrs
+            .iter()
+            .any(|attr| attr.contains("automatically_derived"))
+        {
+            return DeserializeImpl::Derived;
+        }
+        found = DeserializeImpl::Manual;
+    }
+    found
+}
+
+/// Returns the names of the options set via `#[serde(...)]` attributes on an item,
+/// e.g. `["rename", "default"]` for `#[serde(rename = "id", default)]`.
+fn serde_options(attrs: &[String]) -> impl Iterator<Item = &str> {
+    serde_options_with_values(attrs).map(|(name, _)| name)
+}
+
+/// Returns the options set via `#[serde(...)]` attributes on an item, alongside their
+/// (unquoted) values, e.g. `[("rename", Some("id")), ("default", None)]` for
+/// `#[serde(rename = "id", default)]`.
+pub(super) fn serde_options_with_values(
+    attrs: &[String],
+) -> impl Iterator<Item = (&str, Option<&str>)> {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.strip_prefix("#[serde(")?.strip_suffix(")]"))
+        .flat_map(|options| options.split(','))
+        .map(|option| match option.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+            None => (option.trim(), None),
+        })
+}
+
+/// Why a field can't be deserialized from a query string.
+struct UnsupportedField {
+    /// The path to the offending field, e.g. `["filter", "tags"]`.
+    field_path: Vec<String>,
+    reason: String,
+}
+
+/// Check that every field of a plain struct can be deserialized from a query string.
+fn check_query_fields(
+    struct_type: &PathType,
+    krate_collection: &CrateCollection,
+    depth: usize,
+) -> Result<(), UnsupportedField> {
+    if depth > MAX_NESTING_DEPTH {
+        return Ok(());
+    }
+    let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+        rustdoc_item_id: struct_type.rustdoc_id.clone().unwrap(),
+        package_id: struct_type.package_id.clone(),
+    });
+    let ItemEnum::Struct(struct_item) = &item.inner else {
+        return Ok(());
+    };
+    let StructKind::Plain { fields, .. } = &struct_item.kind else {
+        return Ok(());
+    };
+    // The struct is deserialized via another type: its fields don't tell us anything
+    // about the expected shape of the query string.
+    if serde_options(&item.attrs)
+        .any(|option| matches!(option, "from" | "try_from" | "transparent" | "remote"))
+    {
+        return Ok(());
+    }
+    // Bind the generic parameters of the struct to the concrete types
+    // used in the extractor, so that we can resolve the types of its fields.
+    let mut generic_bindings = HashMap::new();
+    let type_parameters = struct_item
+        .generics
+        .params
+        .iter()
+        .filter(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
+    let type_arguments = struct_type
+        .generic_arguments
+        .iter()
+        .filter_map(|a| match a {
+            GenericArgument::TypeParameter(t) => Some(t),
+            GenericArgument::Lifetime(_) => None,
+        });
+    for (param, arg) in type_parameters.zip(type_arguments) {
+        generic_bindings.insert(param.name.clone(), arg.to_owned());
+    }
+
+    for field_id in fields {
+        let field_item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+            rustdoc_item_id: field_id.clone(),
+            package_id: struct_type.package_id.clone(),
+        });
+        let ItemEnum::StructField(field_type) = &field_item.inner else {
+            continue;
+        };
+        // Skipped fields and fields with a custom deserializer are out of our reach.
+        if serde_options(&field_item.attrs).any(|option| {
+            matches!(
+                option,
+                "skip" | "skip_deserializing" | "deserialize_with" | "with"
+            )
+        }) {
+            continue;
+        }
+        let field_name = field_item.name.clone().unwrap_or_default();
+        // If we can't resolve the type of the field, we give it the benefit of the doubt.
+        let Ok(field_type) = resolve_type(
+            field_type,
+            &struct_type.package_id,
+            krate_collection,
+            &generic_bindings,
+        ) else {
+            continue;
+        };
+        check_query_value(&field_type, krate_collection, depth).map_err(|mut e| {
+            e.field_path.insert(0, field_name);
+            e
+        })?;
+    }
+    Ok(())
+}
+
+/// Check that a type can be deserialized from the value(s) associated with a key in a query string.
+fn check_query_value(
+    type_: &ResolvedType,
+    krate_collection: &CrateCollection,
+    depth: usize,
+) -> Result<(), UnsupportedField> {
+    let unsupported = |reason: String| UnsupportedField {
+        field_path: vec![],
+        reason,
+    };
+    match type_ {
+        ResolvedType::ScalarPrimitive(_) | ResolvedType::Generic(_) => Ok(()),
+        ResolvedType::Reference(r) => check_query_value(&r.inner, krate_collection, depth),
+        ResolvedType::Tuple(t) => Err(unsupported(format!(
+            "is a tuple, `{t:?}`. Tuples can't be represented in a query string"
+        ))),
+        ResolvedType::Slice(s) => Err(unsupported(format!(
+            "is a slice, `{s:?}`. Use a `Vec` to capture repeated keys"
+        ))),
+        ResolvedType::ResolvedPath(p) => {
+            if p.base_type == ["core", "option", "Option"] {
+                let Some(GenericArgument::TypeParameter(inner)) = p.generic_arguments.first()
+                else {
+                    return Ok(());
+                };
+                return check_query_value(inner, krate_collection, depth);
+            }
+            if is_sequence(p) {
+                let Some(GenericArgument::TypeParameter(element)) = p.generic_arguments.first()
+                else {
+                    return Ok(());
+                };
+                return check_sequence_element(element, krate_collection).map_err(|reason| {
+                    unsupported(format!(
+                        "is a sequence, `{p:?}`, but {reason}. \
+                        Only sequences of values (e.g. `?id=1&id=2`) can be represented in a query string"
+                    ))
+                });
+            }
+            if deserialize_impl(p, krate_collection) != DeserializeImpl::Derived {
+                // Custom `Deserialize` implementations (e.g. `String`, `Uuid`) usually
+                // deserialize from a single value. We can't verify it, so we trust them.
+                return Ok(());
+            }
+            let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+                rustdoc_item_id: p.rustdoc_id.clone().unwrap(),
+                package_id: p.package_id.clone(),
+            });
+            match &item.inner {
+                ItemEnum::Struct(s) => match &s.kind {
+                    // Nested structures are encoded using the bracket syntax,
+                    // e.g. `filter[status]=open`.
+                    StructKind::Plain { .. } => check_query_fields(p, krate_collection, depth + 1),
+                    StructKind::Unit => Ok(()),
+                    StructKind::Tuple(fields) if fields.len() == 1 => Ok(()),
+                    StructKind::Tuple(_) => Err(unsupported(format!(
+                        "is a tuple struct, `{p:?}`. Tuple structs with more than one field \
+                        can't be represented in a query string"
+                    ))),
+                },
+                ItemEnum::Enum(e) => {
+                    let has_data_carrying_variants = e.variants.iter().any(|variant_id| {
+                        let variant = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+                            rustdoc_item_id: variant_id.clone(),
+                            package_id: p.package_id.clone(),
+                        });
+                        !matches!(
+                            &variant.inner,
+                            ItemEnum::Variant(v) if matches!(v.kind, VariantKind::Plain)
+                        )
+                    });
+                    if has_data_carrying_variants {
+                        Err(unsupported(format!(
+                            "is an enum with data-carrying variants, `{p:?}`. Only enums with unit \
+                            variants can be represented in a query string"
+                        )))
+                    } else {
+                        Ok(())
+                    }
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+/// Check that a type can be used as the element of a sequence in a query string.
+/// It returns the reason why it can't, if it can't.
+fn check_sequence_element(
+    element: &ResolvedType,
+    krate_collection: &CrateCollection,
+) -> Result<(), String> {
+    match element {
+        ResolvedType::ResolvedPath(p) if is_sequence(p) => {
+            Err(format!("its elements are sequences themselves (`{p:?}`)"))
+        }
+        ResolvedType::ResolvedPath(p)
+            if deserialize_impl(p, krate_collection) == DeserializeImpl::Derived =>
+        {
+            let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+                rustdoc_item_id: p.rustdoc_id.clone().unwrap(),
+                package_id: p.package_id.clone(),
+            });
+            match &item.inner {
+                ItemEnum::Struct(s) if matches!(s.kind, StructKind::Plain { .. }) => {
+                    Err(format!("its elements are structures (`{p:?}`)"))
+                }
+                _ => Ok(()),
+            }
+        }
+        ResolvedType::Tuple(t) => Err(format!("its elements are tuples (`{t:?}`)")),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if the type is one of the sequence types provided by the standard library.
+///
+/// Sequence types provided by third-party crates (e.g. `SmallVec`) are not recognised:
+/// they fall under the custom `Deserialize` implementations we trust.
+fn is_sequence(type_: &PathType) -> bool {
+    let path = type_
+        .base_type
+        .iter()
+        .map(|s| s.as_str())
+        .collect::<Vec<_>>();
+    matches!(
+        path.as_slice(),
+        ["alloc", "vec", "Vec"]
+            | ["alloc", "collections", "vec_deque", "VecDeque"]
+            | ["alloc", "collections", "btree", "set", "BTreeSet"]
+            | ["std", "collections", "hash", "set", "HashSet"]
+    )
+}
+
+/// Report an error on each compute node that consumes the `QueryParams` extractor
+/// when its target type can't be deserialized from a query string.
+fn report_unsupported_query_type(
+    extractor: &Extractor,
+    reason: &str,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<Report>,
+) {
+    let extracted_type = extractor.extracted_type;
+    let consuming_ids =
+        extractor_consumer_ids(component_db, extractor.call_graph, extractor.node_id);
+    for user_component_id in consuming_ids {
+        let raw_identifiers = component_db
+            .user_component_db()
+            .get_raw_callable_identifiers(user_component_id);
+        let callable_type = component_db.user_component_db()[user_component_id].callable_type();
+        let location = component_db
+            .user_component_db()
+            .get_location(user_component_id);
+        let source = match location.source_file(package_graph) {
+            Ok(s) => s,
+            Err(e) => {
+                diagnostics.push(e.into());
+                continue;
+            }
+        };
+        let source_span = diagnostic::get_f_macro_invocation_span(&source, location);
+        let error = anyhow!(
+            "Query parameters must be extracted using a plain struct with named fields, \
+            where each field can be deserialized from the value(s) associated with its key \
+            in the query string.\n\
+            `{}` is trying to extract `QueryParams<{extracted_type:?}>`, but {reason}. \
+            I don't support this: the extraction would fail at runtime, when trying to process \
+            an incoming request.",
+            raw_identifiers.fully_qualified_path().join("::")
+        );
+        let d = CompilerDiagnostic::builder(source, error)
+            .optional_label(source_span.labeled(format!(
+                "The {callable_type} asking for `QueryParams<{extracted_type:?}>`"
+            )))
+            .help(
+                "Fields can be values (e.g. `?id=1`), sequences of values (e.g. `?id=1&id=2`) \
+                or nested structures (e.g. `?filter[status]=open`).\n\
+                Check out `QueryParams`' documentation for all the details!"
+                    .into(),
+            )
+            .build();
+        diagnostics.push(d.into());
+    }
+}
+
+/// Report a warning on each compute node that consumes an extractor whose target type
+/// doesn't derive `serde::Deserialize`.
+fn report_missing_deserialize_derive(
+    extractor: &Extractor,
+    deserialize_impl: DeserializeImpl,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<Report>,
+) {
+    let Extractor {
+        name,
+        extracted_type,
+        ..
+    } = extractor;
+    let consuming_ids =
+        extractor_consumer_ids(component_db, extractor.call_graph, extractor.node_id);
+    for user_component_id in consuming_ids {
+        let raw_identifiers = component_db
+            .user_component_db()
+            .get_raw_callable_identifiers(user_component_id);
+        let callable_type = component_db.user_component_db()[user_component_id].callable_type();
+        let location = component_db
+            .user_component_db()
+            .get_location(user_component_id);
+        let source = match location.source_file(package_graph) {
+            Ok(s) => s,
+            Err(e) => {
+                diagnostics.push(e.into());
+                continue;
+            }
+        };
+        let source_span = diagnostic::get_f_macro_invocation_span(&source, location);
+        let problem = match deserialize_impl {
+            DeserializeImpl::Manual => "implements `serde::Deserialize` by hand",
+            DeserializeImpl::Derived | DeserializeImpl::Missing => {
+                "doesn't derive `serde::Deserialize`"
+            }
+        };
+        let error = anyhow!(
+            "`{}` is trying to extract `{name}<{extracted_type:?}>`, but `{extracted_type:?}` \
+            {problem}.\n\
+            I can't verify that a custom implementation of `serde::Deserialize` is compatible \
+            with the data format expected by `{name}`: mistakes will only surface at runtime, \
+            when trying to process an incoming request.",
+            raw_identifiers.fully_qualified_path().join("::")
+        );
+        let d = CompilerDiagnostic::builder(source, error)
+            .optional_label(source_span.labeled(format!(
+                "The {callable_type} asking for `{name}<{extracted_type:?}>`"
+            )))
+            .severity(Severity::Warning)
+            .help(format!(
+                "Add `#[derive(serde::Deserialize)]` to the definition of `{extracted_type:?}`.\n\
+                If you want to ignore this warning, call `.ignore(Lint::CustomDeserialize)` \
+                on the registered constructor for `{name}`."
+            ))
+            .build();
+        diagnostics.push(d.into());
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::too_many_arguments)]
 
 pub use app::App;
+pub use route_table::{RouteTable, RouteTableEntry};
 
 mod analyses;
 mod app;
@@ -8,9 +9,14 @@ mod codegen;
 mod codegen_utils;
 mod component;
 mod computation;
+mod extractor_validation;
 mod generated_app;
 mod interner;
+mod openapi;
 mod path_parameter_validation;
+mod redirect_validation;
 mod resolvers;
+mod route_table;
 mod traits;
+mod url_builders;
 mod utils;
@@ -0,0 +1,1018 @@
+//! Derive an [OpenAPI 3.1](https://spec.openapis.org/oas/v3.1.0) document from the routes
+//! registered against a blueprint.
+//!
+//! It's a best-effort description of the HTTP API exposed by the application:
+//!
+//! - paths, path parameters and methods are taken from the router;
+//! - query parameters and request bodies are derived from the type definitions of the
+//!   extractors used by each request handler (`PathParams<T>`, `QueryParams<T>` and `JsonBody<T>`);
+//! - responses are described using the output types of request handlers and error handlers;
+//! - summaries and descriptions are taken from doc comments.
+use std::collections::BTreeMap;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use ahash::{HashMap, HashMapExt};
+use anyhow::anyhow;
+use guppy::graph::PackageGraph;
+use indexmap::IndexMap;
+use miette::Severity;
+use pavex_bp_schema::Location;
+use rustdoc_types::{GenericParamDefKind, ItemEnum, StructKind, VariantKind};
+use serde_json::{json, Map, Value};
+
+use crate::compiler::analyses::components::{ComponentDb, ComponentId, HydratedComponent};
+use crate::compiler::analyses::computations::ComputationDb;
+use crate::compiler::analyses::processing_pipeline::RequestHandlerPipeline;
+use crate::compiler::analyses::router::{LeafRouter, Router};
+use crate::compiler::analyses::user_components::domain;
+use crate::compiler::analyses::user_components::param_constraints::ParamConstraint;
+use crate::compiler::analyses::user_components::UserComponent;
+use crate::compiler::resolvers::resolve_type;
+use crate::diagnostic;
+use crate::diagnostic::{AnnotatedSnippet, CompilerDiagnostic, LocationExt, OptionalSourceSpanExt};
+use crate::language::{Callable, GenericArgument, PathType, ResolvedType, ScalarPrimitive};
+use crate::rustdoc::{CrateCollection, GlobalItemId};
+
+use super::extractor_validation::{find_extractor, serde_options_with_values};
+
+/// The methods that can be described by an OpenAPI path item.
+const OPENAPI_METHODS: [&str; 8] = [
+    "GET", "PUT", "POST", "DELETE", "OPTIONS", "HEAD", "PATCH", "TRACE",
+];
+
+/// An OpenAPI document describing the routes of a Pavex application.
+pub struct OpenApiDocument {
+    document: Value,
+    warnings: Vec<miette::Error>,
+}
+
+impl OpenApiDocument {
+    /// The routes that couldn't be described in the document.
+    ///
+    /// An OpenAPI path item can't list two operations for the same method: if the same
+    /// path and method are served on different domains, only the first route is described.
+    pub fn warnings(&self) -> &[miette::Error] {
+        &self.warnings
+    }
+
+    /// Save the document to the specified file, as pretty-printed JSON.
+    pub fn persist(&self, filepath: &Path) -> Result<(), anyhow::Error> {
+        let file = fs_err::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(filepath)?;
+        let mut file = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut file, &self.document)?;
+        file.write_all("\n".as_bytes())?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Build an OpenAPI document for the routes of the application.
+#[tracing::instrument(name = "Compute OpenAPI document", skip_all)]
+pub(crate) fn openapi_document(
+    bp_location: &Location,
+    router: &Router,
+    handler_id2pipeline: &IndexMap<ComponentId, RequestHandlerPipeline>,
+    computation_db: &ComputationDb,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    krate_collection: &CrateCollection,
+) -> OpenApiDocument {
+    const PATH_PARAMS: [&str; 4] = ["pavex", "request", "path", "PathParams"];
+    const QUERY_PARAMS: [&str; 4] = ["pavex", "request", "query", "QueryParams"];
+    const JSON_BODY: [&str; 4] = ["pavex", "request", "body", "JsonBody"];
+
+    let mut schemas = SchemaRegistry::new(krate_collection);
+    let mut paths = Map::new();
+    let mut warnings = Vec::new();
+    // The domain of the route described by each (path, method) operation.
+    let mut operation2domain: HashMap<(String, String), (ComponentId, Option<String>)> =
+        HashMap::new();
+    for (routing_key, leaf_router) in &router.route_path2sub_router {
+        let (domain, path) = domain::split_routing_key(routing_key);
+        let handlers = leaf_handlers(leaf_router, component_db);
+        if handlers.is_empty() {
+            continue;
+        }
+        let openapi_path = openapi_path(path);
+        let path_item = paths
+            .entry(openapi_path.clone())
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .unwrap();
+        for (handler_id, methods) in handlers {
+            let Some(pipeline) = handler_id2pipeline.get(&handler_id) else {
+                continue;
+            };
+            let extracted_type = |extractor_path: &[&str]| {
+                find_extractor(pipeline, component_db, computation_db, extractor_path).and_then(
+                    |(_, _, ty_)| match &ty_.generic_arguments[0] {
+                        GenericArgument::TypeParameter(t) => Some(t.to_owned()),
+                        _ => None,
+                    },
+                )
+            };
+            let mut operation = Map::new();
+
+            let HydratedComponent::RequestHandler(handler) =
+                component_db.hydrated_component(handler_id, computation_db)
+            else {
+                continue;
+            };
+            if let Some(redirect) = &leaf_router.redirect {
+                // The request handler of redirects is provided by the framework:
+                // its documentation is not relevant to the API consumers.
+                operation.insert(
+                    "summary".into(),
+                    format!("Redirect to `{}`", redirect.to).into(),
+                );
+            } else if let Some(docs) = callable_docs(&handler.callable, krate_collection) {
+                let summary = docs.lines().next().unwrap_or_default().trim();
+                if !summary.is_empty() {
+                    operation.insert("summary".into(), summary.into());
+                }
+                operation.insert("description".into(), docs.into());
+            }
+            let route_name = router
+                .route_name2handler_id
+                .iter()
+                .find(|(_, id)| **id == handler_id)
+                .map(|(name, _)| name);
+
+            let mut parameters = path_parameters(
+                path,
+                leaf_router,
+                extracted_type(&PATH_PARAMS).as_ref(),
+                &mut schemas,
+            );
+            if let Some(ty) = extracted_type(&QUERY_PARAMS) {
+                for field in schemas.fields(&ty).unwrap_or_default() {
+                    let mut parameter = json!({
+                        "name": field.name,
+                        "in": "query",
+                        "required": field.required,
+                        "schema": field.schema,
+                    });
+                    if let Some(description) = field.description {
+                        parameter["description"] = description.into();
+                    }
+                    parameters.push(parameter);
+                }
+            }
+            if !parameters.is_empty() {
+                operation.insert("parameters".into(), parameters.into());
+            }
+
+            if let Some(ty) = extracted_type(&JSON_BODY) {
+                operation.insert(
+                    "requestBody".into(),
+                    json!({
+                        "required": true,
+                        "content": { "application/json": { "schema": schemas.schema(&ty) } },
+                    }),
+                );
+            }
+
+            let responses = match &leaf_router.redirect {
+                Some(redirect) => json!({
+                    redirect.status.to_string(): {
+                        "description": format!("Redirect to `{}`", redirect.to),
+                        "headers": { "Location": { "schema": { "type": "string" } } },
+                    }
+                }),
+                None => json!({
+                    "default": {
+                        "description": response_description(
+                            handler_id,
+                            &handler.callable,
+                            component_db,
+                            computation_db,
+                            krate_collection,
+                        )
+                    }
+                }),
+            };
+            operation.insert("responses".into(), responses);
+            // Routes registered for the same path on different domains share the same
+            // path item: the domain is specified for each operation.
+            if let Some(domain) = &domain {
+                operation.insert("servers".into(), json!([server(domain)]));
+            }
+
+            let n_methods = methods.len();
+            for method in methods {
+                let method = method.to_lowercase();
+                let key = (openapi_path.clone(), method.clone());
+                if let Some((first_id, first_domain)) = operation2domain.get(&key) {
+                    report_domain_clash(
+                        &openapi_path,
+                        &method,
+                        (*first_id, first_domain.as_deref()),
+                        (handler_id, domain.as_deref()),
+                        component_db,
+                        package_graph,
+                        &mut warnings,
+                    );
+                    continue;
+                }
+                operation2domain.insert(key, (handler_id, domain.clone()));
+                let mut operation = operation.clone();
+                // Operation ids must be unique, even if the route handles multiple methods.
+                if let Some(name) = route_name {
+                    let operation_id = if n_methods > 1 {
+                        format!("{name}.{method}")
+                    } else {
+                        name.to_owned()
+                    };
+                    operation.insert("operationId".into(), operation_id.into());
+                }
+                path_item.insert(method, operation.into());
+            }
+        }
+    }
+
+    let (title, version) = app_info(bp_location, package_graph);
+    let mut document = json!({
+        "openapi": "3.1.0",
+        "info": { "title": title, "version": version },
+        "paths": paths,
+    });
+    if !schemas.schemas.is_empty() {
+        document["components"] = json!({ "schemas": schemas.schemas });
+    }
+    OpenApiDocument { document, warnings }
+}
+
+/// The same path and method are served on two different domains, but an OpenAPI path item
+/// can only describe one operation per method.
+fn report_domain_clash(
+    path: &str,
+    method: &str,
+    (described_id, described_domain): (ComponentId, Option<&str>),
+    (skipped_id, skipped_domain): (ComponentId, Option<&str>),
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    warnings: &mut Vec<miette::Error>,
+) {
+    let mut snippets = Vec::with_capacity(2);
+    for (id, label) in [
+        (skipped_id, "The route I left out"),
+        (described_id, "The route I described"),
+    ] {
+        let Some(user_component_id) = component_db.user_component_id(id) else {
+            return;
+        };
+        let location = component_db
+            .user_component_db()
+            .get_location(user_component_id);
+        let source = match location.source_file(package_graph) {
+            Ok(s) => s,
+            Err(e) => {
+                warnings.push(e.into());
+                return;
+            }
+        };
+        let label = diagnostic::get_route_path_span(&source, location).labeled(label.into());
+        snippets.push(AnnotatedSnippet::new_optional(source, label));
+    }
+    let domain = |d: Option<&str>| match d {
+        Some(d) => format!("`{d}`"),
+        None => "any domain".to_owned(),
+    };
+    let error = anyhow!(
+        "I can't describe all your routes in the OpenAPI document: `{} {path}` is served on \
+        both {} and {}.\n\
+        An OpenAPI path item can only describe one operation per method, so I've only \
+        included the route served on {}.",
+        method.to_uppercase(),
+        domain(described_domain),
+        domain(skipped_domain),
+        domain(described_domain),
+    );
+    let skipped = snippets.remove(0);
+    let d = CompilerDiagnostic::builder(skipped.source_code, error)
+        .labels(skipped.labels.into_iter())
+        .additional_annotated_snippets(snippets.into_iter())
+        .severity(Severity::Warning)
+        .help(
+            "Register the two routes under different paths if you want both of them \
+            to be described."
+                .into(),
+        )
+        .build();
+    warnings.push(d.into());
+}
+
+/// The request handlers registered for a path, alongside the methods they can be described with.
+///
+/// Handlers registered for any method are listed under all the methods supported by OpenAPI.
+/// Methods that can't be represented in an OpenAPI path item (e.g. `CONNECT`) are skipped.
+/// Catch-alls registered to serve the fallback of a nested blueprint are not routes:
+/// no handler is returned for them.
+fn leaf_handlers(
+    leaf_router: &LeafRouter,
+    component_db: &ComponentDb,
+) -> Vec<(ComponentId, Vec<&'static str>)> {
+    if leaf_router.handler_id2methods.is_empty() {
+        let is_fallback = component_db
+            .user_component_id(leaf_router.fallback_id)
+            .is_some_and(|id| {
+                matches!(
+                    component_db.user_component_db()[id],
+                    UserComponent::Fallback { .. }
+                )
+            });
+        if is_fallback {
+            return vec![];
+        }
+        return vec![(leaf_router.fallback_id, OPENAPI_METHODS.to_vec())];
+    }
+    leaf_router
+        .handler_id2methods
+        .iter()
+        .map(|(handler_id, methods)| {
+            let methods = OPENAPI_METHODS
+                .into_iter()
+                .filter(|m| methods.contains(*m))
+                .collect::<Vec<_>>();
+            (*handler_id, methods)
+        })
+        .filter(|(_, methods)| !methods.is_empty())
+        .collect()
+}
+
+/// Convert a route path template into an OpenAPI path template—e.g. `/users/:id` becomes
+/// `/users/{id}`.
+fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match segment.strip_prefix([':', '*']) {
+            Some(name) => format!("{{{name}}}"),
+            None => segment.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// An OpenAPI server object for a domain pattern—e.g. `:tenant.example.com`.
+fn server(domain: &str) -> Value {
+    let mut variables = Map::new();
+    let url = domain
+        .split('.')
+        .map(|label| match label.strip_prefix(':') {
+            Some(name) => {
+                variables.insert(name.into(), json!({ "default": name }));
+                format!("{{{name}}}")
+            }
+            None => label.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut server = json!({ "url": format!("https://{url}") });
+    if !variables.is_empty() {
+        server["variables"] = variables.into();
+    }
+    server
+}
+
+/// The path parameters of a route.
+///
+/// Their schemas are derived from the fields of the type extracted via `PathParams<T>`, if any,
+/// and from the constraints specified in the route path.
+fn path_parameters(
+    path: &str,
+    leaf_router: &LeafRouter,
+    path_params_type: Option<&ResolvedType>,
+    schemas: &mut SchemaRegistry,
+) -> Vec<Value> {
+    let fields = path_params_type
+        .and_then(|ty| schemas.fields(ty))
+        .unwrap_or_default();
+    path.split('/')
+        .filter_map(|s| s.strip_prefix([':', '*']))
+        .map(|name| {
+            let field = fields.iter().find(|f| f.name == name);
+            let constraint = leaf_router.param_constraints.get(name);
+            let mut schema = match (field, constraint) {
+                (Some(field), _) => field.schema.clone(),
+                (None, Some(ParamConstraint::Type(ty))) => ScalarPrimitive::try_from(ty.as_str())
+                    .map(|p| primitive_schema(&p))
+                    .unwrap_or_else(|_| json!({ "type": "string" })),
+                (None, _) => json!({ "type": "string" }),
+            };
+            if let (Some(ParamConstraint::Pattern(pattern)), Value::Object(schema)) =
+                (constraint, &mut schema)
+            {
+                schema.insert("pattern".into(), format!("^(?:{pattern})$").into());
+            }
+            let mut parameter = json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": schema,
+            });
+            if let Some(description) = field.and_then(|f| f.description.clone()) {
+                parameter["description"] = description.into();
+            }
+            parameter
+        })
+        .collect()
+}
+
+/// Describe the response returned by a request handler, including the one built by its
+/// error handler if the request handler is fallible.
+fn response_description(
+    handler_id: ComponentId,
+    handler: &Callable,
+    component_db: &ComponentDb,
+    computation_db: &ComputationDb,
+    krate_collection: &CrateCollection,
+) -> String {
+    let Some(output) = &handler.output else {
+        return "An empty response.".into();
+    };
+    let success_type = if output.is_result() {
+        let ResolvedType::ResolvedPath(result) = output else {
+            unreachable!()
+        };
+        match &result.generic_arguments[0] {
+            GenericArgument::TypeParameter(t) => t,
+            _ => output,
+        }
+    } else {
+        output
+    };
+    let mut description = format!("`{success_type:?}`");
+    if let Some(docs) = type_docs(success_type, krate_collection) {
+        description.push_str(&format!(": {docs}"));
+    }
+    let error_handler = component_db
+        .match_ids(handler_id)
+        .and_then(|(_, err_id)| component_db.error_handler_id(*err_id))
+        .map(|id| component_db.hydrated_component(*id, computation_db));
+    if let Some(HydratedComponent::ErrorHandler(error_handler)) = error_handler {
+        if let Some(error_output) = &error_handler.callable.output {
+            description.push_str(&format!(
+                "\n\nIf the request fails, `{error_output:?}`, built by `{}`.",
+                error_handler.callable.path
+            ));
+        }
+    }
+    description
+}
+
+/// The title and the version of the package where the root blueprint was created.
+fn app_info(bp_location: &Location, package_graph: &PackageGraph) -> (String, String) {
+    let workspace = package_graph.workspace();
+    let bp_file = workspace.root().join(&bp_location.file);
+    workspace
+        .iter()
+        .filter(|p| {
+            p.manifest_path()
+                .parent()
+                .is_some_and(|dir| bp_file.starts_with(dir))
+        })
+        // The innermost package, in case of nested packages.
+        .max_by_key(|p| p.manifest_path().as_str().len())
+        .map(|p| (p.name().to_owned(), p.version().to_string()))
+        .unwrap_or_else(|| ("Pavex application".into(), "0.0.0".into()))
+}
+
+/// The doc comment attached to a callable, if any.
+fn callable_docs(callable: &Callable, krate_collection: &CrateCollection) -> Option<String> {
+    let id = callable.source_coordinates.as_ref()?;
+    krate_collection.get_type_by_global_type_id(id).docs.clone()
+}
+
+/// The first line of the doc comment attached to a type, if any.
+fn type_docs(ty: &ResolvedType, krate_collection: &CrateCollection) -> Option<String> {
+    let ResolvedType::ResolvedPath(path_type) = ty else {
+        return None;
+    };
+    let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+        rustdoc_item_id: path_type.rustdoc_id.clone()?,
+        package_id: path_type.package_id.clone(),
+    });
+    let docs = item.docs.as_deref()?.lines().next()?.trim();
+    (!docs.is_empty()).then(|| docs.to_owned())
+}
+
+fn primitive_schema(primitive: &ScalarPrimitive) -> Value {
+    match primitive {
+        ScalarPrimitive::I32 => json!({ "type": "integer", "format": "int32" }),
+        ScalarPrimitive::I64 => json!({ "type": "integer", "format": "int64" }),
+        ScalarPrimitive::I8 | ScalarPrimitive::I16 | ScalarPrimitive::Isize => {
+            json!({ "type": "integer" })
+        }
+        ScalarPrimitive::U8
+        | ScalarPrimitive::U16
+        | ScalarPrimitive::U32
+        | ScalarPrimitive::U64
+        | ScalarPrimitive::Usize => json!({ "type": "integer", "minimum": 0 }),
+        ScalarPrimitive::F32 => json!({ "type": "number", "format": "float" }),
+        ScalarPrimitive::F64 => json!({ "type": "number", "format": "double" }),
+        ScalarPrimitive::Bool => json!({ "type": "boolean" }),
+        ScalarPrimitive::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        ScalarPrimitive::Str => json!({ "type": "string" }),
+    }
+}
+
+/// A field of a struct, as seen by OpenAPI.
+struct Field {
+    name: String,
+    schema: Value,
+    description: Option<String>,
+    required: bool,
+}
+
+/// Convert Rust types into JSON schemas, collecting the schemas of user-defined types
+/// so that they can be referenced by name.
+struct SchemaRegistry<'a> {
+    krate_collection: &'a CrateCollection,
+    schemas: BTreeMap<String, Value>,
+    type2name: HashMap<ResolvedType, String>,
+}
+
+impl<'a> SchemaRegistry<'a> {
+    fn new(krate_collection: &'a CrateCollection) -> Self {
+        Self {
+            krate_collection,
+            schemas: BTreeMap::new(),
+            type2name: HashMap::new(),
+        }
+    }
+
+    /// The JSON schema for `ty`.
+    ///
+    /// User-defined structs and enums are registered as components and referenced by name.
+    /// An empty schema, which matches any value, is returned for types we can't describe.
+    fn schema(&mut self, ty: &ResolvedType) -> Value {
+        match ty {
+            ResolvedType::ScalarPrimitive(p) => primitive_schema(p),
+            ResolvedType::Reference(r) => self.schema(&r.inner),
+            ResolvedType::Slice(s) => {
+                json!({ "type": "array", "items": self.schema(&s.element_type) })
+            }
+            ResolvedType::Tuple(t) if t.elements.is_empty() => json!({ "type": "null" }),
+            ResolvedType::Tuple(t) => {
+                let items = t
+                    .elements
+                    .iter()
+                    .map(|e| self.schema(e))
+                    .collect::<Vec<_>>();
+                json!({
+                    "type": "array",
+                    "prefixItems": items,
+                    "minItems": t.elements.len(),
+                    "maxItems": t.elements.len(),
+                })
+            }
+            ResolvedType::Generic(_) => json!({}),
+            ResolvedType::ResolvedPath(path_type) => self.path_schema(ty, path_type),
+        }
+    }
+
+    fn path_schema(&mut self, ty: &ResolvedType, path_type: &PathType) -> Value {
+        let type_args = path_type
+            .generic_arguments
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::TypeParameter(t) => Some(t),
+                GenericArgument::Lifetime(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let is_std = matches!(
+            path_type.base_type.first().map(String::as_str),
+            Some("std" | "alloc" | "core")
+        );
+        if is_std {
+            let name = path_type.base_type.last().map(String::as_str);
+            return match (name, type_args.as_slice()) {
+                (Some("String"), _) => json!({ "type": "string" }),
+                (Some("Box" | "Rc" | "Arc" | "Cow"), [inner, ..]) => self.schema(inner),
+                (Some("Option"), [inner]) => {
+                    json!({ "anyOf": [self.schema(inner), { "type": "null" }] })
+                }
+                (Some("Vec" | "VecDeque" | "BTreeSet" | "HashSet"), [inner, ..]) => {
+                    json!({ "type": "array", "items": self.schema(inner) })
+                }
+                (Some("BTreeMap" | "HashMap"), [_, value, ..]) => {
+                    json!({ "type": "object", "additionalProperties": self.schema(value) })
+                }
+                _ => json!({}),
+            };
+        }
+        if let Some(name) = self.type2name.get(ty) {
+            return json!({ "$ref": format!("#/components/schemas/{name}") });
+        }
+        let Some(rustdoc_id) = path_type.rustdoc_id.clone() else {
+            return json!({});
+        };
+        let short_name = path_type.base_type.last().cloned().unwrap_or_default();
+        // Types with the same name from different modules must not overwrite each other.
+        let name = if self.schemas.contains_key(&short_name) {
+            path_type.base_type.join(".")
+        } else {
+            short_name
+        };
+        // Register the name before processing the definition, to handle recursive types.
+        self.type2name.insert(ty.to_owned(), name.clone());
+        self.schemas.insert(name.clone(), json!({}));
+
+        let item = self
+            .krate_collection
+            .get_type_by_global_type_id(&GlobalItemId {
+                rustdoc_item_id: rustdoc_id,
+                package_id: path_type.package_id.clone(),
+            });
+        let mut schema = match &item.inner {
+            ItemEnum::Struct(s) => match &s.kind {
+                StructKind::Plain { .. } => 'plain: {
+                    let Some(fields) = self.fields(ty) else {
+                        break 'plain json!({});
+                    };
+                    let required = fields
+                        .iter()
+                        .filter(|f| f.required)
+                        .map(|f| f.name.clone())
+                        .collect::<Vec<_>>();
+                    let properties = fields
+                        .into_iter()
+                        .map(|f| {
+                            let mut schema = f.schema;
+                            if let (Some(description), Value::Object(schema)) =
+                                (f.description, &mut schema)
+                            {
+                                schema.insert("description".into(), description.into());
+                            }
+                            (f.name, schema)
+                        })
+                        .collect::<Map<_, _>>();
+                    json!({ "type": "object", "properties": properties, "required": required })
+                }
+                StructKind::Unit => json!({ "type": "null" }),
+                StructKind::Tuple(_) => json!({}),
+            },
+            ItemEnum::Enum(e) => 'enum_: {
+                let Some(container) = ContainerOptions::parse(&item.attrs) else {
+                    break 'enum_ json!({});
+                };
+                let variants = e
+                    .variants
+                    .iter()
+                    .map(|id| {
+                        self.krate_collection
+                            .get_type_by_global_type_id(&GlobalItemId {
+                                rustdoc_item_id: id.clone(),
+                                package_id: path_type.package_id.clone(),
+                            })
+                            .into_owned()
+                    })
+                    .collect::<Vec<_>>();
+                let unit_variants = variants
+                    .iter()
+                    .filter_map(|v| match &v.inner {
+                        ItemEnum::Variant(v) if matches!(v.kind, VariantKind::Plain) => Some(()),
+                        _ => None,
+                    })
+                    .count();
+                if unit_variants != variants.len() {
+                    break 'enum_ json!({});
+                }
+                let mut names = Vec::with_capacity(variants.len());
+                for variant in variants {
+                    let Some(name) = &variant.name else {
+                        continue;
+                    };
+                    let Some(options) = MemberOptions::parse(&variant.attrs) else {
+                        break 'enum_ json!({});
+                    };
+                    if options.skip {
+                        continue;
+                    }
+                    let name = match (options.rename, container.rename_all) {
+                        (Some(rename), _) => rename.to_owned(),
+                        (None, Some(rule)) => rule.apply_to_variant(name),
+                        (None, None) => name.to_owned(),
+                    };
+                    names.push(name);
+                }
+                json!({ "type": "string", "enum": names })
+            }
+            _ => json!({}),
+        };
+        if let (Some(docs), Value::Object(schema)) = (&item.docs, &mut schema) {
+            schema.insert("description".into(), docs.as_str().into());
+        }
+        self.schemas.insert(name.clone(), schema);
+        json!({ "$ref": format!("#/components/schemas/{name}") })
+    }
+
+    /// The named fields of `ty`, if it is a plain struct, as seen by `serde`.
+    ///
+    /// `#[serde(...)]` attributes that rename, skip or provide a default for fields are
+    /// honored. A field is required unless its type is an `Option` or it has a default.
+    /// `None` is returned if the struct is deserialized in a way we can't describe—e.g.
+    /// if it's flattened into another struct or deserialized via another type.
+    fn fields(&mut self, ty: &ResolvedType) -> Option<Vec<Field>> {
+        let ResolvedType::ResolvedPath(path_type) = ty else {
+            return None;
+        };
+        let item = self
+            .krate_collection
+            .get_type_by_global_type_id(&GlobalItemId {
+                rustdoc_item_id: path_type.rustdoc_id.clone()?,
+                package_id: path_type.package_id.clone(),
+            });
+        let ItemEnum::Struct(struct_item) = &item.inner else {
+            return None;
+        };
+        let StructKind::Plain { fields, .. } = &struct_item.kind else {
+            return None;
+        };
+        let container = ContainerOptions::parse(&item.attrs)?;
+        // Bind the generic parameters of the struct to the concrete types used in `ty`.
+        let mut generic_bindings = HashMap::new();
+        let type_params = struct_item
+            .generics
+            .params
+            .iter()
+            .filter(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
+        let type_args = path_type
+            .generic_arguments
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::TypeParameter(t) => Some(t),
+                GenericArgument::Lifetime(_) => None,
+            });
+        for (param, arg) in type_params.zip(type_args) {
+            generic_bindings.insert(param.name.clone(), arg.to_owned());
+        }
+
+        let mut output = Vec::with_capacity(fields.len());
+        for field_id in fields {
+            let field_item = self
+                .krate_collection
+                .get_type_by_global_type_id(&GlobalItemId {
+                    rustdoc_item_id: field_id.clone(),
+                    package_id: path_type.package_id.clone(),
+                });
+            let (Some(name), ItemEnum::StructField(field_type)) =
+                (field_item.name.clone(), &field_item.inner)
+            else {
+                continue;
+            };
+            let options = MemberOptions::parse(&field_item.attrs)?;
+            if options.skip {
+                continue;
+            }
+            let name = match (options.rename, container.rename_all) {
+                (Some(rename), _) => rename.to_owned(),
+                (None, Some(rule)) => rule.apply_to_field(&name),
+                (None, None) => name,
+            };
+            let field_type = resolve_type(
+                field_type,
+                &path_type.package_id,
+                self.krate_collection,
+                &generic_bindings,
+            )
+            .ok();
+            let optional_inner = field_type.as_ref().and_then(|t| match t {
+                ResolvedType::ResolvedPath(p) if p.base_type == ["core", "option", "Option"] => {
+                    match p.generic_arguments.first() {
+                        Some(GenericArgument::TypeParameter(inner)) => Some(inner.to_owned()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            });
+            let (schema, required) = match (&optional_inner, &field_type) {
+                // A custom deserializer may accept a different shape.
+                _ if options.custom_deserializer => (json!({}), true),
+                (Some(inner), _) => (self.schema(inner), false),
+                (None, Some(field_type)) => (self.schema(field_type), true),
+                (None, None) => (json!({}), true),
+            };
+            output.push(Field {
+                name,
+                schema,
+                description: field_item.docs.clone(),
+                required: required && !options.default && !container.default,
+            });
+        }
+        Some(output)
+    }
+}
+
+/// The `#[serde(...)]` options of a struct or an enum that affect the way it's described.
+struct ContainerOptions {
+    rename_all: Option<RenameRule>,
+    default: bool,
+}
+
+impl ContainerOptions {
+    /// `None` if the options change the shape of the type in a way we can't describe—e.g.
+    /// `#[serde(tag = "...")]` or `#[serde(from = "...")]`.
+    fn parse(attrs: &[String]) -> Option<Self> {
+        let mut options = Self {
+            rename_all: None,
+            default: false,
+        };
+        for (name, value) in serde_options_with_values(attrs) {
+            match name {
+                "rename_all" => options.rename_all = Some(RenameRule::parse(value?)?),
+                "default" => options.default = true,
+                "rename" | "deny_unknown_fields" | "bound" | "crate" | "expecting" => {}
+                _ => return None,
+            }
+        }
+        Some(options)
+    }
+}
+
+/// The `#[serde(...)]` options of a struct field or of an enum variant that affect the way
+/// it's described.
+struct MemberOptions<'a> {
+    rename: Option<&'a str>,
+    skip: bool,
+    default: bool,
+    custom_deserializer: bool,
+}
+
+impl<'a> MemberOptions<'a> {
+    /// `None` if the options change the shape of the member in a way we can't describe—e.g.
+    /// `#[serde(flatten)]`.
+    fn parse(attrs: &'a [String]) -> Option<Self> {
+        let mut options = Self {
+            rename: None,
+            skip: false,
+            default: false,
+            custom_deserializer: false,
+        };
+        for (name, value) in serde_options_with_values(attrs) {
+            match name {
+                "rename" => options.rename = Some(value?),
+                "skip" | "skip_deserializing" => options.skip = true,
+                "default" => options.default = true,
+                "with" | "deserialize_with" => options.custom_deserializer = true,
+                "alias"
+                | "skip_serializing"
+                | "skip_serializing_if"
+                | "serialize_with"
+                | "bound"
+                | "borrow"
+                | "other" => {}
+                _ => return None,
+            }
+        }
+        Some(options)
+    }
+}
+
+/// A `#[serde(rename_all = "...")]` rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &str) -> Option<Self> {
+        Some(match rule {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    /// Rename a field, assuming it's written in `snake_case`—the same way `serde` does.
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => {
+                let mut pascal = String::with_capacity(field.len());
+                let mut capitalize = self == Self::Pascal;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Rename a variant, assuming it's written in `PascalCase`—the same way `serde` does.
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+            Self::Snake | Self::ScreamingSnake | Self::Kebab | Self::ScreamingKebab => {
+                let mut snake = String::with_capacity(variant.len());
+                for (i, c) in variant.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                match self {
+                    Self::Snake => snake,
+                    Self::ScreamingSnake => snake.to_ascii_uppercase(),
+                    Self::Kebab => snake.replace('_', "-"),
+                    _ => snake.to_ascii_uppercase().replace('_', "-"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{openapi_path, server, RenameRule};
+
+    #[test]
+    fn route_paths_are_converted_to_openapi_templates() {
+        assert_eq!(openapi_path("/"), "/");
+        assert_eq!(openapi_path("/users/:id"), "/users/{id}");
+        assert_eq!(
+            openapi_path("/users/:id/files/*path"),
+            "/users/{id}/files/{path}"
+        );
+    }
+
+    #[test]
+    fn domains_are_converted_to_servers() {
+        assert_eq!(
+            server(":tenant.example.com"),
+            serde_json::json!({
+                "url": "https://{tenant}.example.com",
+                "variables": { "tenant": { "default": "tenant" } },
+            })
+        );
+        assert_eq!(
+            server("example.com"),
+            serde_json::json!({ "url": "https://example.com" })
+        );
+    }
+
+    #[test]
+    fn rename_rules_match_serde() {
+        let rule = |r| RenameRule::parse(r).unwrap();
+        assert_eq!(rule("camelCase").apply_to_field("per_page"), "perPage");
+        assert_eq!(rule("PascalCase").apply_to_field("per_page"), "PerPage");
+        assert_eq!(rule("kebab-case").apply_to_field("per_page"), "per-page");
+        assert_eq!(
+            rule("SCREAMING_SNAKE_CASE").apply_to_field("per_page"),
+            "PER_PAGE"
+        );
+        assert_eq!(
+            rule("snake_case").apply_to_variant("SuperAdmin"),
+            "super_admin"
+        );
+        assert_eq!(
+            rule("camelCase").apply_to_variant("SuperAdmin"),
+            "superAdmin"
+        );
+        assert_eq!(
+            rule("SCREAMING-KEBAB-CASE").apply_to_variant("SuperAdmin"),
+            "SUPER-ADMIN"
+        );
+        assert_eq!(
+            rule("lowercase").apply_to_variant("SuperAdmin"),
+            "superadmin"
+        );
+        assert!(RenameRule::parse("Train-Case").is_none());
+    }
+}
@@ -4,18 +4,15 @@ use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use miette::Report;
 use petgraph::graph::NodeIndex;
-use petgraph::Direction;
 use rustdoc_types::{ItemEnum, StructKind};
 
-use crate::compiler::analyses::call_graph::{CallGraphNode, RawCallGraph};
-use crate::compiler::analyses::components::HydratedComponent;
+use crate::compiler::analyses::call_graph::RawCallGraph;
 use crate::compiler::analyses::components::{ComponentDb, ComponentId};
 use crate::compiler::analyses::computations::ComputationDb;
 use crate::compiler::analyses::processing_pipeline::RequestHandlerPipeline;
 use crate::compiler::analyses::router::Router;
-use crate::compiler::analyses::user_components::UserComponentId;
-use crate::compiler::component::Constructor;
-use crate::compiler::computation::{Computation, MatchResultVariant};
+use crate::compiler::analyses::user_components::domain;
+use crate::compiler::analyses::user_components::param_constraints::{self, ParamConstraint};
 use crate::compiler::utils::process_framework_path;
 use crate::diagnostic;
 use crate::diagnostic::{CompilerDiagnostic, LocationExt, OptionalSourceSpanExt};
@@ -23,6 +20,7 @@ use crate::language::{GenericArgument, ResolvedType};
 use crate::rustdoc::{CrateCollection, GlobalItemId};
 use crate::utils::comma_separated_list;
 
+use super::extractor_validation::{extractor_consumer_ids, find_extractor};
 use super::traits::assert_trait_is_implemented;
 
 /// For each handler, check if path parameters are extracted from the URL of the incoming request.
@@ -46,47 +44,15 @@ pub(crate) fn verify_path_parameters(
         unreachable!()
     };
 
+    const PATH_PARAMS: [&str; 4] = ["pavex", "request", "path", "PathParams"];
     for (path, method_router) in router.route_path2sub_router.iter() {
         for handler_id in method_router.handler_ids() {
             let Some(pipeline) = handler_id2pipeline.get(handler_id) else {
                 continue;
             };
 
-            // If `PathParams` is used, it will appear as a `Compute` node in *at most* one of the
-            // call graphs in the processing pipeline for the handler, since it's a `RequestScoped`
-            // component.
             let Some((graph, ok_path_params_node_id, ty_)) =
-                pipeline.graph_iter().find_map(|graph| {
-                    let graph = &graph.call_graph;
-                    graph
-                        .node_indices()
-                        .find_map(|node_id| {
-                            let node = &graph[node_id];
-                            let CallGraphNode::Compute { component_id, .. } = node else {
-                                return None;
-                            };
-                            let hydrated_component =
-                                component_db.hydrated_component(*component_id, computation_db);
-                            let HydratedComponent::Constructor(Constructor(
-                                Computation::MatchResult(m),
-                            )) = hydrated_component
-                            else {
-                                return None;
-                            };
-                            if m.variant != MatchResultVariant::Ok {
-                                return None;
-                            }
-                            let ResolvedType::ResolvedPath(ty_) = &m.output else {
-                                return None;
-                            };
-                            if ty_.base_type == vec!["pavex", "request", "path", "PathParams"] {
-                                Some((node_id, ty_.clone()))
-                            } else {
-                                None
-                            }
-                        })
-                        .map(|(node_id, ty_)| (graph, node_id, ty_))
-                })
+                find_extractor(pipeline, component_db, computation_db, &PATH_PARAMS)
             else {
                 continue;
             };
@@ -124,11 +90,15 @@ pub(crate) fn verify_path_parameters(
                 continue;
             }
 
+            // Domain captures are extracted via `HostParams`, not `PathParams`.
+            let path = domain::path_template(path);
             let path_parameter_names = path
                 .split('/')
                 .filter_map(|s| s.strip_prefix(':').or_else(|| s.strip_prefix('*')))
                 .collect::<IndexSet<_>>();
 
+            // The primitive type of each struct field, if it is a primitive.
+            let mut field_name2primitive = IndexMap::new();
             let struct_field_names = {
                 let mut struct_field_names = IndexSet::new();
                 let ResolvedType::ResolvedPath(extracted_path_type) = &extracted_type else {
@@ -145,7 +115,13 @@ pub(crate) fn verify_path_parameters(
                         rustdoc_item_id: field_id.clone(),
                         package_id: extracted_path_type.package_id.clone(),
                     });
-                    struct_field_names.insert(field_item.name.clone().unwrap());
+                    let field_name = field_item.name.clone().unwrap();
+                    if let ItemEnum::StructField(rustdoc_types::Type::Primitive(primitive)) =
+                        &field_item.inner
+                    {
+                        field_name2primitive.insert(field_name.clone(), primitive.clone());
+                    }
+                    struct_field_names.insert(field_name);
                 }
                 struct_field_names
             };
@@ -155,6 +131,38 @@ pub(crate) fn verify_path_parameters(
                 .filter(|f| !path_parameter_names.contains(f.as_str()))
                 .collect::<IndexSet<_>>();
 
+            // A type constraint must agree with the type of the corresponding field, otherwise
+            // requests that satisfy the constraint may still fail extraction (or vice versa).
+            let constraint_mismatches = method_router
+                .param_constraints
+                .iter()
+                .filter_map(|(name, constraint)| {
+                    let ParamConstraint::Type(constraint_type) = constraint else {
+                        return None;
+                    };
+                    let field_type = field_name2primitive.get(name)?;
+                    (field_type != constraint_type).then(|| {
+                        (
+                            name.to_owned(),
+                            constraint_type.to_owned(),
+                            field_type.to_owned(),
+                        )
+                    })
+                })
+                .collect::<Vec<_>>();
+            if !constraint_mismatches.is_empty() {
+                report_constraint_type_mismatches(
+                    component_db,
+                    package_graph,
+                    diagnostics,
+                    &param_constraints::restore_constraints(path, &method_router.param_constraints),
+                    graph,
+                    ok_path_params_node_id,
+                    constraint_mismatches,
+                    extracted_type,
+                );
+            }
+
             if !non_existing_path_parameters.is_empty() {
                 report_non_existing_path_parameters(
                     component_db,
@@ -189,7 +197,7 @@ fn report_non_existing_path_parameters(
     assert!(!non_existing_path_parameters.is_empty());
     // Find the compute nodes that consume the `PathParams` extractor and report
     // an error on each of them.
-    let consuming_ids = path_params_consumer_ids(component_db, call_graph, ok_path_params_node_id);
+    let consuming_ids = extractor_consumer_ids(component_db, call_graph, ok_path_params_node_id);
     for user_component_id in consuming_ids {
         let raw_identifiers = component_db
             .user_component_db()
@@ -271,6 +279,62 @@ fn report_non_existing_path_parameters(
     }
 }
 
+/// Report an error on each compute node that consumes the `PathParams` extractor
+/// if the type of one of its fields doesn't match the type constraint on the corresponding
+/// route parameter—e.g. `id: u32` for `:id<u64>`.
+#[allow(clippy::too_many_arguments)]
+fn report_constraint_type_mismatches(
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<Report>,
+    path: &str,
+    call_graph: &RawCallGraph,
+    ok_path_params_node_id: NodeIndex,
+    mismatches: Vec<(String, String, String)>,
+    extracted_type: &ResolvedType,
+) {
+    let consuming_ids = extractor_consumer_ids(component_db, call_graph, ok_path_params_node_id);
+    let mismatches = mismatches
+        .iter()
+        .map(|(name, constraint_type, field_type)| {
+            format!("- `{name}` is constrained to `{constraint_type}`, but the field has type `{field_type}`")
+        })
+        .join("\n");
+    for user_component_id in consuming_ids {
+        let raw_identifiers = component_db
+            .user_component_db()
+            .get_raw_callable_identifiers(user_component_id);
+        let callable_type = component_db.user_component_db()[user_component_id].callable_type();
+        let location = component_db
+            .user_component_db()
+            .get_location(user_component_id);
+        let source = match location.source_file(package_graph) {
+            Ok(s) => s,
+            Err(e) => {
+                diagnostics.push(e.into());
+                continue;
+            }
+        };
+        let source_span = diagnostic::get_f_macro_invocation_span(&source, location);
+        let error = anyhow!(
+            "`{}` is trying to extract path parameters using `PathParams<{extracted_type:?}>`.\n\
+            The types of the fields in `{extracted_type:?}` don't match the type constraints \
+            in `{path}`:\n{mismatches}",
+            raw_identifiers.fully_qualified_path().join("::"),
+        );
+        let d = CompilerDiagnostic::builder(source, error)
+            .optional_label(source_span.labeled(format!(
+                "The {callable_type} asking for `PathParams<{extracted_type:?}>`"
+            )))
+            .help(
+                "Use the same type for the route constraint and for the corresponding field."
+                    .into(),
+            )
+            .build();
+        diagnostics.push(d.into());
+    }
+}
+
 /// Checks that the type of the path parameter is a struct with named fields.
 /// If it is, returns the rustdoc item for the type.  
 /// If it isn't, reports an error diagnostic on each compute node that consumes the
@@ -317,7 +381,7 @@ fn must_be_a_plain_struct(
 
     // Find the compute nodes that consume the `PathParams` extractor and report
     // an error on each of them.
-    let consuming_ids = path_params_consumer_ids(component_db, call_graph, ok_path_params_node_id);
+    let consuming_ids = extractor_consumer_ids(component_db, call_graph, ok_path_params_node_id);
 
     for user_component_id in consuming_ids {
         let raw_identifiers = component_db
@@ -358,25 +422,3 @@ fn must_be_a_plain_struct(
     }
     Err(())
 }
-
-/// Return the set of user component ids that consume a certain instance of the `PathParams` extractor
-/// as input parameter.
-fn path_params_consumer_ids(
-    component_db: &ComponentDb,
-    call_graph: &RawCallGraph,
-    ok_path_params_node_id: NodeIndex,
-) -> IndexSet<UserComponentId> {
-    let mut consumer_ids = IndexSet::new();
-    let mut descendant_ids = call_graph
-        .neighbors_directed(ok_path_params_node_id, Direction::Outgoing)
-        .collect::<IndexSet<_>>();
-    while let Some(descendant_id) = descendant_ids.pop() {
-        let descendant_node = &call_graph[descendant_id];
-        if let CallGraphNode::Compute { component_id, .. } = descendant_node {
-            if let Some(user_component_id) = component_db.user_component_id(*component_id) {
-                consumer_ids.insert(user_component_id);
-            }
-        }
-    }
-    consumer_ids
-}
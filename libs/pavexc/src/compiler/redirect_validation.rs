@@ -0,0 +1,114 @@
+use anyhow::anyhow;
+use guppy::graph::PackageGraph;
+use indexmap::{IndexMap, IndexSet};
+use miette::Report;
+use petgraph::Direction;
+
+use crate::compiler::analyses::call_graph::CallGraphNode;
+use crate::compiler::analyses::components::{ComponentDb, ComponentId};
+use crate::compiler::analyses::framework_items::FrameworkItemDb;
+use crate::compiler::analyses::processing_pipeline::RequestHandlerPipeline;
+use crate::compiler::analyses::router::Router;
+use crate::compiler::analyses::user_components::UserComponentId;
+use crate::diagnostic;
+use crate::diagnostic::{CompilerDiagnostic, LocationExt, OptionalSourceSpanExt};
+use crate::language::ResolvedType;
+
+/// `pavex::router::Redirect` is only available to the request pipelines of routes registered
+/// via `Blueprint::redirect`.
+/// Report an error for each component that asks for it as an input parameter in the
+/// request pipeline of any other route (or of the root fallback).
+#[tracing::instrument(name = "Verify redirect injection", skip_all)]
+pub(crate) fn verify_redirect_injection(
+    router: &Router,
+    handler_id2pipeline: &IndexMap<ComponentId, RequestHandlerPipeline>,
+    framework_item_db: &FrameworkItemDb,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<Report>,
+) {
+    let redirect_type = framework_item_db
+        .get_type(FrameworkItemDb::redirect_id())
+        .unwrap();
+    let handler_ids = router
+        .route_path2sub_router
+        .values()
+        .filter(|leaf_router| leaf_router.redirect.is_none())
+        .flat_map(|leaf_router| leaf_router.handler_ids())
+        .chain(std::iter::once(&router.root_fallback_id));
+
+    let mut consumer_ids = IndexSet::new();
+    for handler_id in handler_ids {
+        let Some(pipeline) = handler_id2pipeline.get(handler_id) else {
+            continue;
+        };
+        for graph in pipeline.graph_iter() {
+            let graph = &graph.call_graph;
+            for node_id in graph.node_indices() {
+                let CallGraphNode::InputParameter { type_, .. } = &graph[node_id] else {
+                    continue;
+                };
+                let is_redirect = match type_ {
+                    ResolvedType::Reference(r) => r.inner.as_ref() == redirect_type,
+                    t => t == redirect_type,
+                };
+                if !is_redirect {
+                    continue;
+                }
+                for consumer_id in graph.neighbors_directed(node_id, Direction::Outgoing) {
+                    let CallGraphNode::Compute { component_id, .. } = &graph[consumer_id] else {
+                        continue;
+                    };
+                    if let Some(user_component_id) = component_db.user_component_id(*component_id) {
+                        consumer_ids.insert(user_component_id);
+                    }
+                }
+            }
+        }
+    }
+
+    for user_component_id in consumer_ids {
+        report_misplaced_redirect(user_component_id, component_db, package_graph, diagnostics);
+    }
+}
+
+fn report_misplaced_redirect(
+    user_component_id: UserComponentId,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    diagnostics: &mut Vec<Report>,
+) {
+    let raw_identifiers = component_db
+        .user_component_db()
+        .get_raw_callable_identifiers(user_component_id);
+    let callable_type = component_db.user_component_db()[user_component_id].callable_type();
+    let location = component_db
+        .user_component_db()
+        .get_location(user_component_id);
+    let source = match location.source_file(package_graph) {
+        Ok(s) => s,
+        Err(e) => {
+            diagnostics.push(e.into());
+            return;
+        }
+    };
+    let source_span = diagnostic::get_f_macro_invocation_span(&source, location);
+    let error = anyhow!(
+        "`pavex::router::Redirect` can only be injected into the request pipeline of \
+        routes registered via `Blueprint::redirect`.\n\
+        `{}` asks for it, but it's part of the request pipeline of at least one route \
+        that doesn't redirect.",
+        raw_identifiers.fully_qualified_path().join("::")
+    );
+    let d = CompilerDiagnostic::builder(source, error)
+        .optional_label(source_span.labeled(format!(
+            "The {callable_type} asking for `pavex::router::Redirect`"
+        )))
+        .help(
+            "Use `Blueprint::redirect` to register a route that redirects to another path. \
+            If you need the target of the redirect elsewhere, compute it in your own constructor."
+                .into(),
+        )
+        .build();
+    diagnostics.push(d.into());
+}
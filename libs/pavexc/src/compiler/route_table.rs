@@ -0,0 +1,227 @@
+use miette::miette;
+use pavex_bp_schema::{Blueprint, Location};
+
+use crate::compiler::analyses::computations::ComputationDb;
+use crate::compiler::analyses::user_components::domain::display_routing_key;
+use crate::compiler::analyses::user_components::{UserComponent, UserComponentDb, UserComponentId};
+use crate::rustdoc::CrateCollection;
+
+/// A summary of the routes registered against a [`Blueprint`].
+///
+/// It's built by running the analyses required to construct the application router,
+/// without processing the request pipelines or generating any code.
+#[derive(Debug, Clone)]
+pub struct RouteTable {
+    routes: Vec<RouteTableEntry>,
+}
+
+/// A route in a [`RouteTable`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RouteTableEntry {
+    /// The methods handled by the route—e.g. `GET | HEAD`.
+    /// It's `*` if the route matches all methods.
+    pub method: String,
+    /// The path of the route, prefixed by its domain if the route is domain-specific.
+    pub path: String,
+    /// The fully-qualified path of the request handler.
+    /// For redirects, the target and the status code of the redirect—e.g.
+    /// `redirect to /users/:id (301)`.
+    pub handler: String,
+    /// The fully-qualified path of the fallback that handles requests for this path
+    /// whose method doesn't match any route.
+    /// It's `None` if the route matches all methods.
+    pub fallback: Option<String>,
+    /// The fully-qualified paths of the middlewares that wrap around the request handler,
+    /// from the outermost to the innermost.
+    pub middlewares: Vec<String>,
+    /// The location where the route was registered—e.g. `src/blueprint.rs:12:5`.
+    pub location: String,
+}
+
+impl RouteTable {
+    /// Process the [`Blueprint`] created by user into a [`RouteTable`].
+    ///
+    /// It returns the warnings emitted during the analysis alongside the table, or
+    /// all the diagnostics if at least one of them is an error.
+    #[tracing::instrument(skip_all)]
+    pub fn build(
+        bp: Blueprint,
+        project_fingerprint: String,
+    ) -> Result<(Self, Vec<miette::Error>), Vec<miette::Error>> {
+        let krate_collection =
+            CrateCollection::new(project_fingerprint).map_err(|e| vec![miette!(e)])?;
+        let package_graph = krate_collection.package_graph().to_owned();
+        let mut diagnostics = vec![];
+        let mut computation_db = ComputationDb::new();
+        let Ok((router, user_component_db)) = UserComponentDb::build(
+            &bp,
+            &mut computation_db,
+            &package_graph,
+            &krate_collection,
+            &mut diagnostics,
+        ) else {
+            return Err(diagnostics);
+        };
+        if diagnostics.iter().any(|e| {
+            let severity = e.severity();
+            severity == Some(miette::Severity::Error) || severity.is_none()
+        }) {
+            return Err(diagnostics);
+        }
+
+        let callable_path = |id: UserComponentId| {
+            user_component_db
+                .get_raw_callable_identifiers(id)
+                .fully_qualified_path()
+                .join("::")
+        };
+        let mut routes = Vec::new();
+        for (routing_key, leaf_router) in &router.route_path2sub_router {
+            let path = display_routing_key(routing_key).into_owned();
+            let handlers: Vec<_> = if leaf_router.handler_id2methods.is_empty() {
+                // Catch-alls registered to serve the fallback of a nested blueprint
+                // are not routes.
+                if let UserComponent::Fallback { .. } = user_component_db[leaf_router.fallback_id] {
+                    continue;
+                }
+                vec![(leaf_router.fallback_id, None)]
+            } else {
+                leaf_router
+                    .handler_id2methods
+                    .iter()
+                    .map(|(id, methods)| (*id, Some(methods)))
+                    .collect()
+            };
+            for (handler_id, methods) in handlers {
+                let method = match methods {
+                    Some(methods) => methods.iter().cloned().collect::<Vec<_>>().join(" | "),
+                    None => "*".into(),
+                };
+                let fallback = methods.map(|_| callable_path(leaf_router.fallback_id));
+                let middlewares = user_component_db
+                    .get_middleware_ids(handler_id)
+                    .iter()
+                    .map(|id| callable_path(*id))
+                    .collect();
+                let handler = match &leaf_router.redirect {
+                    Some(redirect) => format!("redirect to {} ({})", redirect.to, redirect.status),
+                    None => callable_path(handler_id),
+                };
+                routes.push(RouteTableEntry {
+                    method,
+                    path: path.clone(),
+                    handler,
+                    fallback,
+                    middlewares,
+                    location: display_location(user_component_db.get_location(handler_id)),
+                });
+            }
+        }
+        // The router is keyed by routing key, which puts domain labels in reverse order:
+        // we sort by the path that's displayed to the user instead.
+        // The sort is stable: routes for the same path keep the router order.
+        routes.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok((Self { routes }, diagnostics))
+    }
+
+    /// The routes in the table, sorted by path.
+    pub fn routes(&self) -> &[RouteTableEntry] {
+        &self.routes
+    }
+
+    /// Render the table in a human-readable format, with one route per line.
+    pub fn render_table(&self) -> String {
+        const HEADERS: [&str; 6] = [
+            "METHOD",
+            "PATH",
+            "HANDLER",
+            "FALLBACK",
+            "MIDDLEWARES",
+            "LOCATION",
+        ];
+        let rows = self
+            .routes
+            .iter()
+            .map(|route| {
+                [
+                    route.method.clone(),
+                    route.path.clone(),
+                    route.handler.clone(),
+                    route.fallback.clone().unwrap_or_else(|| "-".into()),
+                    if route.middlewares.is_empty() {
+                        "-".into()
+                    } else {
+                        route.middlewares.join(" -> ")
+                    },
+                    route.location.clone(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let mut widths = HEADERS.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let mut output = String::new();
+        let headers = HEADERS.map(String::from);
+        for row in std::iter::once(&headers).chain(&rows) {
+            let line = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Render the table as JSON, to be consumed by other tools.
+    pub fn render_json(&self) -> String {
+        serde_json::to_string_pretty(&self.routes).expect("Failed to serialize the route table")
+    }
+}
+
+fn display_location(location: &Location) -> String {
+    format!("{}:{}:{}", location.file, location.line, location.column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RouteTable, RouteTableEntry};
+
+    #[test]
+    fn columns_are_aligned() {
+        let table = RouteTable {
+            routes: vec![
+                RouteTableEntry {
+                    method: "GET".into(),
+                    path: "/".into(),
+                    handler: "app::index".into(),
+                    fallback: Some("pavex::router::default_fallback".into()),
+                    middlewares: vec![],
+                    location: "src/blueprint.rs:8:5".into(),
+                },
+                RouteTableEntry {
+                    method: "*".into(),
+                    path: "/users/:id".into(),
+                    handler: "app::user".into(),
+                    fallback: None,
+                    middlewares: vec!["app::timeout".into(), "app::log".into()],
+                    location: "src/blueprint.rs:9:5".into(),
+                },
+            ],
+        };
+        assert_eq!(
+            table.render_table(),
+            "\
+METHOD  PATH        HANDLER     FALLBACK                         MIDDLEWARES               LOCATION
+GET     /           app::index  pavex::router::default_fallback  -                         src/blueprint.rs:8:5
+*       /users/:id  app::user   -                                app::timeout -> app::log  src/blueprint.rs:9:5
+"
+        );
+    }
+}
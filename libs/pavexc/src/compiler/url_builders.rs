@@ -0,0 +1,215 @@
+use guppy::graph::PackageGraph;
+use indexmap::IndexMap;
+use rustdoc_types::{ItemEnum, StructKind, Visibility};
+
+use crate::compiler::analyses::components::{ComponentDb, ComponentId};
+use crate::compiler::analyses::computations::ComputationDb;
+use crate::compiler::analyses::processing_pipeline::RequestHandlerPipeline;
+use crate::compiler::analyses::router::Router;
+use crate::compiler::analyses::user_components::domain;
+use crate::compiler::traits::assert_trait_is_implemented;
+use crate::compiler::utils::process_framework_path;
+use crate::language::{GenericArgument, ResolvedType};
+use crate::rustdoc::{CrateCollection, GlobalItemId};
+
+use super::extractor_validation::find_extractor;
+
+/// The information required to generate a function that builds the path
+/// of a named route.
+#[derive(Debug, Clone)]
+pub(crate) struct UrlBuilder {
+    /// The name of the route—e.g. `user.show`.
+    pub(crate) name: String,
+    /// The path template of the route, without domain—e.g. `/users/:id`.
+    pub(crate) path: String,
+    /// The type that the request handler extracts via `PathParams<T>`, if it can be used
+    /// to build the path—i.e. it has a public field for each path parameter.
+    pub(crate) path_params_type: Option<ResolvedType>,
+}
+
+/// A segment of a route path template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PathSegment<'a> {
+    /// A part of the path that must be copied verbatim—e.g. `/users/`.
+    Literal(&'a str),
+    /// A path parameter—e.g. `:id` or `*rest`.
+    Parameter { name: &'a str, catch_all: bool },
+}
+
+impl UrlBuilder {
+    /// Split the path template into literals and path parameters.
+    pub(crate) fn segments(&self) -> Vec<PathSegment<'_>> {
+        let mut segments = Vec::new();
+        let mut rest = self.path.as_str();
+        while let Some(start) = rest.find([':', '*']) {
+            if start > 0 {
+                segments.push(PathSegment::Literal(&rest[..start]));
+            }
+            let catch_all = rest[start..].starts_with('*');
+            let end = rest[start..]
+                .find('/')
+                .map(|i| start + i)
+                .unwrap_or(rest.len());
+            segments.push(PathSegment::Parameter {
+                name: &rest[start + 1..end],
+                catch_all,
+            });
+            rest = &rest[end..];
+        }
+        if !rest.is_empty() {
+            segments.push(PathSegment::Literal(rest));
+        }
+        segments
+    }
+
+    /// The names of the path parameters, in the order they appear in the path template.
+    pub(crate) fn parameter_names(&self) -> Vec<&str> {
+        self.segments()
+            .into_iter()
+            .filter_map(|s| match s {
+                PathSegment::Parameter { name, .. } => Some(name),
+                PathSegment::Literal(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Collect the information required to generate a path builder for each named route.
+#[tracing::instrument(name = "Compute URL builders", skip_all)]
+pub(crate) fn url_builders(
+    router: &Router,
+    handler_id2pipeline: &IndexMap<ComponentId, RequestHandlerPipeline>,
+    computation_db: &ComputationDb,
+    component_db: &ComponentDb,
+    package_graph: &PackageGraph,
+    krate_collection: &CrateCollection,
+) -> Vec<UrlBuilder> {
+    const PATH_PARAMS: [&str; 4] = ["pavex", "request", "path", "PathParams"];
+    let ResolvedType::ResolvedPath(structural_deserialize) = process_framework_path(
+        "pavex::serialization::StructuralDeserialize",
+        package_graph,
+        krate_collection,
+    ) else {
+        unreachable!()
+    };
+
+    let mut builders = Vec::with_capacity(router.route_name2handler_id.len());
+    for (name, handler_id) in &router.route_name2handler_id {
+        let Some(routing_key) = router
+            .route_path2sub_router
+            .iter()
+            .find(|(_, leaf_router)| {
+                leaf_router.handler_id2methods.contains_key(handler_id)
+                    || (leaf_router.handler_id2methods.is_empty()
+                        && &leaf_router.fallback_id == handler_id)
+            })
+            .map(|(path, _)| path)
+        else {
+            continue;
+        };
+        let mut builder = UrlBuilder {
+            name: name.to_owned(),
+            path: domain::path_template(routing_key).to_owned(),
+            path_params_type: None,
+        };
+
+        let extracted_type = handler_id2pipeline
+            .get(handler_id)
+            .and_then(|pipeline| {
+                find_extractor(pipeline, component_db, computation_db, &PATH_PARAMS)
+            })
+            .and_then(|(_, _, ty_)| match &ty_.generic_arguments[0] {
+                GenericArgument::TypeParameter(t) => Some(t.to_owned()),
+                _ => None,
+            });
+        if let Some(extracted_type) = extracted_type {
+            // We can only rely on field names if there are no serde customizations (e.g. renames).
+            let is_structural = assert_trait_is_implemented(
+                krate_collection,
+                &extracted_type,
+                &structural_deserialize,
+            )
+            .is_ok();
+            if is_structural
+                && has_public_fields(
+                    &extracted_type,
+                    &builder.parameter_names(),
+                    krate_collection,
+                )
+            {
+                builder.path_params_type = Some(extracted_type);
+            }
+        }
+        builders.push(builder);
+    }
+    builders
+}
+
+/// Check that `ty` is a struct with a public field for each of the specified names.
+fn has_public_fields(
+    ty: &ResolvedType,
+    names: &[&str],
+    krate_collection: &CrateCollection,
+) -> bool {
+    let ResolvedType::ResolvedPath(path_type) = ty else {
+        return false;
+    };
+    let Some(item_id) = path_type.rustdoc_id.clone() else {
+        return false;
+    };
+    let item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+        rustdoc_item_id: item_id,
+        package_id: path_type.package_id.clone(),
+    });
+    let ItemEnum::Struct(struct_item) = &item.inner else {
+        return false;
+    };
+    let StructKind::Plain { fields, .. } = &struct_item.kind else {
+        return false;
+    };
+    let public_fields = fields
+        .iter()
+        .filter_map(|field_id| {
+            let field_item = krate_collection.get_type_by_global_type_id(&GlobalItemId {
+                rustdoc_item_id: field_id.clone(),
+                package_id: path_type.package_id.clone(),
+            });
+            (field_item.visibility == Visibility::Public).then(|| field_item.name.clone())
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+    names.iter().all(|name| {
+        // Fields whose name is a keyword (e.g. `r#type`) can't be accessed by name.
+        syn::parse_str::<syn::Ident>(name).is_ok() && public_fields.iter().any(|f| f == name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathSegment, UrlBuilder};
+
+    #[test]
+    fn path_templates_are_split_into_segments() {
+        let builder = UrlBuilder {
+            name: "file.show".into(),
+            path: "/users/:id/files/*path".into(),
+            path_params_type: None,
+        };
+        assert_eq!(
+            builder.segments(),
+            vec![
+                PathSegment::Literal("/users/"),
+                PathSegment::Parameter {
+                    name: "id",
+                    catch_all: false
+                },
+                PathSegment::Literal("/files/"),
+                PathSegment::Parameter {
+                    name: "path",
+                    catch_all: true
+                },
+            ]
+        );
+        assert_eq!(builder.parameter_names(), vec!["id", "path"]);
+    }
+}
@@ -7,7 +7,9 @@ pub(crate) use compiler_diagnostic::{
 pub(crate) use ordinals::ZeroBasedOrdinal;
 pub(crate) use proc_macro_utils::ProcMacroSpanExt;
 pub(crate) use registration_locations::{
-    get_bp_new_span, get_f_macro_invocation_span, get_nest_at_prefix_span, get_route_path_span,
+    get_api_version_argument_span, get_auto_options_at_path_span, get_bp_new_span,
+    get_f_macro_invocation_span, get_nest_at_prefix_span, get_redirect_status_span,
+    get_redirect_target_span, get_route_method_guard_span, get_route_path_span,
 };
 pub(crate) use source_file::{read_source_file, LocationExt, ParsedSourceFile};
 
@@ -43,6 +43,8 @@ pub(crate) fn get_f_macro_invocation_span(
                     node.args.first()
                 }
                 "route" => node.args.iter().nth(2),
+                // There's no `f!` invocation for redirects, we point at their target instead.
+                "redirect" => node.args.iter().nth(1),
                 s => {
                     tracing::trace!(
                         "Unknown method name when looking for component registration: {}",
@@ -76,6 +78,10 @@ pub(crate) fn get_f_macro_invocation_span(
                             // Blueprint::route(bp, method, path_pattern, handler)
                             3
                         }
+                        ("Blueprint", "redirect") => {
+                            // Blueprint::redirect(bp, from, to, status)
+                            2
+                        }
                         ("Route", "new") => {
                             // Route::new(method, path_pattern, handler)
                             2
@@ -149,6 +155,10 @@ pub(crate) fn get_route_path_span(
                         return None;
                     }
                 }
+                "redirect" => {
+                    // bp.redirect(from, to, status)
+                    node.args.first()
+                }
                 s => {
                     tracing::trace!(
                         "Unknown method name when looking for a `route` invocation: {}",
@@ -160,7 +170,10 @@ pub(crate) fn get_route_path_span(
             argument.span()
         }
         Call::FunctionCall(node) => {
-            let argument = if node.args.len() == 4 {
+            let argument = if is_redirect_function_call(node) {
+                // Blueprint::redirect(bp, from, to, status)
+                node.args.iter().nth(1)
+            } else if node.args.len() == 4 {
                 // Blueprint::route(bp, method, path, handler)
                 node.args.iter().nth(2)
             } else {
@@ -173,6 +186,152 @@ pub(crate) fn get_route_path_span(
     Some(convert_proc_macro_span(raw_source, span))
 }
 
+/// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
+/// `.` in the method invocation for `route`.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.route(GET, "/home", f!(crate::home))
+/// //^ `location` points here!
+/// ```
+///
+/// We build a `SourceSpan` that matches the method guard argument.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.route(GET, "/home", f!(crate::home))
+/// //       ^^^
+/// //       We want a SourceSpan that points at this for routes
+/// ```
+pub(crate) fn get_route_method_guard_span(
+    source: &ParsedSourceFile,
+    location: &Location,
+) -> Option<SourceSpan> {
+    let raw_source = &source.contents;
+    let node = find_method_call(location, &source.parsed)?;
+    let span = match node {
+        Call::MethodCall(node) => {
+            let argument = match node.method.to_string().as_str() {
+                "route" => {
+                    if node.args.len() == 3 {
+                        // bp.route(method, path, handler)
+                        node.args.first()
+                    } else {
+                        tracing::trace!("Unexpected number of arguments for `route` invocation");
+                        return None;
+                    }
+                }
+                s => {
+                    tracing::trace!(
+                        "Unknown method name when looking for a `route` invocation: {}",
+                        s
+                    );
+                    return None;
+                }
+            }?;
+            argument.span()
+        }
+        Call::FunctionCall(node) => {
+            let argument = if node.args.len() == 4 {
+                // Blueprint::route(bp, method, path, handler)
+                node.args.iter().nth(1)
+            } else {
+                tracing::trace!("Unexpected number of arguments for `route` invocation");
+                return None;
+            };
+            argument.span()
+        }
+    };
+    Some(convert_proc_macro_span(raw_source, span))
+}
+
+/// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
+/// `.` in the method invocation for `redirect`.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY)
+/// //^ `location` points here!
+/// ```
+///
+/// We build a `SourceSpan` that matches the target argument.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY)
+/// //                          ^^^^^^^^^^^^
+/// //                          We want a SourceSpan that points at this
+/// ```
+pub(crate) fn get_redirect_target_span(
+    source: &ParsedSourceFile,
+    location: &Location,
+) -> Option<SourceSpan> {
+    get_redirect_argument_span(source, location, 1)
+}
+
+/// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
+/// `.` in the method invocation for `redirect`.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY)
+/// //^ `location` points here!
+/// ```
+///
+/// We build a `SourceSpan` that matches the status code argument.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.redirect("/profile/:id", "/users/:id", StatusCode::MOVED_PERMANENTLY)
+/// //                                        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// //                                        We want a SourceSpan that points at this
+/// ```
+pub(crate) fn get_redirect_status_span(
+    source: &ParsedSourceFile,
+    location: &Location,
+) -> Option<SourceSpan> {
+    get_redirect_argument_span(source, location, 2)
+}
+
+/// The span of the argument at `index` in a `bp.redirect(from, to, status)` invocation.
+fn get_redirect_argument_span(
+    source: &ParsedSourceFile,
+    location: &Location,
+    index: usize,
+) -> Option<SourceSpan> {
+    let raw_source = &source.contents;
+    let node = find_method_call(location, &source.parsed)?;
+    let argument = match node {
+        Call::MethodCall(node) => {
+            if node.method != "redirect" || node.args.len() != 3 {
+                tracing::trace!("Expected a `redirect` invocation with three arguments");
+                return None;
+            }
+            node.args.iter().nth(index)
+        }
+        Call::FunctionCall(node) => {
+            if !is_redirect_function_call(node) || node.args.len() != 4 {
+                tracing::trace!("Expected a `Blueprint::redirect` invocation with four arguments");
+                return None;
+            }
+            // Blueprint::redirect(bp, from, to, status)
+            node.args.iter().nth(index + 1)
+        }
+    }?;
+    Some(convert_proc_macro_span(raw_source, argument.span()))
+}
+
+/// Returns `true` if the function call is a `Blueprint::redirect(...)` invocation.
+fn is_redirect_function_call(node: &ExprCall) -> bool {
+    let Expr::Path(path) = node.func.deref() else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "redirect")
+}
+
 /// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
 /// `.` in the method invocation for `nest_at`.
 /// E.g.
@@ -199,7 +358,7 @@ pub(crate) fn get_nest_at_prefix_span(
     let span = match node {
         Call::MethodCall(node) => {
             let argument = match node.method.to_string().as_str() {
-                "nest_at" => {
+                "nest_at" | "nest_at_domain" => {
                     if node.args.len() == 2 {
                         // bp.nest_at(prefix, sub_bp)
                         node.args.first()
@@ -235,6 +394,110 @@ pub(crate) fn get_nest_at_prefix_span(
     Some(convert_proc_macro_span(raw_source, span))
 }
 
+/// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
+/// `.` in the method invocation for `auto_options_at`.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.auto_options_at("/home", false)
+/// //^ `location` points here!
+/// ```
+///
+/// We build a `SourceSpan` that matches the path argument.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.auto_options_at("/home", false)
+/// //                 ^^^^^^^
+/// //                 We want a SourceSpan that points at this
+/// ```
+pub(crate) fn get_auto_options_at_path_span(
+    source: &ParsedSourceFile,
+    location: &Location,
+) -> Option<SourceSpan> {
+    let raw_source = &source.contents;
+    let node = find_method_call(location, &source.parsed)?;
+    let span = match node {
+        Call::MethodCall(node) => {
+            let argument = match node.method.to_string().as_str() {
+                "auto_options_at" => {
+                    if node.args.len() == 2 {
+                        // bp.auto_options_at(path, enabled)
+                        node.args.first()
+                    } else {
+                        tracing::trace!(
+                            "Unexpected number of arguments for `auto_options_at` invocation"
+                        );
+                        return None;
+                    }
+                }
+                s => {
+                    tracing::trace!(
+                        "Unknown method name when looking for an `auto_options_at` invocation: {}",
+                        s
+                    );
+                    return None;
+                }
+            }?;
+            argument.span()
+        }
+        Call::FunctionCall(node) => {
+            let argument = if node.args.len() == 3 {
+                // Blueprint::auto_options_at(bp, path, enabled)
+                node.args.iter().nth(1)
+            } else {
+                tracing::trace!("Unexpected number of arguments for `auto_options_at` invocation");
+                return None;
+            };
+            argument.span()
+        }
+    };
+    Some(convert_proc_macro_span(raw_source, span))
+}
+
+/// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
+/// `.` in the method invocation for `inherit_from` or `remove_route`.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.nest_version("v2", v2()).inherit_from("v1").remove_route(GET, "/users")
+/// //                         ^ `location` points here for `inherit_from`!
+/// ```
+///
+/// We build a `SourceSpan` that matches the version argument for `inherit_from` and the
+/// path argument for `remove_route`.
+/// E.g.
+///
+/// ```rust,ignore
+/// bp.nest_version("v2", v2()).inherit_from("v1").remove_route(GET, "/users")
+/// //                                       ^^^^                    ^^^^^^^^
+/// //            We want a SourceSpan that points here for inherit_from  |
+/// //                                             or here for remove_route
+/// ```
+pub(crate) fn get_api_version_argument_span(
+    source: &ParsedSourceFile,
+    location: &Location,
+) -> Option<SourceSpan> {
+    let raw_source = &source.contents;
+    let node = find_method_call(location, &source.parsed)?;
+    let Call::MethodCall(node) = node else {
+        tracing::trace!("Expected a method call when looking for an API version argument");
+        return None;
+    };
+    let argument = match node.method.to_string().as_str() {
+        "inherit_from" => node.args.first(),
+        "remove_route" => node.args.iter().nth(1),
+        s => {
+            tracing::trace!(
+                "Unknown method name when looking for an API version argument: {}",
+                s
+            );
+            return None;
+        }
+    }?;
+    Some(convert_proc_macro_span(raw_source, argument.span()))
+}
+
 /// Location, obtained via `#[track_caller]` and `std::panic::Location::caller`, points at the
 /// `(` in the method invocation for `Blueprint::new`.
 /// E.g.
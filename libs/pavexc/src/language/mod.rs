@@ -5,8 +5,8 @@ pub(crate) use resolved_path::{
     ResolvedPathQualifiedSelf, ResolvedPathSegment, ResolvedPathType, UnknownPath,
 };
 pub(crate) use resolved_type::{
-    Generic, GenericArgument, GenericLifetimeParameter, Lifetime, PathType, ResolvedType, Slice,
-    Tuple, TypeReference,
+    Generic, GenericArgument, GenericLifetimeParameter, Lifetime, PathType, ResolvedType,
+    ScalarPrimitive, Slice, Tuple, TypeReference,
 };
 
 mod callable;
@@ -1,6 +1,6 @@
 extern crate core;
 
-pub use compiler::App;
+pub use compiler::{App, RouteTable, RouteTableEntry};
 
 mod compiler;
 mod diagnostic;
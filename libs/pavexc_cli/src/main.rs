@@ -9,7 +9,7 @@ use clap::{Parser, Subcommand};
 use generate_from_path::GenerateArgs;
 use miette::Severity;
 use owo_colors::OwoColorize;
-use pavexc::App;
+use pavexc::{App, RouteTable};
 use supports_color::Stream;
 use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
 use tracing_subscriber::fmt::format::FmtSpan;
@@ -66,6 +66,33 @@ impl FromStr for Color {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
+enum RoutesFormat {
+    Table,
+    Json,
+}
+
+impl Display for RoutesFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoutesFormat::Table => write!(f, "table"),
+            RoutesFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for RoutesFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(RoutesFormat::Table),
+            "json" => Ok(RoutesFormat::Json),
+            s => Err(anyhow::anyhow!("Invalid output format: {}", s)),
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Generate application runtime code according to an application blueprint.
@@ -77,11 +104,24 @@ enum Commands {
         /// the application to the specified path.
         #[clap(long, value_parser)]
         diagnostics: Option<PathBuf>,
+        /// Optional. If provided, pavex will derive an OpenAPI document from the routes
+        /// of the application and save it, as JSON, to the specified path.
+        #[clap(long, value_parser)]
+        openapi: Option<PathBuf>,
         /// The path to the directory that will contain the manifest and the source code for the generated application crate.
         /// If the provided path is relative, it is interpreted as relative to the root of the current workspace.
         #[clap(short, long, value_parser)]
         output: PathBuf,
     },
+    /// List the routes registered against the application blueprint.
+    Routes {
+        /// The source path for the serialized application blueprint.
+        #[clap(short, long, value_parser)]
+        blueprint: PathBuf,
+        /// The format of the output: `table` (human-readable) or `json` (machine-readable).
+        #[clap(long, default_value_t = RoutesFormat::Table)]
+        format: RoutesFormat,
+    },
     /// Scaffold a new Pavex project at <PATH>.
     New {
         /// The path of the new directory that will contain the project files.
@@ -149,8 +189,10 @@ fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
         Commands::Generate {
             blueprint,
             diagnostics,
+            openapi,
             output,
-        } => generate(blueprint, diagnostics, output, cli.color),
+        } => generate(blueprint, diagnostics, openapi, output, cli.color),
+        Commands::Routes { blueprint, format } => routes(blueprint, format, cli.color),
         Commands::New { path } => scaffold_project(path),
     }
 }
@@ -159,6 +201,7 @@ fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
 fn generate(
     blueprint: PathBuf,
     diagnostics: Option<PathBuf>,
+    openapi: Option<PathBuf>,
     output: PathBuf,
     color_profile: Color,
 ) -> Result<ExitCode, Box<dyn std::error::Error>> {
@@ -197,11 +240,66 @@ fn generate(
         app.diagnostic_representation()
             .persist_flat(&diagnostic_path)?;
     }
+    if let Some(openapi_path) = openapi {
+        let openapi_document = app.openapi_document();
+        for e in openapi_document.warnings() {
+            if color_on_stderr {
+                eprintln!("{}: {e:?}", "WARNING".bold().yellow());
+            } else {
+                eprintln!("WARNING: {e:?}");
+            };
+        }
+        openapi_document.persist(&openapi_path)?;
+    }
     let generated_app = app.codegen()?;
     generated_app.persist(&output)?;
     Ok(ExitCode::SUCCESS)
 }
 
+#[tracing::instrument("List routes")]
+fn routes(
+    blueprint: PathBuf,
+    format: RoutesFormat,
+    color_profile: Color,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let color_on_stderr = use_color_on_stderr(color_profile);
+
+    // We use the path to the blueprint as a fingerprint for the project.
+    let project_fingerprint = blueprint.to_string_lossy().into_owned();
+    let blueprint = {
+        let file = fs_err::OpenOptions::new().read(true).open(blueprint)?;
+        ron::de::from_reader(&file)?
+    };
+    let route_table = match RouteTable::build(blueprint, project_fingerprint) {
+        Ok((t, warnings)) => {
+            for e in warnings {
+                assert_eq!(e.severity(), Some(Severity::Warning));
+                if color_on_stderr {
+                    eprintln!("{}: {e:?}", "WARNING".bold().yellow());
+                } else {
+                    eprintln!("WARNING: {e:?}");
+                };
+            }
+            t
+        }
+        Err(errors) => {
+            for e in errors {
+                if color_on_stderr {
+                    eprintln!("{}: {e:?}", "ERROR".bold().red());
+                } else {
+                    eprintln!("ERROR: {e:?}");
+                };
+            }
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    match format {
+        RoutesFormat::Table => print!("{}", route_table.render_table()),
+        RoutesFormat::Json => println!("{}", route_table.render_json()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
 fn use_color_on_stderr(color_profile: Color) -> bool {
     match color_profile {
         Color::Auto => supports_color::on(Stream::Stderr).is_some(),
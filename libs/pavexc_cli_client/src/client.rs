@@ -2,6 +2,7 @@ use std::{path::PathBuf, process::Command};
 
 use crate::commands::generate::{BlueprintArgument, GenerateBuilder};
 use crate::commands::new::NewBuilder;
+use crate::commands::routes::RoutesBuilder;
 use crate::config::Color;
 
 /// A fluent API for configuring and executing `pavexc`'s CLI commands.
@@ -73,6 +74,14 @@ impl Client {
         let cmd = self.command();
         NewBuilder::new(cmd, path)
     }
+
+    /// Start building the configuration for the `routes` command.
+    ///
+    /// You must specify the path to the serialized `Blueprint` of the application.
+    pub fn routes(self, blueprint: PathBuf) -> RoutesBuilder {
+        let cmd = self.command();
+        RoutesBuilder::new(cmd, blueprint)
+    }
 }
 
 /// Setters for optional configuration knobs on `Client`.
@@ -11,6 +11,7 @@ use pavex::blueprint::Blueprint;
 pub struct GenerateBuilder {
     cmd: Command,
     diagnostics_path: Option<PathBuf>,
+    openapi_path: Option<PathBuf>,
     blueprint: BlueprintArgument,
     output_directory: PathBuf,
 }
@@ -31,6 +32,7 @@ impl GenerateBuilder {
     ) -> Self {
         Self {
             diagnostics_path: None,
+            openapi_path: None,
             blueprint,
             cmd,
             output_directory,
@@ -99,6 +101,9 @@ impl GenerateBuilder {
         if let Some(path) = self.diagnostics_path {
             self.cmd.arg("--diagnostics").arg(path);
         }
+        if let Some(path) = self.openapi_path {
+            self.cmd.arg("--openapi").arg(path);
+        }
         Ok(self.cmd)
     }
 
@@ -112,6 +117,17 @@ impl GenerateBuilder {
         self.diagnostics_path = Some(path);
         self
     }
+
+    /// Set the path to the file that Pavex will use to save an OpenAPI document
+    /// describing the routes of the application.
+    ///
+    /// The document is serialized as JSON.
+    ///
+    /// If this is not set, Pavex will not generate an OpenAPI document.
+    pub fn openapi_path(mut self, path: PathBuf) -> Self {
+        self.openapi_path = Some(path);
+        self
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -2,3 +2,4 @@
 pub mod errors;
 pub mod generate;
 pub mod new;
+pub mod routes;
@@ -0,0 +1,107 @@
+use std::{path::PathBuf, process::Command};
+
+use crate::commands::errors::{InvocationError, NonZeroExitCode, SignalTermination};
+
+/// The configuration for `pavexc`'s `routes` command.
+///
+/// You can use [`Client::routes`] to start building the command configuration.
+///
+/// [`Client::routes`]: crate::Client::routes
+pub struct RoutesBuilder {
+    cmd: Command,
+    blueprint: PathBuf,
+    format: RoutesFormat,
+}
+
+/// The format used by `pavexc` to print the routes of the application.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RoutesFormat {
+    /// A human-readable table, with one route per line.
+    #[default]
+    Table,
+    /// A JSON array, with one object per route.
+    Json,
+}
+
+/// The representation of this command used in error messages.
+static ROUTES_DEBUG_COMMAND: &str = "pavexc [...] routes [...]";
+
+impl RoutesBuilder {
+    pub(crate) fn new(cmd: Command, blueprint: PathBuf) -> Self {
+        Self {
+            cmd,
+            blueprint,
+            format: RoutesFormat::default(),
+        }
+    }
+
+    /// Print the routes of the application.
+    ///
+    /// This will invoke `pavexc` with the chosen configuration.
+    /// It won't return until `pavexc` has finished running.
+    ///
+    /// If `pavexc` exits with a non-zero status code, this will return an error.
+    pub fn execute(self) -> Result<(), RoutesError> {
+        let mut cmd = self.command();
+        let status = cmd
+            .status()
+            .map_err(|e| InvocationError {
+                source: e,
+                command: ROUTES_DEBUG_COMMAND,
+            })
+            .map_err(RoutesError::InvocationError)?;
+        if !status.success() {
+            if let Some(code) = status.code() {
+                return Err(RoutesError::NonZeroExitCode(NonZeroExitCode {
+                    code,
+                    command: ROUTES_DEBUG_COMMAND,
+                }));
+            } else {
+                return Err(RoutesError::SignalTermination(SignalTermination {
+                    command: ROUTES_DEBUG_COMMAND,
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    /// Assemble the `std::process::Command` that will be used to invoke `pavexc`,
+    /// but do not run it.
+    ///
+    /// This method can be useful if you need to customize the command before running it.
+    /// If that's not your usecase, consider using [`RoutesBuilder::execute`] instead.
+    pub fn command(mut self) -> Command {
+        let format = match self.format {
+            RoutesFormat::Table => "table",
+            RoutesFormat::Json => "json",
+        };
+        self.cmd
+            .arg("routes")
+            .arg("-b")
+            .arg(self.blueprint)
+            .arg("--format")
+            .arg(format)
+            .stdout(std::process::Stdio::inherit())
+            .stderr(std::process::Stdio::inherit());
+        self.cmd
+    }
+
+    /// Set the format used to print the routes.
+    ///
+    /// If this is not set, routes will be printed as a human-readable table.
+    pub fn format(mut self, format: RoutesFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RoutesError {
+    #[error(transparent)]
+    InvocationError(InvocationError),
+    #[error(transparent)]
+    SignalTermination(SignalTermination),
+    #[error(transparent)]
+    NonZeroExitCode(NonZeroExitCode),
+}